@@ -0,0 +1,43 @@
+use routes::Routes;
+use std::fmt::Display;
+
+mod routes;
+
+pub const DAY: aoc::Day = aoc::day!(2015, 9, Solution);
+
+pub struct Solution;
+
+impl aoc::Solver for Solution {
+    type Input = Routes;
+    type Error = &'static str;
+
+    fn parse(input: &str) -> Result<Routes, aoc::ParseError> {
+        input.parse()
+    }
+
+    fn part1(routes: &Routes) -> Result<impl Display, &'static str> {
+        routes.shortest().ok_or("no route visits every location")
+    }
+
+    fn part2(routes: &Routes) -> Result<impl Display, &'static str> {
+        routes.longest().ok_or("no route visits every location")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::Solution;
+    use aoc::Part;
+
+    const EXAMPLE: &str = include_str!("../assets/example.txt");
+
+    #[test]
+    fn example_part1() {
+        assert_eq!(aoc::solve::<Solution>(EXAMPLE, Part::One).unwrap(), "605");
+    }
+
+    #[test]
+    fn example_part2() {
+        assert_eq!(aoc::solve::<Solution>(EXAMPLE, Part::Two).unwrap(), "982");
+    }
+}
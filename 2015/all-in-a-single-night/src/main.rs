@@ -0,0 +1,3 @@
+fn main() {
+    aoc::aoc_main::<all_in_a_single_night::Solution>(aoc::input!());
+}
@@ -0,0 +1,88 @@
+use aoc::{parse, ParseError};
+use std::str::FromStr;
+
+pub struct Routes {
+    distances: Vec<Vec<Option<u32>>>,
+}
+
+impl Routes {
+    pub fn shortest(&self) -> Option<u32> {
+        self.best(u32::min)
+    }
+
+    pub fn longest(&self) -> Option<u32> {
+        self.best(u32::max)
+    }
+
+    fn best(&self, pick: fn(u32, u32) -> u32) -> Option<u32> {
+        let count = self.distances.len();
+        let mut best = vec![vec![None; count]; 1 << count];
+        for start in 0..count {
+            best[1 << start][start] = Some(0);
+        }
+        for visited in 1..1usize << count {
+            for last in 0..count {
+                let Some(distance) = best[visited][last] else {
+                    continue;
+                };
+                for next in (0..count).filter(|next| visited & (1 << next) == 0) {
+                    if let Some(step) = self.distances[last][next] {
+                        let entry = &mut best[visited | (1 << next)][next];
+                        let total = distance + step;
+                        *entry = Some(entry.map_or(total, |current| pick(current, total)));
+                    }
+                }
+            }
+        }
+        best.last()?.iter().flatten().copied().reduce(pick)
+    }
+}
+
+impl FromStr for Routes {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut locations: Vec<&str> = Vec::new();
+        let mut index = |name| match locations.iter().position(|&other| other == name) {
+            Some(idx) => idx,
+            None => {
+                locations.push(name);
+                locations.len() - 1
+            }
+        };
+        let mut edges = Vec::new();
+        for line in s.lines() {
+            let (from, to, distance) = parse::complete(line, |input| {
+                let from = parse::identifier(input)?;
+                parse::literal(input, " to ")?;
+                let to = parse::identifier(input)?;
+                parse::literal(input, " = ")?;
+                Ok((from, to, parse::unsigned::<u32>(input)?))
+            })?;
+            edges.push((index(from), index(to), distance));
+        }
+        if locations.len() > 16 {
+            return Err(ParseError::new("too many locations"));
+        }
+        let mut distances = vec![vec![None; locations.len()]; locations.len()];
+        for (from, to, distance) in edges {
+            distances[from][to] = Some(distance);
+            distances[to][from] = Some(distance);
+        }
+        Ok(Routes { distances })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Routes;
+
+    #[test]
+    fn missing_edge() {
+        let routes: Routes = "A to B = 1\nC to D = 2".parse().unwrap();
+        assert_eq!(routes.shortest(), None);
+        let routes: Routes = "A to B = 1\nB to C = 2".parse().unwrap();
+        assert_eq!(routes.shortest(), Some(3));
+        assert_eq!(routes.longest(), Some(3));
+    }
+}
@@ -0,0 +1,75 @@
+use std::{cmp::Ordering, fmt::Display};
+use sue::{Compound, Sue};
+
+mod sue;
+
+pub const DAY: aoc::Day = aoc::day!(2015, 16, Solution);
+
+const READING: [(Compound, u32); 10] = [
+    (Compound::Children, 3),
+    (Compound::Cats, 7),
+    (Compound::Samoyeds, 2),
+    (Compound::Pomeranians, 3),
+    (Compound::Akitas, 0),
+    (Compound::Vizslas, 0),
+    (Compound::Goldfish, 5),
+    (Compound::Trees, 3),
+    (Compound::Cars, 2),
+    (Compound::Perfumes, 1),
+];
+
+pub struct Solution;
+
+impl aoc::Solver for Solution {
+    type Input = Vec<Sue>;
+    type Error = &'static str;
+
+    fn parse(input: &str) -> Result<Vec<Sue>, aoc::ParseError> {
+        aoc::parse::lines(input, str::parse)
+    }
+
+    fn part1(aunts: &Vec<Sue>) -> Result<impl Display, &'static str> {
+        find(aunts, |_| Ordering::Equal)
+    }
+
+    fn part2(aunts: &Vec<Sue>) -> Result<impl Display, &'static str> {
+        find(aunts, |compound| match compound {
+            Compound::Cats | Compound::Trees => Ordering::Greater,
+            Compound::Pomeranians | Compound::Goldfish => Ordering::Less,
+            _ => Ordering::Equal,
+        })
+    }
+}
+
+fn find(aunts: &[Sue], expected: fn(Compound) -> Ordering) -> Result<u32, &'static str> {
+    aunts
+        .iter()
+        .find(|sue| {
+            READING
+                .iter()
+                .all(|&(compound, reading)| match sue.count(compound) {
+                    Some(count) => count.cmp(&reading) == expected(compound),
+                    None => true,
+                })
+        })
+        .map(Sue::number)
+        .ok_or("no matching aunt")
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::Solution;
+    use aoc::Part;
+
+    const EXAMPLE: &str = include_str!("../assets/example.txt");
+
+    #[test]
+    fn example_part1() {
+        assert_eq!(aoc::solve::<Solution>(EXAMPLE, Part::One).unwrap(), "2");
+    }
+
+    #[test]
+    fn example_part2() {
+        assert_eq!(aoc::solve::<Solution>(EXAMPLE, Part::Two).unwrap(), "3");
+    }
+}
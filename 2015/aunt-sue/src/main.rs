@@ -0,0 +1,3 @@
+fn main() {
+    aoc::aoc_main::<aunt_sue::Solution>(aoc::input!());
+}
@@ -0,0 +1,89 @@
+use aoc::{parse, ParseError};
+use std::{collections::HashMap, str::FromStr};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Compound {
+    Children,
+    Cats,
+    Samoyeds,
+    Pomeranians,
+    Akitas,
+    Vizslas,
+    Goldfish,
+    Trees,
+    Cars,
+    Perfumes,
+}
+
+impl FromStr for Compound {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "children" => Ok(Compound::Children),
+            "cats" => Ok(Compound::Cats),
+            "samoyeds" => Ok(Compound::Samoyeds),
+            "pomeranians" => Ok(Compound::Pomeranians),
+            "akitas" => Ok(Compound::Akitas),
+            "vizslas" => Ok(Compound::Vizslas),
+            "goldfish" => Ok(Compound::Goldfish),
+            "trees" => Ok(Compound::Trees),
+            "cars" => Ok(Compound::Cars),
+            "perfumes" => Ok(Compound::Perfumes),
+            _ => Err(ParseError::invalid("compound", s)),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Sue {
+    number: u32,
+    compounds: HashMap<Compound, u32>,
+}
+
+impl Sue {
+    pub fn number(&self) -> u32 {
+        self.number
+    }
+
+    pub fn count(&self, compound: Compound) -> Option<u32> {
+        self.compounds.get(&compound).copied()
+    }
+}
+
+impl FromStr for Sue {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        parse::complete(s, |input| {
+            parse::literal(input, "Sue ")?;
+            let number = parse::unsigned(input)?;
+            parse::literal(input, ": ")?;
+            let compounds = parse::separated(input, ", ", |input| {
+                let compound = parse::identifier(input)?.parse()?;
+                parse::literal(input, ": ")?;
+                Ok((compound, parse::unsigned(input)?))
+            })?;
+            Ok(Sue {
+                number,
+                compounds: compounds.into_iter().collect(),
+            })
+        })
+        .map_err(|err| err.context("aunt"))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Compound, Sue};
+
+    #[test]
+    fn parse() {
+        let sue: Sue = "Sue 12: cars: 9, akitas: 3".parse().unwrap();
+        assert_eq!(sue.number(), 12);
+        assert_eq!(sue.count(Compound::Akitas), Some(3));
+        assert_eq!(sue.count(Compound::Trees), None);
+        let err = "Sue 1: dogs: 2".parse::<Sue>().unwrap_err();
+        assert_eq!(err.to_string(), "invalid compound 'dogs' in aunt");
+    }
+}
@@ -0,0 +1,48 @@
+use password::Password;
+use std::fmt::Display;
+
+mod password;
+
+pub const DAY: aoc::Day = aoc::day!(2015, 11, Solution);
+
+pub struct Solution;
+
+impl aoc::Solver for Solution {
+    type Input = Password;
+    type Error = &'static str;
+
+    fn parse(input: &str) -> Result<Password, aoc::ParseError> {
+        input.trim_end().parse()
+    }
+
+    fn part1(password: &Password) -> Result<impl Display, &'static str> {
+        password.next_valid().ok_or("no valid password left")
+    }
+
+    fn part2(password: &Password) -> Result<impl Display, &'static str> {
+        password
+            .next_valid()
+            .and_then(|password| password.next_valid())
+            .ok_or("no valid password left")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::Solution;
+    use aoc::Part;
+
+    const EXAMPLE: &str = include_str!("../assets/example.txt");
+
+    #[test]
+    fn example_part1() {
+        assert_eq!(
+            aoc::solve::<Solution>(EXAMPLE, Part::One).unwrap(),
+            "abcdffaa"
+        );
+        assert_eq!(
+            aoc::solve::<Solution>("ghijklmn", Part::One).unwrap(),
+            "ghjaabcc"
+        );
+    }
+}
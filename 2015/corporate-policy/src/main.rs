@@ -0,0 +1,3 @@
+fn main() {
+    aoc::aoc_main::<corporate_policy::Solution>(aoc::input!());
+}
@@ -0,0 +1,132 @@
+use aoc::{iter::ExtIterator, ParseError};
+use std::{
+    collections::HashSet,
+    fmt::{self, Display, Formatter},
+    str::FromStr,
+};
+
+const FORBIDDEN: [u8; 3] = [b'i', b'o', b'l'];
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Password {
+    letters: Vec<u8>,
+}
+
+impl Password {
+    pub fn next_valid(&self) -> Option<Password> {
+        let mut password = self.clone();
+        loop {
+            password.increment()?;
+            if password.is_valid() {
+                return Some(password);
+            }
+        }
+    }
+
+    fn increment(&mut self) -> Option<()> {
+        if let Some(idx) = self
+            .letters
+            .iter()
+            .position(|letter| FORBIDDEN.contains(letter))
+        {
+            self.letters[idx] += 1;
+            self.letters[idx + 1..].fill(b'a');
+            return Some(());
+        }
+        for letter in self.letters.iter_mut().rev() {
+            if *letter == b'z' {
+                *letter = b'a';
+            } else {
+                *letter += 1;
+                if FORBIDDEN.contains(letter) {
+                    *letter += 1;
+                }
+                return Some(());
+            }
+        }
+        None
+    }
+
+    fn is_valid(&self) -> bool {
+        self.has_straight() && !self.has_forbidden() && self.pair_count() >= 2
+    }
+
+    fn has_straight(&self) -> bool {
+        self.letters
+            .iter()
+            .triplet()
+            .any(|(a, b, c)| a + 1 == *b && b + 1 == *c)
+    }
+
+    fn has_forbidden(&self) -> bool {
+        self.letters.iter().any(|letter| FORBIDDEN.contains(letter))
+    }
+
+    fn pair_count(&self) -> usize {
+        self.letters
+            .iter()
+            .tuples()
+            .filter(|(a, b)| a == b)
+            .map(|(a, _)| a)
+            .collect::<HashSet<_>>()
+            .len()
+    }
+}
+
+impl Display for Password {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        self.letters
+            .iter()
+            .try_for_each(|&letter| write!(f, "{}", letter as char))
+    }
+}
+
+impl FromStr for Password {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        if s.is_empty() {
+            return Err(ParseError::missing("password"));
+        }
+        if let Some(idx) = s.find(|c: char| !c.is_ascii_lowercase()) {
+            let len = s[idx..].chars().next().map_or(1, char::len_utf8);
+            return Err(ParseError::invalid("letter", &s[idx..idx + len]));
+        }
+        Ok(Password {
+            letters: s.bytes().collect(),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Password;
+
+    fn password(s: &str) -> Password {
+        s.parse().unwrap()
+    }
+
+    #[test]
+    fn requirements() {
+        assert!(!password("hijklmmn").is_valid());
+        assert!(password("hijklmmn").has_straight());
+        assert!(!password("abbceffg").is_valid());
+        assert_eq!(password("abbceffg").pair_count(), 2);
+        assert!(!password("abbcegjk").is_valid());
+        assert!(password("abcdffaa").is_valid());
+    }
+
+    #[test]
+    fn increment() {
+        let mut wrap = password("xz");
+        wrap.increment();
+        assert_eq!(wrap, password("ya"));
+        let mut skip = password("ah");
+        skip.increment();
+        assert_eq!(skip, password("aj"));
+        let mut reset = password("aiz");
+        reset.increment();
+        assert_eq!(reset, password("aja"));
+        assert_eq!(password("zz").increment(), None);
+    }
+}
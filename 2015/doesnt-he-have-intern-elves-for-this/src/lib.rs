@@ -0,0 +1,132 @@
+use aoc::iter::ExtIterator;
+use std::{collections::HashSet, fmt::Display};
+
+pub const DAY: aoc::Day = aoc::day!(2015, 5, Solution);
+
+pub struct Solution;
+
+impl aoc::Solver for Solution {
+    type Input = String;
+    type Error = &'static str;
+
+    fn parse(input: &str) -> Result<String, aoc::ParseError> {
+        Ok(input.to_string())
+    }
+
+    fn part1(input: &String) -> Result<impl Display, &'static str> {
+        Ok(filtered_count(input, is_nice_part1))
+    }
+
+    fn part2(input: &String) -> Result<impl Display, &'static str> {
+        Ok(filtered_count(input, is_nice_part2))
+    }
+}
+
+fn filtered_count(input: &str, filter: fn(&str) -> bool) -> usize {
+    input.lines().filter(|s| filter(s)).count()
+}
+
+fn is_nice_part1(word: &str) -> bool {
+    has_three_vowels(word) && has_double_letter(word) && !has_forbidden_subword(word)
+}
+
+fn has_three_vowels(word: &str) -> bool {
+    word.chars()
+        .map(|c| match c {
+            'a' | 'e' | 'i' | 'o' | 'u' => 1,
+            _ => 0,
+        })
+        .sum::<u32>()
+        >= 3
+}
+
+fn has_double_letter(word: &str) -> bool {
+    word.chars().into_iter().tuples().any(|(a, b)| a == b)
+}
+
+fn has_forbidden_subword(word: &str) -> bool {
+    word.contains("ab") | word.contains("cd") | word.contains("pq") | word.contains("xy")
+}
+
+fn is_nice_part2(word: &str) -> bool {
+    has_double_pair(word) && has_alternating(word)
+}
+
+fn has_double_pair(word: &str) -> bool {
+    let mut pairs = HashSet::new();
+    let mut previous = None;
+    for pair in word.chars().tuples() {
+        if pairs.contains(&pair) {
+            return true;
+        }
+        if let Some(previous) = previous {
+            pairs.insert(previous);
+        }
+        previous = Some(pair);
+    }
+    false
+}
+
+fn has_alternating(word: &str) -> bool {
+    word.chars().triplet().any(|(a, _, c)| a == c)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{has_double_pair, is_nice_part1, is_nice_part2};
+
+    #[test]
+    fn example1() {
+        assert!(is_nice_part1("ugknbfddgicrmopn"));
+    }
+
+    #[test]
+    fn example2() {
+        assert!(is_nice_part1("aaa"));
+    }
+
+    #[test]
+    fn example3() {
+        assert!(!is_nice_part1("jchzalrnumimnmhp"));
+    }
+
+    #[test]
+    fn example4() {
+        assert!(!is_nice_part1("haegwjzuvuyypxyu"));
+    }
+
+    #[test]
+    fn example5() {
+        assert!(!is_nice_part1("dvszwmarrgswjxmb"));
+    }
+
+    #[test]
+    fn no_overlap_double_pair() {
+        assert!(has_double_pair("xyxy"));
+    }
+
+    #[test]
+    fn overlap_double_pair() {
+        assert!(!has_double_pair("aaa"));
+    }
+
+    #[test]
+    fn example6() {
+        assert!(is_nice_part2("qjhvhtzxzqqjkmpb"));
+    }
+
+    #[test]
+    fn example7() {
+        assert!(is_nice_part2("xxyxx"));
+    }
+
+    #[test]
+    fn example8() {
+        assert!(!is_nice_part2("uurcxstgmygtbstg"));
+    }
+
+    #[test]
+    fn example9() {
+        assert!(!is_nice_part2("ieodomkazucvgmuy"));
+    }
+}
@@ -0,0 +1,91 @@
+use aoc::ParseError;
+use std::fmt::Display;
+
+pub const DAY: aoc::Day = aoc::day!(2015, 10, Solution);
+
+pub struct Solution;
+
+impl aoc::Solver for Solution {
+    type Input = Vec<u8>;
+    type Error = &'static str;
+
+    fn parse(input: &str) -> Result<Vec<u8>, ParseError> {
+        let input = input.trim_end();
+        if input.is_empty() {
+            return Err(ParseError::missing("digits"));
+        }
+        input
+            .char_indices()
+            .map(|(idx, c)| {
+                c.to_digit(10)
+                    .map(|digit| digit as u8)
+                    .ok_or_else(|| ParseError::invalid("digit", &input[idx..idx + c.len_utf8()]))
+            })
+            .collect()
+    }
+
+    fn part1(digits: &Vec<u8>) -> Result<impl Display, &'static str> {
+        Ok(repeat(digits, 40).len())
+    }
+
+    fn part2(digits: &Vec<u8>) -> Result<impl Display, &'static str> {
+        Ok(repeat(digits, 50).len())
+    }
+}
+
+fn repeat(digits: &[u8], times: usize) -> Vec<u8> {
+    (0..times).fold(digits.to_vec(), |digits, _| look_and_say(&digits))
+}
+
+fn look_and_say(digits: &[u8]) -> Vec<u8> {
+    let mut next = Vec::with_capacity(digits.len() * 2);
+    for run in digits.chunk_by(|a, b| a == b) {
+        let mut count = run.len();
+        let start = next.len();
+        while count > 0 {
+            next.push((count % 10) as u8);
+            count /= 10;
+        }
+        next[start..].reverse();
+        next.push(run[0]);
+    }
+    next
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{look_and_say, repeat, Solution};
+    use aoc::{Part, Solver};
+
+    const EXAMPLE: &str = include_str!("../assets/example.txt");
+
+    #[test]
+    fn example_steps() {
+        let digits = Solution::parse(EXAMPLE).unwrap();
+        let steps: Vec<Vec<u8>> = (1..=5).map(|times| repeat(&digits, times)).collect();
+        assert_eq!(
+            steps,
+            vec![
+                vec![1, 1],
+                vec![2, 1],
+                vec![1, 2, 1, 1],
+                vec![1, 1, 1, 2, 2, 1],
+                vec![3, 1, 2, 2, 1, 1]
+            ]
+        );
+        assert_eq!(look_and_say(&[1; 12]), vec![1, 2, 1]);
+    }
+
+    #[test]
+    fn example_part1() {
+        assert_eq!(aoc::solve::<Solution>(EXAMPLE, Part::One).unwrap(), "82350");
+    }
+
+    #[test]
+    fn example_part2() {
+        assert_eq!(
+            aoc::solve::<Solution>(EXAMPLE, Part::Two).unwrap(),
+            "1166642"
+        );
+    }
+}
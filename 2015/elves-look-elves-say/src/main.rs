@@ -0,0 +1,3 @@
+fn main() {
+    aoc::aoc_main::<elves_look_elves_say::Solution>(aoc::input!());
+}
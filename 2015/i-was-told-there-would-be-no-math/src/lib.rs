@@ -0,0 +1,92 @@
+use std::fmt::Display;
+
+pub const DAY: aoc::Day = aoc::day!(2015, 2, Solution);
+
+pub struct Solution;
+
+impl aoc::Solver for Solution {
+    type Input = Vec<[u32; 3]>;
+    type Error = &'static str;
+
+    fn parse(input: &str) -> Result<Vec<[u32; 3]>, aoc::ParseError> {
+        input
+            .lines()
+            .map(|l| parse_line(l).ok_or_else(|| aoc::ParseError::invalid("present", l)))
+            .collect()
+    }
+
+    fn part1(presents: &Vec<[u32; 3]>) -> Result<impl Display, &'static str> {
+        Ok(present_calc(presents, wrap_size))
+    }
+
+    fn part2(presents: &Vec<[u32; 3]>) -> Result<impl Display, &'static str> {
+        Ok(present_calc(presents, ribbon_size))
+    }
+}
+
+fn present_calc(presents: &[[u32; 3]], func: fn([u32; 3]) -> u32) -> u32 {
+    presents.iter().map(|&sides| func(sides)).sum()
+}
+
+const SIDES: usize = 3;
+
+fn parse_line(description: &str) -> Option<[u32; 3]> {
+    let mut it = description.split('x');
+    let strs = [
+        it.next()?.parse().ok()?,
+        it.next()?.parse().ok()?,
+        it.next()?.parse().ok()?,
+    ];
+    if it.next().is_some() {
+        return None;
+    }
+    Some(strs)
+}
+
+fn wrap_size(sides: [u32; 3]) -> u32 {
+    let faces: Vec<_> = (0..SIDES)
+        .into_iter()
+        .map(|idx| sides[idx] * sides[(idx + 1) % SIDES])
+        .collect();
+    faces.iter().map(|side| side * 2).sum::<u32>() + faces.iter().min().unwrap()
+}
+
+fn ribbon_size(mut sides: [u32; 3]) -> u32 {
+    sides.sort();
+    sides[0] * 2 + sides[1] * 2 + sides.into_iter().fold(1, |a, b| a * b)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{parse_line, ribbon_size, wrap_size};
+
+    #[test]
+    fn parse_line1() {
+        assert_eq!(parse_line("2x3x4").unwrap(), [2, 3, 4]);
+    }
+
+    #[test]
+    fn parse_line2() {
+        assert_eq!(parse_line("1x1x10").unwrap(), [1, 1, 10]);
+    }
+
+    #[test]
+    fn wrap_size_case1() {
+        assert_eq!(wrap_size([2, 3, 4]), 58);
+    }
+
+    #[test]
+    fn wrap_size_case2() {
+        assert_eq!(wrap_size([1, 1, 10]), 43);
+    }
+
+    #[test]
+    fn ribbon_size_case1() {
+        assert_eq!(ribbon_size([2, 3, 4]), 34);
+    }
+
+    #[test]
+    fn ribbon_size_case2() {
+        assert_eq!(ribbon_size([1, 1, 10]), 14);
+    }
+}
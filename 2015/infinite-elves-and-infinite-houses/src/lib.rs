@@ -0,0 +1,67 @@
+use aoc::parse;
+use std::fmt::Display;
+
+pub const DAY: aoc::Day = aoc::day!(2015, 20, Solution);
+
+pub struct Solution;
+
+impl aoc::Solver for Solution {
+    type Input = usize;
+    type Error = &'static str;
+
+    fn parse(input: &str) -> Result<usize, aoc::ParseError> {
+        parse::complete(input.trim_end(), parse::unsigned)
+    }
+
+    fn part1(presents: &usize) -> Result<impl Display, &'static str> {
+        first_house(*presents, 10, None).ok_or("no house gets enough presents")
+    }
+
+    fn part2(presents: &usize) -> Result<impl Display, &'static str> {
+        first_house(*presents, 11, Some(50)).ok_or("no house gets enough presents")
+    }
+}
+
+fn first_house(presents: usize, per_elf: usize, visits: Option<usize>) -> Option<usize> {
+    let limit = presents / per_elf + 1;
+    let mut houses = vec![0; limit + 1];
+    for elf in 1..=limit {
+        let last = visits.map_or(limit, |visits| limit.min(elf * visits));
+        for house in (elf..=last).step_by(elf) {
+            houses[house] += elf * per_elf;
+        }
+    }
+    houses
+        .iter()
+        .skip(1)
+        .position(|&delivered| delivered >= presents)
+        .map(|idx| idx + 1)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{first_house, Solution};
+    use aoc::Part;
+
+    const EXAMPLE: &str = include_str!("../assets/example.txt");
+
+    #[test]
+    fn example_houses() {
+        let houses: Vec<_> = [10, 30, 40, 70, 60, 120, 80, 150, 130]
+            .into_iter()
+            .map(|presents| first_house(presents, 10, None))
+            .collect();
+        assert_eq!(houses, [1, 2, 3, 4, 4, 6, 6, 8, 8].map(Some).to_vec());
+    }
+
+    #[test]
+    fn example_part1() {
+        assert_eq!(aoc::solve::<Solution>(EXAMPLE, Part::One).unwrap(), "8");
+    }
+
+    #[test]
+    fn example_part2() {
+        assert_eq!(aoc::solve::<Solution>(EXAMPLE, Part::Two).unwrap(), "6");
+        assert_eq!(first_house(560, 11, Some(1)), Some(51));
+    }
+}
@@ -0,0 +1,3 @@
+fn main() {
+    aoc::aoc_main::<infinite_elves_and_infinite_houses::Solution>(aoc::input!());
+}
@@ -0,0 +1,125 @@
+use aoc::{parse, ParseError};
+use std::fmt::Display;
+
+pub const DAY: aoc::Day = aoc::day!(2015, 24, Solution);
+
+pub struct Solution;
+
+impl aoc::Solver for Solution {
+    type Input = Vec<u64>;
+    type Error = &'static str;
+
+    fn parse(input: &str) -> Result<Vec<u64>, ParseError> {
+        let weights = parse::lines(input, |line| parse::complete(line, parse::unsigned))?;
+        if weights.len() > 64 {
+            return Err(ParseError::new("too many packages"));
+        }
+        Ok(weights)
+    }
+
+    fn part1(weights: &Vec<u64>) -> Result<impl Display, &'static str> {
+        entanglement(weights, 3).ok_or("the packages cannot be balanced")
+    }
+
+    fn part2(weights: &Vec<u64>) -> Result<impl Display, &'static str> {
+        entanglement(weights, 4).ok_or("the packages cannot be balanced")
+    }
+}
+
+fn entanglement(weights: &[u64], groups: u64) -> Option<u64> {
+    let total: u64 = weights.iter().sum();
+    if groups == 0 || !total.is_multiple_of(groups) {
+        return None;
+    }
+    let target = total / groups;
+    let all = mask_of(weights.len());
+    (1..=weights.len()).find_map(|size| {
+        let mut candidates = Vec::new();
+        subsets(weights, all, 0, size, target, 0, &mut candidates);
+        candidates.sort_by_key(|&mask| product(weights, mask));
+        candidates
+            .into_iter()
+            .find(|&mask| splits(weights, all & !mask, groups - 1, target))
+            .map(|mask| product(weights, mask))
+    })
+}
+
+fn splits(weights: &[u64], available: u64, groups: u64, target: u64) -> bool {
+    if groups <= 1 {
+        return true;
+    }
+    (1..=available.count_ones() as usize).any(|size| {
+        let mut candidates = Vec::new();
+        subsets(weights, available, 0, size, target, 0, &mut candidates);
+        candidates
+            .into_iter()
+            .any(|mask| splits(weights, available & !mask, groups - 1, target))
+    })
+}
+
+fn subsets(
+    weights: &[u64],
+    available: u64,
+    start: usize,
+    size: usize,
+    target: u64,
+    mask: u64,
+    found: &mut Vec<u64>,
+) {
+    if size == 0 {
+        if target == 0 {
+            found.push(mask);
+        }
+        return;
+    }
+    for idx in start..weights.len() {
+        if available & (1 << idx) != 0 && weights[idx] <= target {
+            let mask = mask | (1 << idx);
+            subsets(
+                weights,
+                available,
+                idx + 1,
+                size - 1,
+                target - weights[idx],
+                mask,
+                found,
+            );
+        }
+    }
+}
+
+fn product(weights: &[u64], mask: u64) -> u64 {
+    (0..weights.len())
+        .filter(|idx| mask & (1 << idx) != 0)
+        .map(|idx| weights[idx])
+        .product()
+}
+
+fn mask_of(len: usize) -> u64 {
+    u64::MAX.checked_shr(64 - len as u32).unwrap_or(0)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{entanglement, Solution};
+    use aoc::Part;
+
+    const EXAMPLE: &str = include_str!("../assets/example.txt");
+
+    #[test]
+    fn example_part1() {
+        assert_eq!(aoc::solve::<Solution>(EXAMPLE, Part::One).unwrap(), "99");
+    }
+
+    #[test]
+    fn example_part2() {
+        assert_eq!(aoc::solve::<Solution>(EXAMPLE, Part::Two).unwrap(), "44");
+    }
+
+    #[test]
+    fn unbalanced() {
+        assert_eq!(entanglement(&[1, 2, 4], 2), None);
+        assert_eq!(entanglement(&[3, 3, 2, 2, 2], 3), None);
+        assert_eq!(entanglement(&[3, 1, 2], 2), Some(3));
+    }
+}
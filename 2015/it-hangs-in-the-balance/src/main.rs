@@ -0,0 +1,3 @@
+fn main() {
+    aoc::aoc_main::<it_hangs_in_the_balance::Solution>(aoc::input!());
+}
@@ -0,0 +1,97 @@
+use aoc::{parse, ParseError};
+use std::str::FromStr;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Json {
+    Number(i64),
+    String(String),
+    Array(Vec<Json>),
+    Object(Vec<(String, Json)>),
+}
+
+impl Json {
+    pub fn sum(&self, skip: fn(&[(String, Json)]) -> bool) -> i64 {
+        match self {
+            Json::Number(number) => *number,
+            Json::String(_) => 0,
+            Json::Array(values) => values.iter().map(|value| value.sum(skip)).sum(),
+            Json::Object(members) if skip(members) => 0,
+            Json::Object(members) => members.iter().map(|(_, value)| value.sum(skip)).sum(),
+        }
+    }
+}
+
+fn value(input: &mut &str) -> Result<Json, ParseError> {
+    match input.chars().next() {
+        Some('[') => list(input, '[', ']', value).map(Json::Array),
+        Some('{') => list(input, '{', '}', member).map(Json::Object),
+        Some('"') => string(input).map(Json::String),
+        _ => parse::signed(input).map(Json::Number),
+    }
+}
+
+fn list<'a, T>(
+    input: &mut &'a str,
+    open: char,
+    close: char,
+    item: fn(&mut &'a str) -> Result<T, ParseError>,
+) -> Result<Vec<T>, ParseError> {
+    parse::literal(input, open.encode_utf8(&mut [0; 4]))?;
+    let items = if input.starts_with(close) {
+        Vec::new()
+    } else {
+        parse::separated(input, ",", item)?
+    };
+    parse::literal(input, close.encode_utf8(&mut [0; 4]))?;
+    Ok(items)
+}
+
+fn member(input: &mut &str) -> Result<(String, Json), ParseError> {
+    let key = string(input)?;
+    parse::literal(input, ":")?;
+    Ok((key, value(input)?))
+}
+
+fn string(input: &mut &str) -> Result<String, ParseError> {
+    parse::literal(input, "\"")?;
+    let string = parse::take_while(input, |c| c != '"' && c != '\\');
+    parse::literal(input, "\"")?;
+    Ok(string.to_string())
+}
+
+impl FromStr for Json {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        parse::complete(s, value).map_err(|err| err.context("json"))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Json;
+
+    #[test]
+    fn parse_nested() {
+        let json: Json = r#"{"a":[1,"b"],"c":{}}"#.parse().unwrap();
+        assert_eq!(
+            json,
+            Json::Object(vec![
+                (
+                    "a".to_string(),
+                    Json::Array(vec![Json::Number(1), Json::String("b".to_string())])
+                ),
+                ("c".to_string(), Json::Object(vec![]))
+            ])
+        );
+    }
+
+    #[test]
+    fn parse_errors() {
+        let err = "[1,2".parse::<Json>().unwrap_err();
+        assert_eq!(err.to_string(), "expected ']' in json");
+        assert!(r#"{"a"}"#.parse::<Json>().is_err());
+        assert!("[1,]".parse::<Json>().is_err());
+        assert!(r#"["a\"b"]"#.parse::<Json>().is_err());
+    }
+}
@@ -0,0 +1,61 @@
+use json::Json;
+use std::fmt::Display;
+
+mod json;
+
+pub const DAY: aoc::Day = aoc::day!(2015, 12, Solution);
+
+pub struct Solution;
+
+impl aoc::Solver for Solution {
+    type Input = Json;
+    type Error = &'static str;
+
+    fn parse(input: &str) -> Result<Json, aoc::ParseError> {
+        input.trim_end().parse()
+    }
+
+    fn part1(json: &Json) -> Result<impl Display, &'static str> {
+        Ok(json.sum(|_| false))
+    }
+
+    fn part2(json: &Json) -> Result<impl Display, &'static str> {
+        Ok(json.sum(|object| {
+            object
+                .iter()
+                .any(|(_, value)| *value == Json::String("red".to_string()))
+        }))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::Solution;
+    use aoc::Part;
+
+    const EXAMPLE: &str = include_str!("../assets/example.txt");
+
+    fn solve(input: &str, part: Part) -> String {
+        aoc::solve::<Solution>(input, part).unwrap()
+    }
+
+    #[test]
+    fn example_part1() {
+        assert_eq!(solve(EXAMPLE, Part::One), "6");
+        assert_eq!(solve(r#"{"a":2,"b":4}"#, Part::One), "6");
+        assert_eq!(solve("[[[3]]]", Part::One), "3");
+        assert_eq!(solve(r#"{"a":{"b":4},"c":-1}"#, Part::One), "3");
+        assert_eq!(solve(r#"{"a":[-1,1]}"#, Part::One), "0");
+        assert_eq!(solve(r#"[-1,{"a":1}]"#, Part::One), "0");
+        assert_eq!(solve("[]", Part::One), "0");
+        assert_eq!(solve("{}", Part::One), "0");
+    }
+
+    #[test]
+    fn example_part2() {
+        assert_eq!(solve(EXAMPLE, Part::Two), "4");
+        assert_eq!(solve("[1,2,3]", Part::Two), "6");
+        assert_eq!(solve(r#"{"d":"red","e":[1,2,3,4],"f":5}"#, Part::Two), "0");
+        assert_eq!(solve(r#"[1,"red",5]"#, Part::Two), "6");
+    }
+}
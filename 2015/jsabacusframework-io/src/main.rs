@@ -0,0 +1,3 @@
+fn main() {
+    aoc::aoc_main::<jsabacusframework_io::Solution>(aoc::input!());
+}
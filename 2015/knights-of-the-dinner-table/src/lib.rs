@@ -0,0 +1,38 @@
+use std::fmt::Display;
+use table::Table;
+
+mod table;
+
+pub const DAY: aoc::Day = aoc::day!(2015, 13, Solution);
+
+pub struct Solution;
+
+impl aoc::Solver for Solution {
+    type Input = Table;
+    type Error = &'static str;
+
+    fn parse(input: &str) -> Result<Table, aoc::ParseError> {
+        input.parse()
+    }
+
+    fn part1(table: &Table) -> Result<impl Display, &'static str> {
+        Ok(table.best_arrangement())
+    }
+
+    fn part2(table: &Table) -> Result<impl Display, &'static str> {
+        Ok(table.with_guest().best_arrangement())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::Solution;
+    use aoc::Part;
+
+    const EXAMPLE: &str = include_str!("../assets/example.txt");
+
+    #[test]
+    fn example_part1() {
+        assert_eq!(aoc::solve::<Solution>(EXAMPLE, Part::One).unwrap(), "330");
+    }
+}
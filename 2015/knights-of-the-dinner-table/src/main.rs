@@ -0,0 +1,3 @@
+fn main() {
+    aoc::aoc_main::<knights_of_the_dinner_table::Solution>(aoc::input!());
+}
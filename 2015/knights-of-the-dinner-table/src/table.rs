@@ -0,0 +1,104 @@
+use aoc::{parse, ParseError};
+use std::str::FromStr;
+
+#[derive(Debug, Clone)]
+pub struct Table {
+    happiness: Vec<Vec<i64>>,
+}
+
+impl Table {
+    pub fn with_guest(&self) -> Table {
+        let mut happiness = self.happiness.clone();
+        happiness.iter_mut().for_each(|row| row.push(0));
+        happiness.push(vec![0; happiness.len() + 1]);
+        Table { happiness }
+    }
+
+    pub fn best_arrangement(&self) -> i64 {
+        let count = self.happiness.len();
+        if count < 2 {
+            return 0;
+        }
+        let mut best = vec![vec![None; count]; 1 << count];
+        best[1][0] = Some(0);
+        for seated in (1..1usize << count).step_by(2) {
+            for last in 0..count {
+                let Some(total) = best[seated][last] else {
+                    continue;
+                };
+                for next in (0..count).filter(|next| seated & (1 << next) == 0) {
+                    let entry: &mut Option<i64> = &mut best[seated | (1 << next)][next];
+                    let total = total + self.pair(last, next);
+                    *entry = Some(entry.map_or(total, |current| current.max(total)));
+                }
+            }
+        }
+        (1..count)
+            .filter_map(|last| best[(1 << count) - 1][last].map(|total| total + self.pair(last, 0)))
+            .max()
+            .unwrap_or(0)
+    }
+
+    fn pair(&self, a: usize, b: usize) -> i64 {
+        self.happiness[a][b] + self.happiness[b][a]
+    }
+}
+
+impl FromStr for Table {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut guests: Vec<&str> = Vec::new();
+        let mut index = |name| match guests.iter().position(|&other| other == name) {
+            Some(idx) => idx,
+            None => {
+                guests.push(name);
+                guests.len() - 1
+            }
+        };
+        let mut preferences = Vec::new();
+        for line in s.lines() {
+            let (guest, units, neighbour) = parse::complete(line, |input| {
+                let guest = parse::identifier(input)?;
+                parse::literal(input, " would ")?;
+                let sign = match parse::identifier(input)? {
+                    "gain" => 1,
+                    "lose" => -1,
+                    other => return Err(ParseError::invalid("change", other)),
+                };
+                parse::spaces(input);
+                let units = sign * parse::unsigned::<i64>(input)?;
+                parse::literal(input, " happiness units by sitting next to ")?;
+                let neighbour = parse::identifier(input)?;
+                parse::literal(input, ".")?;
+                Ok((guest, units, neighbour))
+            })?;
+            preferences.push((index(guest), units, index(neighbour)));
+        }
+        if guests.len() > 16 {
+            return Err(ParseError::new("too many guests"));
+        }
+        let mut happiness = vec![vec![0; guests.len()]; guests.len()];
+        for (guest, units, neighbour) in preferences {
+            happiness[guest][neighbour] = units;
+        }
+        Ok(Table { happiness })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Table;
+
+    #[test]
+    fn small_tables() {
+        let table: Table = "A would lose 3 happiness units by sitting next to B."
+            .parse()
+            .unwrap();
+        assert_eq!(table.best_arrangement(), -6);
+        assert_eq!(table.with_guest().best_arrangement(), -3);
+        assert!("A would win 3 happiness units by sitting next to B."
+            .parse::<Table>()
+            .is_err());
+    }
+}
@@ -0,0 +1,73 @@
+use aoc::{math, parse, ParseError};
+use std::fmt::Display;
+
+pub const DAY: aoc::Day = aoc::day!(2015, 25, Solution);
+
+const FIRST_CODE: i128 = 20151125;
+const MULTIPLIER: i128 = 252533;
+const MODULUS: i128 = 33554393;
+
+pub struct Solution;
+
+impl aoc::Solver for Solution {
+    type Input = (u64, u64);
+    type Error = &'static str;
+
+    fn parse(input: &str) -> Result<(u64, u64), ParseError> {
+        parse::complete(input.trim_end(), |input| {
+            parse::literal(
+                input,
+                "To continue, please consult the code grid in the manual.  Enter the code at row ",
+            )?;
+            let row = parse::unsigned(input)?;
+            parse::literal(input, ", column ")?;
+            let column = parse::unsigned(input)?;
+            parse::literal(input, ".")?;
+            if row == 0 || column == 0 {
+                return Err(ParseError::new("rows and columns start at 1"));
+            }
+            Ok((row, column))
+        })
+    }
+
+    fn part1(&(row, column): &(u64, u64)) -> Result<impl Display, &'static str> {
+        Ok(code(row, column))
+    }
+
+    fn part2(_position: &(u64, u64)) -> Result<impl Display, &'static str> {
+        Ok("Done")
+    }
+}
+
+fn code(row: u64, column: u64) -> i128 {
+    let diagonal = u128::from(row + column - 1);
+    let index = diagonal * (diagonal - 1) / 2 + u128::from(column) - 1;
+    let power = math::mod_pow(MULTIPLIER, index, MODULUS).expect("modulus is positive");
+    FIRST_CODE * power % MODULUS
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{code, Solution};
+    use aoc::Part;
+
+    const EXAMPLE: &str = include_str!("../assets/example.txt");
+
+    #[test]
+    fn example_part1() {
+        assert_eq!(
+            aoc::solve::<Solution>(EXAMPLE, Part::One).unwrap(),
+            "27995004"
+        );
+    }
+
+    #[test]
+    fn code_grid() {
+        assert_eq!(code(1, 1), 20151125);
+        assert_eq!(code(2, 1), 31916031);
+        assert_eq!(code(1, 2), 18749137);
+        assert_eq!(code(4, 2), 32451966);
+        assert_eq!(code(5, 1), 77061);
+        assert_eq!(code(1, 6), 33511524);
+    }
+}
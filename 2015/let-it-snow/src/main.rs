@@ -0,0 +1,3 @@
+fn main() {
+    aoc::aoc_main::<let_it_snow::Solution>(aoc::input!());
+}
@@ -0,0 +1,82 @@
+use aoc::grid::Grid;
+use light::Light;
+use std::fmt::Display;
+
+mod light;
+
+pub const DAY: aoc::Day = aoc::day!(2015, 18, Solution);
+
+const STEPS: usize = 100;
+
+pub struct Solution;
+
+impl aoc::Solver for Solution {
+    type Input = Grid<Light>;
+    type Error = &'static str;
+
+    fn parse(input: &str) -> Result<Grid<Light>, aoc::ParseError> {
+        input.parse()
+    }
+
+    fn part1(lights: &Grid<Light>) -> Result<impl Display, &'static str> {
+        Ok(lit_after(lights, STEPS, false))
+    }
+
+    fn part2(lights: &Grid<Light>) -> Result<impl Display, &'static str> {
+        Ok(lit_after(lights, STEPS, true))
+    }
+}
+
+fn lit_after(lights: &Grid<Light>, steps: usize, stuck_corners: bool) -> usize {
+    let corners = [
+        (0, 0),
+        (0, lights.columns() - 1),
+        (lights.rows() - 1, 0),
+        (lights.rows() - 1, lights.columns() - 1),
+    ];
+    let stick = |mut lights: Grid<Light>| {
+        if stuck_corners {
+            corners
+                .iter()
+                .for_each(|&corner| lights[corner] = Light::On);
+        }
+        lights
+    };
+    let lights = (0..steps).fold(stick(lights.clone()), |lights, _| stick(animate(&lights)));
+    lights
+        .iter()
+        .filter(|(_, &light)| light == Light::On)
+        .count()
+}
+
+fn animate(lights: &Grid<Light>) -> Grid<Light> {
+    Grid::from_fn(lights.rows(), lights.columns(), |position| {
+        let neighbours = lights
+            .neighbours8(position)
+            .filter(|&neighbour| lights[neighbour] == Light::On)
+            .count();
+        match (lights[position], neighbours) {
+            (Light::On, 2 | 3) | (Light::Off, 3) => Light::On,
+            _ => Light::Off,
+        }
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{animate, lit_after, Solution};
+    use aoc::Solver;
+
+    const EXAMPLE: &str = include_str!("../assets/example.txt");
+
+    #[test]
+    fn example() {
+        let lights = Solution::parse(EXAMPLE).unwrap();
+        assert_eq!(
+            animate(&lights).to_string(),
+            "..##..\n..##.#\n...##.\n......\n#.....\n#.##.."
+        );
+        assert_eq!(lit_after(&lights, 4, false), 4);
+        assert_eq!(lit_after(&lights, 5, true), 17);
+    }
+}
@@ -0,0 +1,29 @@
+use aoc::ParseError;
+use std::fmt::{self, Display, Formatter};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Light {
+    On,
+    Off,
+}
+
+impl TryFrom<char> for Light {
+    type Error = ParseError;
+
+    fn try_from(value: char) -> Result<Self, Self::Error> {
+        match value {
+            '#' => Ok(Light::On),
+            '.' => Ok(Light::Off),
+            _ => Err(ParseError::new("expected '#' or '.'")),
+        }
+    }
+}
+
+impl Display for Light {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            Light::On => write!(f, "#"),
+            Light::Off => write!(f, "."),
+        }
+    }
+}
@@ -0,0 +1,3 @@
+fn main() {
+    aoc::aoc_main::<like_a_gif_for_your_yard::Solution>(aoc::input!());
+}
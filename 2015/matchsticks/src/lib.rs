@@ -0,0 +1,49 @@
+use literal::Literal;
+use std::fmt::Display;
+
+mod literal;
+
+pub const DAY: aoc::Day = aoc::day!(2015, 8, Solution);
+
+pub struct Solution;
+
+impl aoc::Solver for Solution {
+    type Input = Vec<Literal>;
+    type Error = &'static str;
+
+    fn parse(input: &str) -> Result<Vec<Literal>, aoc::ParseError> {
+        aoc::parse::lines(input, str::parse)
+    }
+
+    fn part1(literals: &Vec<Literal>) -> Result<impl Display, &'static str> {
+        Ok(literals
+            .iter()
+            .map(|literal| literal.code_len() - literal.memory_len())
+            .sum::<usize>())
+    }
+
+    fn part2(literals: &Vec<Literal>) -> Result<impl Display, &'static str> {
+        Ok(literals
+            .iter()
+            .map(|literal| literal.encoded_len() - literal.code_len())
+            .sum::<usize>())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::Solution;
+    use aoc::Part;
+
+    const EXAMPLE: &str = include_str!("../assets/example.txt");
+
+    #[test]
+    fn example_part1() {
+        assert_eq!(aoc::solve::<Solution>(EXAMPLE, Part::One).unwrap(), "12");
+    }
+
+    #[test]
+    fn example_part2() {
+        assert_eq!(aoc::solve::<Solution>(EXAMPLE, Part::Two).unwrap(), "19");
+    }
+}
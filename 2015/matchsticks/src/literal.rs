@@ -0,0 +1,87 @@
+use aoc::ParseError;
+use std::str::FromStr;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Literal {
+    code: String,
+    memory_len: usize,
+}
+
+impl Literal {
+    pub fn code_len(&self) -> usize {
+        self.code.len()
+    }
+
+    pub fn memory_len(&self) -> usize {
+        self.memory_len
+    }
+
+    pub fn encoded_len(&self) -> usize {
+        let escaped = self
+            .code
+            .chars()
+            .filter(|c| matches!(c, '"' | '\\'))
+            .count();
+        self.code.len() + escaped + 2
+    }
+}
+
+impl FromStr for Literal {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let inner = s
+            .strip_prefix('"')
+            .and_then(|rest| rest.strip_suffix('"'))
+            .ok_or_else(|| ParseError::invalid("string literal", s))?;
+        let mut memory_len = 0;
+        let mut rest = inner;
+        while let Some(c) = rest.chars().next() {
+            let len = match (c, rest.as_bytes().get(1)) {
+                ('\\', Some(b'\\' | b'"')) => 2,
+                ('\\', Some(b'x')) => {
+                    let digits = rest.get(2..4);
+                    if !digits.is_some_and(|digits| digits.chars().all(|c| c.is_ascii_hexdigit())) {
+                        return Err(ParseError::invalid(
+                            "hex escape",
+                            rest.get(..4).unwrap_or(rest),
+                        ));
+                    }
+                    4
+                }
+                ('\\', _) => return Err(ParseError::invalid("escape", &rest[..1])),
+                ('"', _) => return Err(ParseError::unexpected(&rest[..1])),
+                (c, _) => c.len_utf8(),
+            };
+            memory_len += 1;
+            rest = &rest[len..];
+        }
+        Ok(Literal {
+            code: s.to_string(),
+            memory_len,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Literal;
+
+    #[test]
+    fn lengths() {
+        let literal: Literal = r#""aaa\"aaa""#.parse().unwrap();
+        assert_eq!(literal.code_len(), 10);
+        assert_eq!(literal.memory_len(), 7);
+        assert_eq!(literal.encoded_len(), 16);
+        let literal: Literal = r#""\x27""#.parse().unwrap();
+        assert_eq!((literal.code_len(), literal.memory_len()), (6, 1));
+    }
+
+    #[test]
+    fn invalid_escapes() {
+        assert!(r#""\q""#.parse::<Literal>().is_err());
+        assert!(r#""\x4""#.parse::<Literal>().is_err());
+        assert!(r#""a"b""#.parse::<Literal>().is_err());
+        assert!("abc".parse::<Literal>().is_err());
+    }
+}
@@ -0,0 +1,3 @@
+fn main() {
+    aoc::aoc_main::<matchsticks::Solution>(aoc::input!());
+}
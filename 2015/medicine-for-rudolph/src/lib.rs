@@ -0,0 +1,49 @@
+use machine::Machine;
+use std::fmt::Display;
+
+mod machine;
+
+pub const DAY: aoc::Day = aoc::day!(2015, 19, Solution);
+
+pub struct Solution;
+
+impl aoc::Solver for Solution {
+    type Input = Machine;
+    type Error = &'static str;
+
+    fn parse(input: &str) -> Result<Machine, aoc::ParseError> {
+        input.parse()
+    }
+
+    fn part1(machine: &Machine) -> Result<impl Display, &'static str> {
+        Ok(machine.calibrate())
+    }
+
+    fn part2(machine: &Machine) -> Result<impl Display, &'static str> {
+        machine
+            .fabrication_steps()
+            .ok_or("the molecule cannot be made from 'e'")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::Solution;
+    use aoc::Part;
+
+    const EXAMPLE: &str = include_str!("../assets/example.txt");
+
+    #[test]
+    fn example_part1() {
+        assert_eq!(aoc::solve::<Solution>(EXAMPLE, Part::One).unwrap(), "4");
+        let longer = EXAMPLE.replace("HOH", "HOHOHO");
+        assert_eq!(aoc::solve::<Solution>(&longer, Part::One).unwrap(), "7");
+    }
+
+    #[test]
+    fn example_part2() {
+        assert_eq!(aoc::solve::<Solution>(EXAMPLE, Part::Two).unwrap(), "3");
+        let longer = EXAMPLE.replace("HOH", "HOHOHO");
+        assert_eq!(aoc::solve::<Solution>(&longer, Part::Two).unwrap(), "6");
+    }
+}
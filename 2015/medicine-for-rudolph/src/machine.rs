@@ -0,0 +1,131 @@
+use aoc::{parse, rand::Rng, ParseError};
+use std::{collections::HashSet, str::FromStr};
+
+const ATTEMPTS: usize = 1000;
+
+type Element = u8;
+
+#[derive(Debug, Clone)]
+pub struct Machine {
+    replacements: Vec<(Element, Vec<Element>)>,
+    molecule: Vec<Element>,
+    electron: Element,
+}
+
+impl Machine {
+    pub fn calibrate(&self) -> usize {
+        let mut molecules = HashSet::new();
+        for (from, to) in &self.replacements {
+            for idx in (0..self.molecule.len()).filter(|&idx| self.molecule[idx] == *from) {
+                let mut molecule = self.molecule.clone();
+                molecule.splice(idx..idx + 1, to.iter().copied());
+                molecules.insert(molecule);
+            }
+        }
+        molecules.len()
+    }
+
+    pub fn fabrication_steps(&self) -> Option<usize> {
+        let mut rng = Rng::seeded();
+        let mut replacements = self.replacements.clone();
+        for _ in 0..ATTEMPTS {
+            if let Some(steps) = self.reduce(&replacements) {
+                return Some(steps);
+            }
+            rng.shuffle(&mut replacements);
+        }
+        None
+    }
+
+    fn reduce(&self, replacements: &[(Element, Vec<Element>)]) -> Option<usize> {
+        let mut molecule = self.molecule.clone();
+        let mut steps = 0;
+        while molecule != [self.electron] {
+            let (idx, from, to) = replacements.iter().find_map(|(from, to)| {
+                if *from == self.electron {
+                    return (molecule == *to).then_some((0, from, to));
+                }
+                molecule
+                    .windows(to.len())
+                    .position(|window| window == to.as_slice())
+                    .map(|idx| (idx, from, to))
+            })?;
+            molecule.splice(idx..idx + to.len(), [*from]);
+            steps += 1;
+        }
+        Some(steps)
+    }
+}
+
+fn elements<'a>(input: &'a str, names: &mut Vec<&'a str>) -> Result<Vec<Element>, ParseError> {
+    let mut rest = input;
+    let mut elements = Vec::new();
+    while let Some(first) = rest.chars().next() {
+        if !first.is_ascii_alphabetic() {
+            return Err(ParseError::invalid("element", &rest[..first.len_utf8()]));
+        }
+        let len = 1 + rest[1..]
+            .find(|c: char| !c.is_ascii_lowercase())
+            .unwrap_or(rest.len() - 1);
+        let (name, remaining) = rest.split_at(len);
+        let element = match names.iter().position(|&other| other == name) {
+            Some(idx) => idx,
+            None => {
+                names.push(name);
+                names.len() - 1
+            }
+        };
+        elements
+            .push(Element::try_from(element).map_err(|_| ParseError::new("too many elements"))?);
+        rest = remaining;
+    }
+    if elements.is_empty() {
+        return Err(ParseError::missing("molecule").span(input));
+    }
+    Ok(elements)
+}
+
+impl FromStr for Machine {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (replacements, molecule) = s
+            .split_once("\n\n")
+            .ok_or_else(|| ParseError::missing("molecule"))?;
+        let mut names = vec!["e"];
+        let replacements = parse::lines(replacements, |line| {
+            let (from, to) = line
+                .split_once(" => ")
+                .ok_or_else(|| ParseError::invalid("replacement", line))?;
+            match elements(from, &mut names)?.as_slice() {
+                &[from] => Ok((from, elements(to, &mut names)?)),
+                _ => Err(ParseError::invalid("element", from)),
+            }
+        })?;
+        let molecule = elements(molecule.trim_end(), &mut names)?;
+        Ok(Machine {
+            replacements,
+            molecule,
+            electron: 0,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Machine;
+
+    #[test]
+    fn elements_respect_boundaries() {
+        let machine: Machine = "C => CC\nCa => X\n\nCaC".parse().unwrap();
+        assert_eq!(machine.calibrate(), 2);
+        assert!("e => H\n\nH1".parse::<Machine>().is_err());
+        assert!("eH => H\n\nH".parse::<Machine>().is_err());
+    }
+
+    #[test]
+    fn unreachable_molecule() {
+        let machine: Machine = "e => H\n\nO".parse().unwrap();
+        assert_eq!(machine.fabrication_steps(), None);
+    }
+}
@@ -0,0 +1,3 @@
+fn main() {
+    aoc::aoc_main::<medicine_for_rudolph::Solution>(aoc::input!());
+}
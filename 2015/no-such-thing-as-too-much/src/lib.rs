@@ -0,0 +1,63 @@
+use aoc::ParseError;
+use std::fmt::Display;
+
+pub const DAY: aoc::Day = aoc::day!(2015, 17, Solution);
+
+const EGGNOG: usize = 150;
+
+pub struct Solution;
+
+impl aoc::Solver for Solution {
+    type Input = Vec<usize>;
+    type Error = &'static str;
+
+    fn parse(input: &str) -> Result<Vec<usize>, ParseError> {
+        aoc::parse::lines(input, |line| {
+            aoc::parse::complete(line, aoc::parse::unsigned)
+        })
+    }
+
+    fn part1(containers: &Vec<usize>) -> Result<impl Display, &'static str> {
+        Ok(combinations(containers, EGGNOG).iter().sum::<u64>())
+    }
+
+    fn part2(containers: &Vec<usize>) -> Result<impl Display, &'static str> {
+        combinations(containers, EGGNOG)
+            .into_iter()
+            .find(|&ways| ways > 0)
+            .ok_or("the eggnog does not fit")
+    }
+}
+
+fn combinations(containers: &[usize], volume: usize) -> Vec<u64> {
+    let mut ways = vec![vec![0u64; volume + 1]; containers.len() + 1];
+    ways[0][0] = 1;
+    for &size in containers {
+        for count in (1..=containers.len()).rev() {
+            for filled in (size..=volume).rev() {
+                ways[count][filled] += ways[count - 1][filled - size];
+            }
+        }
+    }
+    ways.into_iter().map(|ways| ways[volume]).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{combinations, Solution};
+    use aoc::Solver;
+
+    const EXAMPLE: &str = include_str!("../assets/example.txt");
+
+    #[test]
+    fn example() {
+        let containers = Solution::parse(EXAMPLE).unwrap();
+        assert_eq!(combinations(&containers, 25), vec![0, 0, 3, 1, 0, 0]);
+    }
+
+    #[test]
+    fn oversized_container() {
+        assert_eq!(combinations(&[30, 10], 25), vec![0, 0, 0]);
+        assert_eq!(combinations(&[], 0), vec![1]);
+    }
+}
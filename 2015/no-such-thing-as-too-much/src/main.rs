@@ -0,0 +1,3 @@
+fn main() {
+    aoc::aoc_main::<no_such_thing_as_too_much::Solution>(aoc::input!());
+}
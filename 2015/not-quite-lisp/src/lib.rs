@@ -0,0 +1,117 @@
+use std::fmt::Display;
+
+pub const DAY: aoc::Day = aoc::day!(2015, 1, Solution);
+
+pub struct Solution;
+
+impl aoc::Solver for Solution {
+    type Input = String;
+    type Error = &'static str;
+
+    fn parse(input: &str) -> Result<String, aoc::ParseError> {
+        Ok(input.to_string())
+    }
+
+    fn part1(input: &String) -> Result<impl Display, &'static str> {
+        Ok(input.chars().map(get_value).sum::<i32>())
+    }
+
+    fn part2(input: &String) -> Result<impl Display, &'static str> {
+        find_index(input).ok_or("Not Found")
+    }
+}
+
+fn find_index(input: &str) -> Option<usize> {
+    let mut acc = 0;
+    for (idx, c) in input.chars().enumerate() {
+        acc += get_value(c);
+        if acc == -1 {
+            return Some(idx + 1);
+        }
+    }
+    None
+}
+
+fn get_value(c: char) -> i32 {
+    match c {
+        '(' => 1,
+        ')' => -1,
+        _ => 0,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    mod part1 {
+        use crate::Solution;
+        use aoc::Part;
+
+        #[test]
+        fn symetric() {
+            assert_eq!(aoc::solve::<Solution>("(())", Part::One).unwrap(), "0");
+        }
+
+        #[test]
+        fn inmediatly_closed() {
+            assert_eq!(aoc::solve::<Solution>("()()", Part::One).unwrap(), "0");
+        }
+
+        #[test]
+        fn only_open() {
+            assert_eq!(aoc::solve::<Solution>("(((", Part::One).unwrap(), "3");
+        }
+
+        #[test]
+        fn majority_open() {
+            assert_eq!(aoc::solve::<Solution>("(()(()(", Part::One).unwrap(), "3");
+        }
+
+        #[test]
+        fn majority_open_start_closing() {
+            assert_eq!(aoc::solve::<Solution>("))(((((", Part::One).unwrap(), "3");
+        }
+
+        #[test]
+        fn first_up_basement() {
+            assert_eq!(aoc::solve::<Solution>("())", Part::One).unwrap(), "-1");
+        }
+
+        #[test]
+        fn first_down_basement() {
+            assert_eq!(aoc::solve::<Solution>("))(", Part::One).unwrap(), "-1");
+        }
+
+        #[test]
+        fn only_close() {
+            assert_eq!(aoc::solve::<Solution>(")))", Part::One).unwrap(), "-3");
+        }
+
+        #[test]
+        fn mix_negative() {
+            assert_eq!(aoc::solve::<Solution>(")())())", Part::One).unwrap(), "-3");
+        }
+    }
+
+    mod part2 {
+        use crate::Solution;
+        use aoc::{Failure, Part};
+
+        #[test]
+        fn direct() {
+            assert_eq!(aoc::solve::<Solution>(")", Part::Two).unwrap(), "1");
+        }
+
+        #[test]
+        fn oscilate() {
+            assert_eq!(aoc::solve::<Solution>("()())", Part::Two).unwrap(), "5");
+        }
+
+        #[test]
+        fn not_found() {
+            assert_eq!(
+                aoc::solve::<Solution>("()()", Part::Two),
+                Err(Failure::Solve("Not Found".to_string()))
+            );
+        }
+    }
+}
@@ -0,0 +1,128 @@
+use aoc::{parse, ParseError};
+use std::str::FromStr;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Register {
+    A,
+    B,
+}
+
+impl FromStr for Register {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "a" => Ok(Register::A),
+            "b" => Ok(Register::B),
+            _ => Err(ParseError::invalid("register", s)),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Instruction {
+    Half(Register),
+    Triple(Register),
+    Increment(Register),
+    Jump(isize),
+    JumpIfEven(Register, isize),
+    JumpIfOne(Register, isize),
+}
+
+impl FromStr for Instruction {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        parse::complete(s, |input| {
+            let name = parse::identifier(input)?;
+            parse::spaces(input);
+            let register = |input: &mut &str| parse::identifier(input)?.parse::<Register>();
+            let instruction = match name {
+                "hlf" => Instruction::Half(register(input)?),
+                "tpl" => Instruction::Triple(register(input)?),
+                "inc" => Instruction::Increment(register(input)?),
+                "jmp" => Instruction::Jump(parse::signed(input)?),
+                "jie" | "jio" => {
+                    let register = register(input)?;
+                    parse::literal(input, ", ")?;
+                    let offset = parse::signed(input)?;
+                    if name == "jie" {
+                        Instruction::JumpIfEven(register, offset)
+                    } else {
+                        Instruction::JumpIfOne(register, offset)
+                    }
+                }
+                _ => return Err(ParseError::invalid("instruction", name)),
+            };
+            Ok(instruction)
+        })
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Computer {
+    registers: [u64; 2],
+}
+
+impl Computer {
+    pub fn new(a: u64) -> Computer {
+        Computer { registers: [a, 0] }
+    }
+
+    pub fn register(&self, register: Register) -> u64 {
+        self.registers[register as usize]
+    }
+
+    pub fn run(mut self, program: &[Instruction]) -> Computer {
+        let mut pc = 0;
+        while let Some(&instruction) = program.get(pc) {
+            let offset = match instruction {
+                Instruction::Half(register) => {
+                    self.registers[register as usize] /= 2;
+                    1
+                }
+                Instruction::Triple(register) => {
+                    self.registers[register as usize] *= 3;
+                    1
+                }
+                Instruction::Increment(register) => {
+                    self.registers[register as usize] += 1;
+                    1
+                }
+                Instruction::Jump(offset) => offset,
+                Instruction::JumpIfEven(register, offset)
+                    if self.register(register).is_multiple_of(2) =>
+                {
+                    offset
+                }
+                Instruction::JumpIfOne(register, offset) if self.register(register) == 1 => offset,
+                Instruction::JumpIfEven(..) | Instruction::JumpIfOne(..) => 1,
+            };
+            match pc.checked_add_signed(offset) {
+                Some(next) => pc = next,
+                None => break,
+            }
+        }
+        self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Instruction, Register};
+
+    #[test]
+    fn parse() {
+        assert_eq!(
+            "jio a, +2".parse::<Instruction>().ok(),
+            Some(Instruction::JumpIfOne(Register::A, 2))
+        );
+        assert_eq!(
+            "jmp -7".parse::<Instruction>().ok(),
+            Some(Instruction::Jump(-7))
+        );
+        let err = "inc c".parse::<Instruction>().unwrap_err();
+        assert_eq!(err.to_string(), "invalid register 'c'");
+        assert!("nop".parse::<Instruction>().is_err());
+    }
+}
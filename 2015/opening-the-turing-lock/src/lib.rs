@@ -0,0 +1,43 @@
+use computer::{Computer, Instruction, Register};
+use std::fmt::Display;
+
+mod computer;
+
+pub const DAY: aoc::Day = aoc::day!(2015, 23, Solution);
+
+pub struct Solution;
+
+impl aoc::Solver for Solution {
+    type Input = Vec<Instruction>;
+    type Error = &'static str;
+
+    fn parse(input: &str) -> Result<Vec<Instruction>, aoc::ParseError> {
+        aoc::parse::lines(input, str::parse)
+    }
+
+    fn part1(program: &Vec<Instruction>) -> Result<impl Display, &'static str> {
+        Ok(Computer::new(0).run(program).register(Register::B))
+    }
+
+    fn part2(program: &Vec<Instruction>) -> Result<impl Display, &'static str> {
+        Ok(Computer::new(1).run(program).register(Register::B))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        computer::{Computer, Register},
+        Solution,
+    };
+    use aoc::Solver;
+
+    const EXAMPLE: &str = include_str!("../assets/example.txt");
+
+    #[test]
+    fn example() {
+        let program = Solution::parse(EXAMPLE).unwrap();
+        assert_eq!(Computer::new(0).run(&program).register(Register::A), 2);
+        assert_eq!(Computer::new(1).run(&program).register(Register::A), 7);
+    }
+}
@@ -0,0 +1,3 @@
+fn main() {
+    aoc::aoc_main::<opening_the_turing_lock::Solution>(aoc::input!());
+}
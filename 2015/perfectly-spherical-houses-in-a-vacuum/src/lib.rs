@@ -0,0 +1,110 @@
+use std::{collections::HashSet, fmt::Display, hash::Hash, ops::AddAssign};
+
+pub const DAY: aoc::Day = aoc::day!(2015, 3, Solution);
+
+pub struct Solution;
+
+impl aoc::Solver for Solution {
+    type Input = std::vec::Vec<Vec>;
+    type Error = String;
+
+    fn parse(input: &str) -> Result<std::vec::Vec<Vec>, aoc::ParseError> {
+        input
+            .trim_end()
+            .chars()
+            .map(|c| Vec::try_from(c).map_err(aoc::ParseError::new))
+            .collect()
+    }
+
+    fn part1(offsets: &std::vec::Vec<Vec>) -> Result<impl Display, String> {
+        let mut s = HashSet::new();
+        let mut position = Vec::default();
+        s.insert(position);
+        for &offset in offsets {
+            position += offset;
+            s.insert(position);
+        }
+        Ok(s.len())
+    }
+
+    fn part2(offsets: &std::vec::Vec<Vec>) -> Result<impl Display, String> {
+        let mut s = HashSet::new();
+        let mut selector = 0;
+        let mut positions = [Vec::default(), Vec::default()];
+        s.insert(positions[selector]);
+        for &offset in offsets {
+            positions[selector] += offset;
+            s.insert(positions[selector]);
+            selector = 1 - selector;
+        }
+        Ok(s.len())
+    }
+}
+
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Vec {
+    v: [i32; 2],
+}
+
+impl AddAssign for Vec {
+    fn add_assign(&mut self, rhs: Self) {
+        self.v[0] += rhs.v[0];
+        self.v[1] += rhs.v[1];
+    }
+}
+
+impl TryFrom<char> for Vec {
+    type Error = String;
+
+    fn try_from(value: char) -> Result<Self, Self::Error> {
+        match value {
+            '^' => Ok(Vec { v: [0, 1] }),
+            'v' => Ok(Vec { v: [0, -1] }),
+            '>' => Ok(Vec { v: [1, 0] }),
+            '<' => Ok(Vec { v: [-1, 0] }),
+            c => Err(format!("invalid char `{c}`")),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::Solution;
+    use aoc::Part;
+
+    #[test]
+    fn houses_example1() {
+        assert_eq!(aoc::solve::<Solution>(">", Part::One).unwrap(), "2");
+    }
+
+    #[test]
+    fn houses_example2() {
+        assert_eq!(aoc::solve::<Solution>("^>v<", Part::One).unwrap(), "4");
+    }
+
+    #[test]
+    fn houses_example3() {
+        assert_eq!(
+            aoc::solve::<Solution>("^v^v^v^v^v", Part::One).unwrap(),
+            "2"
+        );
+    }
+
+    #[test]
+    fn robo_santa_example1() {
+        assert_eq!(aoc::solve::<Solution>("^v", Part::Two).unwrap(), "3");
+    }
+
+    #[test]
+    fn robo_santa_example2() {
+        assert_eq!(aoc::solve::<Solution>("^>v<", Part::Two).unwrap(), "3");
+    }
+
+    #[test]
+    fn robo_santa_example3() {
+        assert_eq!(
+            aoc::solve::<Solution>("^v^v^v^v^v", Part::Two).unwrap(),
+            "11"
+        );
+    }
+}
@@ -0,0 +1,67 @@
+use reindeer::Reindeer;
+use std::fmt::Display;
+
+mod reindeer;
+
+pub const DAY: aoc::Day = aoc::day!(2015, 14, Solution);
+
+const RACE: u32 = 2503;
+
+pub struct Solution;
+
+impl aoc::Solver for Solution {
+    type Input = Vec<Reindeer>;
+    type Error = &'static str;
+
+    fn parse(input: &str) -> Result<Vec<Reindeer>, aoc::ParseError> {
+        aoc::parse::lines(input, str::parse)
+    }
+
+    fn part1(reindeer: &Vec<Reindeer>) -> Result<impl Display, &'static str> {
+        winning_distance(reindeer, RACE).ok_or("no reindeer in the race")
+    }
+
+    fn part2(reindeer: &Vec<Reindeer>) -> Result<impl Display, &'static str> {
+        winning_points(reindeer, RACE).ok_or("no reindeer in the race")
+    }
+}
+
+fn winning_distance(reindeer: &[Reindeer], seconds: u32) -> Option<u32> {
+    reindeer
+        .iter()
+        .map(|reindeer| reindeer.distance(seconds))
+        .max()
+}
+
+fn winning_points(reindeer: &[Reindeer], seconds: u32) -> Option<u32> {
+    let mut points = vec![0; reindeer.len()];
+    for second in 1..=seconds {
+        let distances: Vec<u32> = reindeer
+            .iter()
+            .map(|reindeer| reindeer.distance(second))
+            .collect();
+        let lead = distances.iter().max()?;
+        for (points, distance) in points.iter_mut().zip(&distances) {
+            if distance == lead {
+                *points += 1;
+            }
+        }
+    }
+    points.into_iter().max()
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{winning_distance, winning_points, Solution};
+    use aoc::Solver;
+
+    const EXAMPLE: &str = include_str!("../assets/example.txt");
+
+    #[test]
+    fn example() {
+        let reindeer = Solution::parse(EXAMPLE).unwrap();
+        assert_eq!(winning_distance(&reindeer, 1000), Some(1120));
+        assert_eq!(winning_points(&reindeer, 1000), Some(689));
+        assert_eq!(winning_points(&reindeer, 140), Some(139));
+    }
+}
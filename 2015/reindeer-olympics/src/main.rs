@@ -0,0 +1,3 @@
+fn main() {
+    aoc::aoc_main::<reindeer_olympics::Solution>(aoc::input!());
+}
@@ -0,0 +1,61 @@
+use aoc::{parse, ParseError};
+use std::str::FromStr;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Reindeer {
+    speed: u32,
+    flying: u32,
+    resting: u32,
+}
+
+impl Reindeer {
+    pub fn distance(&self, seconds: u32) -> u32 {
+        let period = self.flying + self.resting;
+        let flown = seconds / period * self.flying + (seconds % period).min(self.flying);
+        flown * self.speed
+    }
+}
+
+impl FromStr for Reindeer {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        parse::complete(s, |input| {
+            parse::identifier(input)?;
+            parse::literal(input, " can fly ")?;
+            let speed = parse::unsigned(input)?;
+            parse::literal(input, " km/s for ")?;
+            let flying = parse::unsigned(input)?;
+            parse::literal(input, " seconds, but then must rest for ")?;
+            let resting = parse::unsigned(input)?;
+            parse::literal(input, " seconds.")?;
+            if flying + resting == 0 {
+                return Err(ParseError::new("reindeer never moves").span(s));
+            }
+            Ok(Reindeer {
+                speed,
+                flying,
+                resting,
+            })
+        })
+        .map_err(|err| err.context("reindeer"))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Reindeer;
+
+    #[test]
+    fn distance() {
+        let comet: Reindeer =
+            "Comet can fly 14 km/s for 10 seconds, but then must rest for 127 seconds."
+                .parse()
+                .unwrap();
+        assert_eq!(comet.distance(1), 14);
+        assert_eq!(comet.distance(10), 140);
+        assert_eq!(comet.distance(137), 140);
+        assert_eq!(comet.distance(138), 154);
+        assert_eq!(comet.distance(1000), 1120);
+    }
+}
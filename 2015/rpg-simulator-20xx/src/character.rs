@@ -0,0 +1,90 @@
+use aoc::{parse, ParseError};
+use std::{ops::Add, str::FromStr};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Item {
+    pub cost: u32,
+    damage: u32,
+    armor: u32,
+}
+
+impl Item {
+    pub const fn new(cost: u32, damage: u32, armor: u32) -> Item {
+        Item {
+            cost,
+            damage,
+            armor,
+        }
+    }
+}
+
+impl Add for Item {
+    type Output = Item;
+
+    fn add(self, rhs: Self) -> Self::Output {
+        Item::new(
+            self.cost + rhs.cost,
+            self.damage + rhs.damage,
+            self.armor + rhs.armor,
+        )
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Character {
+    hit_points: u32,
+    damage: u32,
+    armor: u32,
+}
+
+impl Character {
+    pub fn new(hit_points: u32, damage: u32, armor: u32) -> Character {
+        Character {
+            hit_points,
+            damage,
+            armor,
+        }
+    }
+
+    pub fn player(hit_points: u32, loadout: Item) -> Character {
+        Character::new(hit_points, loadout.damage, loadout.armor)
+    }
+
+    pub fn defeats(&self, other: &Character) -> bool {
+        self.turns_to_beat(other) <= other.turns_to_beat(self)
+    }
+
+    fn turns_to_beat(&self, other: &Character) -> u32 {
+        let damage = self.damage.saturating_sub(other.armor).max(1);
+        other.hit_points.div_ceil(damage)
+    }
+}
+
+impl FromStr for Character {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        parse::complete(s.trim_end(), |input| {
+            parse::literal(input, "Hit Points: ")?;
+            let hit_points = parse::unsigned(input)?;
+            parse::literal(input, "\nDamage: ")?;
+            let damage = parse::unsigned(input)?;
+            parse::literal(input, "\nArmor: ")?;
+            let armor = parse::unsigned(input)?;
+            Ok(Character::new(hit_points, damage, armor))
+        })
+        .map_err(|err| err.context("boss"))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Character;
+
+    #[test]
+    fn parse() {
+        let boss: Character = "Hit Points: 104\nDamage: 8\nArmor: 1\n".parse().unwrap();
+        assert_eq!(boss, Character::new(104, 8, 1));
+        assert!("Hit Points: 104\nDamage: 8".parse::<Character>().is_err());
+    }
+}
@@ -0,0 +1,103 @@
+use character::{Character, Item};
+use std::fmt::Display;
+
+mod character;
+
+pub const DAY: aoc::Day = aoc::day!(2015, 21, Solution);
+
+const HIT_POINTS: u32 = 100;
+
+const WEAPONS: [Item; 5] = [
+    Item::new(8, 4, 0),
+    Item::new(10, 5, 0),
+    Item::new(25, 6, 0),
+    Item::new(40, 7, 0),
+    Item::new(74, 8, 0),
+];
+
+const ARMOR: [Item; 5] = [
+    Item::new(13, 0, 1),
+    Item::new(31, 0, 2),
+    Item::new(53, 0, 3),
+    Item::new(75, 0, 4),
+    Item::new(102, 0, 5),
+];
+
+const RINGS: [Item; 6] = [
+    Item::new(25, 1, 0),
+    Item::new(50, 2, 0),
+    Item::new(100, 3, 0),
+    Item::new(20, 0, 1),
+    Item::new(40, 0, 2),
+    Item::new(80, 0, 3),
+];
+
+pub struct Solution;
+
+impl aoc::Solver for Solution {
+    type Input = Character;
+    type Error = &'static str;
+
+    fn parse(input: &str) -> Result<Character, aoc::ParseError> {
+        input.parse()
+    }
+
+    fn part1(boss: &Character) -> Result<impl Display, &'static str> {
+        loadouts()
+            .into_iter()
+            .filter(|loadout| Character::player(HIT_POINTS, *loadout).defeats(boss))
+            .map(|loadout| loadout.cost)
+            .min()
+            .ok_or("the boss cannot be beaten")
+    }
+
+    fn part2(boss: &Character) -> Result<impl Display, &'static str> {
+        loadouts()
+            .into_iter()
+            .filter(|loadout| !Character::player(HIT_POINTS, *loadout).defeats(boss))
+            .map(|loadout| loadout.cost)
+            .max()
+            .ok_or("the boss cannot be lost to")
+    }
+}
+
+fn loadouts() -> Vec<Item> {
+    let mut rings = vec![Vec::new()];
+    for (idx, &first) in RINGS.iter().enumerate() {
+        rings.push(vec![first]);
+        rings.extend(RINGS[idx + 1..].iter().map(|&second| vec![first, second]));
+    }
+    let mut loadouts = Vec::new();
+    for weapon in WEAPONS {
+        for armor in [None].into_iter().chain(ARMOR.map(Some)) {
+            for rings in &rings {
+                let items = armor.iter().chain(rings);
+                loadouts.push(items.fold(weapon, |total, &item| total + item));
+            }
+        }
+    }
+    loadouts
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{character::Character, loadouts};
+
+    #[test]
+    fn example_fight() {
+        let player = Character::new(8, 5, 5);
+        let boss = Character::new(12, 7, 2);
+        assert!(player.defeats(&boss));
+        assert!(!Character::new(8, 5, 4).defeats(&boss));
+    }
+
+    #[test]
+    fn shop_loadouts() {
+        assert_eq!(loadouts().len(), 5 * 6 * 22);
+        assert_eq!(loadouts().iter().map(|loadout| loadout.cost).min(), Some(8));
+        assert_eq!(
+            loadouts().iter().map(|loadout| loadout.cost).max(),
+            Some(356)
+        );
+    }
+}
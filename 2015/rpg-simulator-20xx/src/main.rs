@@ -0,0 +1,3 @@
+fn main() {
+    aoc::aoc_main::<rpg_simulator_20xx::Solution>(aoc::input!());
+}
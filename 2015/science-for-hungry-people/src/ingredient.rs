@@ -0,0 +1,98 @@
+use aoc::{parse, ParseError};
+use std::{
+    ops::{Add, Mul},
+    str::FromStr,
+};
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct Ingredient {
+    pub capacity: i64,
+    pub durability: i64,
+    pub flavor: i64,
+    pub texture: i64,
+    pub calories: i64,
+}
+
+impl Ingredient {
+    pub fn score(&self) -> i64 {
+        [self.capacity, self.durability, self.flavor, self.texture]
+            .into_iter()
+            .map(|property| property.max(0))
+            .product()
+    }
+}
+
+impl Add for Ingredient {
+    type Output = Ingredient;
+
+    fn add(self, rhs: Self) -> Self::Output {
+        Ingredient {
+            capacity: self.capacity + rhs.capacity,
+            durability: self.durability + rhs.durability,
+            flavor: self.flavor + rhs.flavor,
+            texture: self.texture + rhs.texture,
+            calories: self.calories + rhs.calories,
+        }
+    }
+}
+
+impl Mul<i64> for Ingredient {
+    type Output = Ingredient;
+
+    fn mul(self, rhs: i64) -> Self::Output {
+        Ingredient {
+            capacity: self.capacity * rhs,
+            durability: self.durability * rhs,
+            flavor: self.flavor * rhs,
+            texture: self.texture * rhs,
+            calories: self.calories * rhs,
+        }
+    }
+}
+
+impl FromStr for Ingredient {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        parse::complete(s, |input| {
+            parse::identifier(input)?;
+            parse::literal(input, ": ")?;
+            let mut property = |name, separator| {
+                parse::literal(input, name)?;
+                parse::literal(input, " ")?;
+                let value = parse::signed(input)?;
+                parse::literal(input, separator)?;
+                Ok::<_, ParseError>(value)
+            };
+            Ok(Ingredient {
+                capacity: property("capacity", ", ")?,
+                durability: property("durability", ", ")?,
+                flavor: property("flavor", ", ")?,
+                texture: property("texture", ", ")?,
+                calories: property("calories", "")?,
+            })
+        })
+        .map_err(|err| err.context("ingredient"))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Ingredient;
+
+    #[test]
+    fn score() {
+        let butterscotch: Ingredient =
+            "Butterscotch: capacity -1, durability -2, flavor 6, texture 3, calories 8"
+                .parse()
+                .unwrap();
+        let cinnamon: Ingredient =
+            "Cinnamon: capacity 2, durability 3, flavor -2, texture -1, calories 3"
+                .parse()
+                .unwrap();
+        let cookie = butterscotch * 44 + cinnamon * 56;
+        assert_eq!(cookie.score(), 62842880);
+        assert_eq!((butterscotch * 100).score(), 0);
+        assert!("Sugar: capacity 3".parse::<Ingredient>().is_err());
+    }
+}
@@ -0,0 +1,76 @@
+use ingredient::Ingredient;
+use std::fmt::Display;
+
+mod ingredient;
+
+pub const DAY: aoc::Day = aoc::day!(2015, 15, Solution);
+
+const TEASPOONS: i64 = 100;
+const CALORIES: i64 = 500;
+
+pub struct Solution;
+
+impl aoc::Solver for Solution {
+    type Input = Vec<Ingredient>;
+    type Error = &'static str;
+
+    fn parse(input: &str) -> Result<Vec<Ingredient>, aoc::ParseError> {
+        aoc::parse::lines(input, str::parse)
+    }
+
+    fn part1(ingredients: &Vec<Ingredient>) -> Result<impl Display, &'static str> {
+        best_score(ingredients, |_| true).ok_or("no ingredients")
+    }
+
+    fn part2(ingredients: &Vec<Ingredient>) -> Result<impl Display, &'static str> {
+        best_score(ingredients, |cookie| cookie.calories == CALORIES)
+            .ok_or("no cookie has the right calories")
+    }
+}
+
+fn best_score(ingredients: &[Ingredient], accept: fn(&Ingredient) -> bool) -> Option<i64> {
+    fn mix(
+        ingredients: &[Ingredient],
+        remaining: i64,
+        cookie: Ingredient,
+        accept: fn(&Ingredient) -> bool,
+    ) -> Option<i64> {
+        match ingredients {
+            [] => None,
+            [last] => {
+                let cookie = cookie + *last * remaining;
+                accept(&cookie).then(|| cookie.score())
+            }
+            [first, rest @ ..] => (0..=remaining)
+                .filter_map(|amount| {
+                    mix(rest, remaining - amount, cookie + *first * amount, accept)
+                })
+                .max(),
+        }
+    }
+    mix(ingredients, TEASPOONS, Ingredient::default(), accept)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::Solution;
+    use aoc::Part;
+
+    const EXAMPLE: &str = include_str!("../assets/example.txt");
+
+    #[test]
+    fn example_part1() {
+        assert_eq!(
+            aoc::solve::<Solution>(EXAMPLE, Part::One).unwrap(),
+            "62842880"
+        );
+    }
+
+    #[test]
+    fn example_part2() {
+        assert_eq!(
+            aoc::solve::<Solution>(EXAMPLE, Part::Two).unwrap(),
+            "57600000"
+        );
+    }
+}
@@ -0,0 +1,3 @@
+fn main() {
+    aoc::aoc_main::<science_for_hungry_people::Solution>(aoc::input!());
+}
@@ -0,0 +1,30 @@
+use std::fmt::Display;
+
+pub const DAY: aoc::Day = aoc::day!(2015, 7, Solution);
+
+pub struct Solution;
+
+impl aoc::Solver for Solution {
+    type Input = String;
+    type Error = &'static str;
+
+    fn parse(input: &str) -> Result<String, aoc::ParseError> {
+        Ok(input.to_string())
+    }
+
+    fn part1(_input: &String) -> Result<impl Display, &'static str> {
+        Ok(0)
+    }
+
+    fn part2(_input: &String) -> Result<impl Display, &'static str> {
+        Ok(0)
+    }
+}
+
+mod statement;
+use statement::Statement;
+
+mod expression;
+use expression::Expression;
+
+mod solver;
@@ -1,21 +1,3 @@
-use std::fmt::Display;
-
 fn main() {
-    aoc::aoc_main(aoc::input!(), part1, part2)
+    aoc::aoc_main::<some_assembly_required::Solution>(aoc::input!());
 }
-
-fn part1(input: &str) -> impl Display {
-    0
-}
-
-fn part2(input: &str) -> impl Display {
-    0
-}
-
-mod statement;
-use statement::Statement;
-
-mod expression;
-use expression::Expression;
-
-mod solver;
\ No newline at end of file
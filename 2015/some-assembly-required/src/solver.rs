@@ -1,5 +1,5 @@
 use crate::{
-    expression::{Expression, Value, Op},
+    expression::{Expression, Op, Value},
     statement::Statement,
 };
 use std::collections::{btree_map::Values, HashMap};
@@ -65,7 +65,7 @@ impl<'a> SolveState<'a> {
             Op::And(l, r) => {
                 todo!()
             }
-            _ => todo!()
+            _ => todo!(),
         }
     }
 }
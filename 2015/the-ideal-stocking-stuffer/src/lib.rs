@@ -0,0 +1,63 @@
+use aoc::md5::Md5;
+use std::{fmt::Display, io::Write};
+
+pub const DAY: aoc::Day = aoc::day!(2015, 4, Solution);
+
+pub struct Solution;
+
+impl aoc::Solver for Solution {
+    type Input = String;
+    type Error = &'static str;
+
+    fn parse(input: &str) -> Result<String, aoc::ParseError> {
+        Ok(input.trim_end().to_string())
+    }
+
+    fn part1(secret: &String) -> Result<impl Display, &'static str> {
+        Ok(find_zeros(secret, 5))
+    }
+
+    fn part2(secret: &String) -> Result<impl Display, &'static str> {
+        Ok(find_zeros(secret, 6))
+    }
+}
+
+fn find_zeros(input: &str, hex_digit_count: u32) -> u32 {
+    let bit_count = hex_digit_count * 4;
+    let mut prefix = Md5::new();
+    prefix.update(input);
+    aoc::par::find_first(0..u32::MAX as usize, 4096, |i| {
+        let mut digits = [0u8; 10];
+        let len = {
+            let mut buffer = &mut digits[..];
+            write!(buffer, "{i}").unwrap();
+            10 - buffer.len()
+        };
+        let mut md5 = prefix.clone();
+        md5.update(&digits[..len]);
+        md5.finalize().leading_zeros() >= bit_count
+    })
+    .expect("no matching hash") as u32
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::Solution;
+    use aoc::Part;
+
+    #[test]
+    fn example1() {
+        assert_eq!(
+            aoc::solve::<Solution>("abcdef", Part::One).unwrap(),
+            "609043"
+        );
+    }
+
+    #[test]
+    fn example2() {
+        assert_eq!(
+            aoc::solve::<Solution>("pqrstuv", Part::One).unwrap(),
+            "1048970"
+        );
+    }
+}
@@ -0,0 +1,161 @@
+use aoc::{parse, search, ParseError};
+use std::str::FromStr;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Boss {
+    hit_points: i32,
+    damage: i32,
+}
+
+impl FromStr for Boss {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        parse::complete(s.trim_end(), |input| {
+            parse::literal(input, "Hit Points: ")?;
+            let hit_points = parse::unsigned(input)?;
+            parse::literal(input, "\nDamage: ")?;
+            let damage = parse::unsigned(input)?;
+            Ok(Boss { hit_points, damage })
+        })
+        .map_err(|err| err.context("boss"))
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Spell {
+    MagicMissile,
+    Drain,
+    Shield,
+    Poison,
+    Recharge,
+}
+
+impl Spell {
+    const ALL: [Spell; 5] = [
+        Spell::MagicMissile,
+        Spell::Drain,
+        Spell::Shield,
+        Spell::Poison,
+        Spell::Recharge,
+    ];
+
+    fn cost(self) -> i32 {
+        match self {
+            Spell::MagicMissile => 53,
+            Spell::Drain => 73,
+            Spell::Shield => 113,
+            Spell::Poison => 173,
+            Spell::Recharge => 229,
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Battle {
+    hit_points: i32,
+    mana: i32,
+    boss_hit_points: i32,
+    boss_damage: i32,
+    shield: u8,
+    poison: u8,
+    recharge: u8,
+}
+
+impl Battle {
+    pub fn new(hit_points: i32, mana: i32, boss: &Boss) -> Battle {
+        Battle {
+            hit_points,
+            mana,
+            boss_hit_points: boss.hit_points,
+            boss_damage: boss.damage,
+            shield: 0,
+            poison: 0,
+            recharge: 0,
+        }
+    }
+
+    pub fn least_mana(&self, hard: bool) -> Option<i32> {
+        search::dijkstra(
+            [self.clone()],
+            |battle| {
+                Spell::ALL
+                    .into_iter()
+                    .filter_map(|spell| Some((battle.round(spell, hard)?, spell.cost())))
+                    .collect::<Vec<_>>()
+            },
+            |battle| battle.boss_hit_points <= 0,
+        )
+        .cost()
+    }
+
+    fn round(&self, spell: Spell, hard: bool) -> Option<Battle> {
+        let mut battle = self.clone();
+        if hard {
+            battle.hit_points -= 1;
+            if battle.hit_points <= 0 {
+                return None;
+            }
+        }
+        battle.apply_effects();
+        if battle.boss_hit_points <= 0 {
+            return Some(battle.won());
+        }
+        battle.cast(spell)?;
+        if battle.boss_hit_points <= 0 {
+            return Some(battle.won());
+        }
+        let armor = battle.apply_effects();
+        if battle.boss_hit_points <= 0 {
+            return Some(battle.won());
+        }
+        battle.hit_points -= (battle.boss_damage - armor).max(1);
+        (battle.hit_points > 0).then_some(battle)
+    }
+
+    fn apply_effects(&mut self) -> i32 {
+        let mut armor = 0;
+        if self.shield > 0 {
+            armor = 7;
+            self.shield -= 1;
+        }
+        if self.poison > 0 {
+            self.boss_hit_points -= 3;
+            self.poison -= 1;
+        }
+        if self.recharge > 0 {
+            self.mana += 101;
+            self.recharge -= 1;
+        }
+        armor
+    }
+
+    fn cast(&mut self, spell: Spell) -> Option<()> {
+        if spell.cost() > self.mana {
+            return None;
+        }
+        self.mana -= spell.cost();
+        match spell {
+            Spell::MagicMissile => self.boss_hit_points -= 4,
+            Spell::Drain => {
+                self.boss_hit_points -= 2;
+                self.hit_points += 2;
+            }
+            Spell::Shield if self.shield == 0 => self.shield = 6,
+            Spell::Poison if self.poison == 0 => self.poison = 6,
+            Spell::Recharge if self.recharge == 0 => self.recharge = 5,
+            _ => return None,
+        }
+        Some(())
+    }
+
+    fn won(self) -> Battle {
+        Battle {
+            boss_hit_points: 0,
+            shield: 0,
+            poison: 0,
+            recharge: 0,
+            ..self
+        }
+    }
+}
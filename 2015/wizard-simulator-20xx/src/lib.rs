@@ -0,0 +1,46 @@
+use battle::{Battle, Boss};
+use std::fmt::Display;
+
+mod battle;
+
+pub const DAY: aoc::Day = aoc::day!(2015, 22, Solution);
+
+const HIT_POINTS: i32 = 50;
+const MANA: i32 = 500;
+
+pub struct Solution;
+
+impl aoc::Solver for Solution {
+    type Input = Boss;
+    type Error = &'static str;
+
+    fn parse(input: &str) -> Result<Boss, aoc::ParseError> {
+        input.parse()
+    }
+
+    fn part1(boss: &Boss) -> Result<impl Display, &'static str> {
+        Battle::new(HIT_POINTS, MANA, boss)
+            .least_mana(false)
+            .ok_or("the boss cannot be beaten")
+    }
+
+    fn part2(boss: &Boss) -> Result<impl Display, &'static str> {
+        Battle::new(HIT_POINTS, MANA, boss)
+            .least_mana(true)
+            .ok_or("the boss cannot be beaten")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::battle::{Battle, Boss};
+
+    #[test]
+    fn example_battles() {
+        let boss: Boss = "Hit Points: 13\nDamage: 8".parse().unwrap();
+        assert_eq!(Battle::new(10, 250, &boss).least_mana(false), Some(226));
+        let boss: Boss = "Hit Points: 14\nDamage: 8".parse().unwrap();
+        assert_eq!(Battle::new(10, 250, &boss).least_mana(false), Some(641));
+        assert_eq!(Battle::new(10, 250, &boss).least_mana(true), None);
+    }
+}
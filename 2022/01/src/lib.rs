@@ -0,0 +1,75 @@
+use std::{cmp, collections::BinaryHeap, fmt::Display};
+
+pub const DAY: aoc::Day = aoc::day!(2022, 1, Solution);
+
+pub struct Solution;
+
+impl aoc::Solver for Solution {
+    type Input = Vec<i32>;
+    type Error = &'static str;
+
+    fn parse(input: &str) -> Result<Vec<i32>, aoc::ParseError> {
+        input
+            .trim_end()
+            .split("\n\n")
+            .map(get_calorie_count)
+            .collect()
+    }
+
+    fn part1(elf_calories: &Vec<i32>) -> Result<impl Display, &'static str> {
+        Ok(*elf_calories.iter().max().unwrap_or(&0))
+    }
+
+    fn part2(elf_calories: &Vec<i32>) -> Result<impl Display, &'static str> {
+        let mut top = TopN::new(3);
+        for cal in elf_calories {
+            top.combine(*cal);
+        }
+        Ok(top.calories.into_iter().map(|v| v.0).sum::<i32>())
+    }
+}
+
+#[derive(Clone)]
+struct TopN {
+    n: usize,
+    calories: BinaryHeap<cmp::Reverse<i32>>,
+}
+
+impl TopN {
+    fn new(n: usize) -> Self {
+        Self {
+            n,
+            calories: BinaryHeap::new(),
+        }
+    }
+
+    fn combine(&mut self, new: i32) {
+        self.calories.push(cmp::Reverse(new));
+        if self.calories.len() > self.n {
+            self.calories.pop();
+        }
+    }
+}
+
+fn get_calorie_count(s: &str) -> Result<i32, aoc::ParseError> {
+    s.lines()
+        .map(|s| {
+            s.parse::<i32>()
+                .map_err(|err| aoc::ParseError::invalid("calories", s).with_cause(err))
+        })
+        .sum()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Solution;
+    use aoc::Part;
+
+    const EXAMPLE: &str = "1000\n2000\n3000\n\n4000\n\n5000\n6000\n\n7000\n8000\n9000\n\n10000\n";
+
+    #[test]
+    fn example() {
+        assert_eq!(aoc::solve::<Solution>(EXAMPLE, Part::One).unwrap(), "24000");
+        assert_eq!(aoc::solve::<Solution>(EXAMPLE, Part::Two).unwrap(), "45000");
+    }
+}
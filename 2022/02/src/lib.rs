@@ -0,0 +1,211 @@
+use aoc::ParseError;
+use std::{fmt::Display, str::FromStr};
+
+pub const DAY: aoc::Day = aoc::day!(2022, 2, Solution);
+
+pub struct Solution;
+
+impl aoc::Solver for Solution {
+    type Input = Vec<(Round, AlternativeRound)>;
+    type Error = &'static str;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        input
+            .lines()
+            .filter(|l| !l.is_empty())
+            .map(|l| Ok((l.parse()?, l.parse()?)))
+            .collect()
+    }
+
+    fn part1(rounds: &Self::Input) -> Result<impl Display, &'static str> {
+        Ok(rounds.iter().map(|(r, _)| r.get_score()).sum::<u32>())
+    }
+
+    fn part2(rounds: &Self::Input) -> Result<impl Display, &'static str> {
+        Ok(rounds.iter().map(|(_, r)| r.get_score()).sum::<u32>())
+    }
+}
+
+trait Scoreable {
+    fn get_score(&self) -> u32;
+}
+
+#[derive(Debug, Clone)]
+enum Move {
+    Rock,
+    Paper,
+    Scissors,
+}
+
+impl FromStr for Move {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "A" => Ok(Move::Rock),
+            "B" => Ok(Move::Paper),
+            "C" => Ok(Move::Scissors),
+            "X" => Ok(Move::Rock),
+            "Y" => Ok(Move::Paper),
+            "Z" => Ok(Move::Scissors),
+            _ => Err(ParseError::invalid("move", s)),
+        }
+    }
+}
+
+impl Scoreable for Move {
+    fn get_score(&self) -> u32 {
+        match self {
+            Move::Rock => 1,
+            Move::Paper => 2,
+            Move::Scissors => 3,
+        }
+    }
+}
+
+impl Move {
+    fn wins(&self, other: &Self) -> bool {
+        match (self, other) {
+            (Move::Rock, Move::Scissors) => true,
+            (Move::Paper, Move::Rock) => true,
+            (Move::Scissors, Move::Paper) => true,
+            _ => false,
+        }
+    }
+
+    fn apply_result(&self, outcome: &Outcome) -> Move {
+        match outcome {
+            Outcome::Loss => self.get_losing(),
+            Outcome::Draw => self.clone(),
+            Outcome::Win => self.get_wining(),
+        }
+    }
+
+    fn get_wining(&self) -> Move {
+        match self {
+            Move::Rock => Move::Paper,
+            Move::Paper => Move::Scissors,
+            Move::Scissors => Move::Rock,
+        }
+    }
+
+    fn get_losing(&self) -> Move {
+        match self {
+            Move::Rock => Move::Scissors,
+            Move::Paper => Move::Rock,
+            Move::Scissors => Move::Paper,
+        }
+    }
+}
+
+#[derive(Debug)]
+enum Outcome {
+    Loss,
+    Draw,
+    Win,
+}
+
+impl FromStr for Outcome {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let splits = s.split_whitespace().into_iter().collect::<Vec<_>>();
+        let other_move = splits
+            .get(0)
+            .ok_or_else(|| ParseError::missing("elf move").span(s))?
+            .parse::<Move>()?;
+        let self_move = splits
+            .get(1)
+            .ok_or_else(|| ParseError::missing("user move").span(s))?
+            .parse::<Move>()?;
+        match (self_move.wins(&other_move), other_move.wins(&self_move)) {
+            (true, false) => Ok(Self::Win),
+            (false, true) => Ok(Self::Loss),
+            (false, false) => Ok(Self::Draw),
+            _ => Err(ParseError::invalid("result", s)),
+        }
+    }
+}
+
+pub struct AlternativeRound(Round);
+
+impl FromStr for AlternativeRound {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let splits = s.split_whitespace().into_iter().collect::<Vec<_>>();
+        let outcome = match splits
+            .get(1)
+            .ok_or_else(|| ParseError::missing("user move").span(s))?
+            .as_ref()
+        {
+            "X" => Ok(Outcome::Loss),
+            "Y" => Ok(Outcome::Draw),
+            "Z" => Ok(Outcome::Win),
+            _ => Err(ParseError::invalid("result", s)),
+        }?;
+        let hand = splits
+            .get(0)
+            .ok_or_else(|| ParseError::missing("elf move").span(s))?
+            .parse::<Move>()?
+            .apply_result(&outcome);
+        Ok(AlternativeRound(Round { hand, outcome }))
+    }
+}
+
+impl Scoreable for AlternativeRound {
+    fn get_score(&self) -> u32 {
+        self.0.get_score()
+    }
+}
+
+impl Scoreable for Outcome {
+    fn get_score(&self) -> u32 {
+        match self {
+            Outcome::Loss => 0,
+            Outcome::Draw => 3,
+            Outcome::Win => 6,
+        }
+    }
+}
+
+#[derive(Debug)]
+pub struct Round {
+    hand: Move,
+    outcome: Outcome,
+}
+
+impl FromStr for Round {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let outcome = s.parse::<Outcome>()?;
+        let hand = s
+            .split_whitespace()
+            .into_iter()
+            .nth(1)
+            .ok_or_else(|| ParseError::missing("user move").span(s))?
+            .parse::<Move>()?;
+        Ok(Round { hand, outcome })
+    }
+}
+
+impl Scoreable for Round {
+    fn get_score(&self) -> u32 {
+        self.hand.get_score() + self.outcome.get_score()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Solution;
+    use aoc::Part;
+
+    const EXAMPLE: &str = "A Y\nB X\nC Z\n";
+
+    #[test]
+    fn example() {
+        assert_eq!(aoc::solve::<Solution>(EXAMPLE, Part::One).unwrap(), "15");
+        assert_eq!(aoc::solve::<Solution>(EXAMPLE, Part::Two).unwrap(), "12");
+    }
+}
@@ -0,0 +1,148 @@
+use aoc::ParseError;
+use std::{collections::HashSet, fmt::Display, str::FromStr};
+
+pub const DAY: aoc::Day = aoc::day!(2022, 3, Solution);
+
+pub struct Solution;
+
+impl aoc::Solver for Solution {
+    type Input = Vec<Rucksack>;
+    type Error = &'static str;
+
+    fn parse(input: &str) -> Result<Vec<Rucksack>, ParseError> {
+        input.lines().map(str::parse).collect()
+    }
+
+    fn part1(rucksacks: &Vec<Rucksack>) -> Result<impl Display, &'static str> {
+        rucksacks
+            .iter()
+            .map(|r| r.get_priority())
+            .sum::<Result<u32, _>>()
+    }
+
+    fn part2(rucksacks: &Vec<Rucksack>) -> Result<impl Display, &'static str> {
+        ElfGroup::from_ruckstacks(rucksacks.iter().cloned())
+            .iter()
+            .map(|g| g.get_priority())
+            .sum::<Result<u32, _>>()
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct Rucksack {
+    content: String,
+}
+
+trait CommonChar {
+    fn get_common_char(&self) -> Result<char, &'static str>;
+    fn get_priority(&self) -> Result<u32, &'static str> {
+        let c = self.get_common_char()?;
+        if c.is_ascii_lowercase() {
+            Ok(c as u32 - 'a' as u32 + 1)
+        } else if c.is_ascii_uppercase() {
+            Ok(c as u32 - 'A' as u32 + 27)
+        } else {
+            Err("invalid item")
+        }
+    }
+}
+
+impl FromStr for Rucksack {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        if s.len() % 2 != 0 {
+            Err(ParseError::new("odd rucksack length").span(s))
+        } else {
+            Ok(Rucksack {
+                content: s.to_string(),
+            })
+        }
+    }
+}
+
+impl Rucksack {
+    fn get_set(&self) -> HashSet<char> {
+        self.content.chars().into_iter().collect()
+    }
+}
+
+impl CommonChar for Rucksack {
+    fn get_common_char(&self) -> Result<char, &'static str> {
+        let split_size = self.content.len() / 2;
+        let char_vec = self.content.chars().collect::<Vec<_>>();
+        let first = char_vec[..split_size]
+            .iter()
+            .cloned()
+            .collect::<HashSet<_>>();
+        let second = char_vec[split_size..]
+            .iter()
+            .cloned()
+            .collect::<HashSet<_>>();
+        let intersection = first.intersection(&second).cloned().collect::<Vec<_>>();
+        if intersection.len() != 1 {
+            Err("no unique common item")
+        } else {
+            Ok(*intersection.get(0).unwrap())
+        }
+    }
+}
+
+struct ElfGroup {
+    rucksacks: [Rucksack; 3],
+}
+
+impl ElfGroup {
+    fn from_ruckstacks<I>(rucksacks: I) -> Vec<ElfGroup>
+    where
+        I: IntoIterator<Item = Rucksack>,
+    {
+        let mut groups = Vec::new();
+        let mut new: Vec<_> = Vec::new();
+        for rucksack in rucksacks {
+            new.push(rucksack);
+            if new.len() == 3 {
+                groups.push(ElfGroup {
+                    rucksacks: [new.pop().unwrap(), new.pop().unwrap(), new.pop().unwrap()],
+                })
+            }
+        }
+        groups
+    }
+}
+
+impl CommonChar for ElfGroup {
+    fn get_common_char(&self) -> Result<char, &'static str> {
+        let intersection = self
+            .rucksacks
+            .iter()
+            .map(|r| r.get_set())
+            .reduce(|a, b| a.intersection(&b).cloned().collect::<HashSet<_>>())
+            .ok_or("empty group")?;
+        if intersection.len() != 1 {
+            Err("no unique common item")
+        } else {
+            Ok(*intersection.iter().next().unwrap())
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Solution;
+    use aoc::Part;
+
+    const EXAMPLE: &str = "vJrwpWtwJgWrhcsFMMfFFhFp
+jqHRNqRjqzjGDLGLrsFMfFZSrLrFZsSL
+PmmdzqPrVvPwwTWBwg
+wMqvLMZHhHMvwLHjbvcjnnSBnvTQFn
+ttgJtRGJQctTZtZT
+CrZsJsPPZsGzwwsLwLmpwMDw
+";
+
+    #[test]
+    fn example() {
+        assert_eq!(aoc::solve::<Solution>(EXAMPLE, Part::One).unwrap(), "157");
+        assert_eq!(aoc::solve::<Solution>(EXAMPLE, Part::Two).unwrap(), "70");
+    }
+}
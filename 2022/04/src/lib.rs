@@ -0,0 +1,79 @@
+use aoc::{interval::Interval, ParseError};
+use std::{fmt::Display, str::FromStr};
+
+pub const DAY: aoc::Day = aoc::day!(2022, 4, Solution);
+
+pub struct Solution;
+
+impl aoc::Solver for Solution {
+    type Input = Vec<ElfPair>;
+    type Error = &'static str;
+
+    fn parse(input: &str) -> Result<Vec<ElfPair>, ParseError> {
+        input.lines().map(str::parse).collect()
+    }
+
+    fn part1(pairs: &Vec<ElfPair>) -> Result<impl Display, &'static str> {
+        Ok(pairs
+            .iter()
+            .filter(|p| p.first.covers(&p.second) || p.second.covers(&p.first))
+            .count())
+    }
+
+    fn part2(pairs: &Vec<ElfPair>) -> Result<impl Display, &'static str> {
+        Ok(pairs.iter().filter(|p| p.first.overlaps(&p.second)).count())
+    }
+}
+
+fn parse_range(s: &str) -> Result<Interval<u32>, ParseError> {
+    let mut splits = s.split("-");
+    let start = parse_section(splits.next(), "start", s)?;
+    let end = parse_section(splits.next(), "end", s)?;
+    Ok(Interval::inclusive(start, end))
+}
+
+pub struct ElfPair {
+    first: Interval<u32>,
+    second: Interval<u32>,
+}
+
+impl FromStr for ElfPair {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut splits = s.split(",");
+        let first = parse_range(
+            splits
+                .next()
+                .ok_or_else(|| ParseError::missing("first range").span(s))?,
+        )?;
+        let second = parse_range(
+            splits
+                .next()
+                .ok_or_else(|| ParseError::missing("second range").span(s))?,
+        )?;
+        Ok(ElfPair { first, second })
+    }
+}
+
+fn parse_section(section: Option<&str>, what: &str, range: &str) -> Result<u32, ParseError> {
+    let section =
+        section.ok_or_else(|| ParseError::new(format!("missing range {what}")).span(range))?;
+    section
+        .parse()
+        .map_err(|err| ParseError::invalid("section", section).with_cause(err))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Solution;
+    use aoc::Part;
+
+    const EXAMPLE: &str = "2-4,6-8\n2-3,4-5\n5-7,7-9\n2-8,3-7\n6-6,4-6\n2-6,4-8\n";
+
+    #[test]
+    fn example() {
+        assert_eq!(aoc::solve::<Solution>(EXAMPLE, Part::One).unwrap(), "2");
+        assert_eq!(aoc::solve::<Solution>(EXAMPLE, Part::Two).unwrap(), "4");
+    }
+}
@@ -0,0 +1,191 @@
+use aoc::ParseError;
+use std::{fmt::Display, str::FromStr};
+
+pub const DAY: aoc::Day = aoc::day!(2022, 5, Solution);
+
+pub struct Solution;
+
+impl aoc::Solver for Solution {
+    type Input = (Shipyard, Vec<Instruction>);
+    type Error = &'static str;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        let (shipyard, instructions) = input
+            .trim_end()
+            .split_once("\n\n")
+            .ok_or_else(|| ParseError::missing("instructions"))?;
+        let shipyard = shipyard
+            .parse()
+            .map_err(|err: ParseError| err.context("shipyard"))?;
+        let instructions = instructions
+            .lines()
+            .map(str::parse)
+            .collect::<Result<_, _>>()?;
+        Ok((shipyard, instructions))
+    }
+
+    fn part1((shipyard, instructions): &Self::Input) -> Result<impl Display, &'static str> {
+        let mut shipyard = shipyard.clone();
+        for instruction in instructions {
+            shipyard.apply(instruction)?;
+        }
+        Ok(shipyard.top())
+    }
+
+    fn part2((shipyard, instructions): &Self::Input) -> Result<impl Display, &'static str> {
+        let mut shipyard = shipyard.clone();
+        for instruction in instructions {
+            shipyard.apply_grouped(instruction)?;
+        }
+        Ok(shipyard.top())
+    }
+}
+
+#[derive(Clone)]
+struct Stack {
+    crates: Vec<char>,
+}
+
+impl Stack {
+    fn new() -> Stack {
+        Self { crates: Vec::new() }
+    }
+}
+
+#[derive(Clone)]
+pub struct Shipyard {
+    stacks: Vec<Stack>,
+}
+
+impl Shipyard {
+    fn new(n: usize) -> Shipyard {
+        let mut stacks = Vec::with_capacity(n);
+        for _ in 0..n {
+            stacks.push(Stack::new())
+        }
+        Shipyard { stacks }
+    }
+
+    fn apply(&mut self, inst: &Instruction) -> Result<(), &'static str> {
+        for _ in 0..inst.count {
+            let value = self
+                .stacks
+                .get_mut(inst.start)
+                .ok_or("invalid start stack")?
+                .crates
+                .pop()
+                .ok_or("empty stack")?;
+            self.stacks
+                .get_mut(inst.end)
+                .ok_or("invalid end stack")?
+                .crates
+                .push(value)
+        }
+        Ok(())
+    }
+
+    fn apply_grouped(&mut self, inst: &Instruction) -> Result<(), &'static str> {
+        let mut temp = Vec::new();
+        for _ in 0..inst.count {
+            let value = self
+                .stacks
+                .get_mut(inst.start)
+                .ok_or("invalid start stack")?
+                .crates
+                .pop()
+                .ok_or("empty stack")?;
+            temp.push(value)
+        }
+        for _ in 0..inst.count {
+            self.stacks
+                .get_mut(inst.end)
+                .ok_or("invalid end stack")?
+                .crates
+                .push(temp.pop().unwrap())
+        }
+        Ok(())
+    }
+
+    fn top(&self) -> String {
+        self.stacks
+            .iter()
+            .map(|s| s.crates.last())
+            .filter(|o| o.is_some())
+            .map(|o| o.unwrap())
+            .collect()
+    }
+}
+
+impl FromStr for Shipyard {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut lines = s.split("\n").collect::<Vec<_>>();
+        let stack_count = lines.pop().unwrap().trim().split_whitespace().count();
+        let mut shipyard = Self::new(stack_count);
+        for line in lines.iter().rev() {
+            let stack_elements = line
+                .chars()
+                .into_iter()
+                .enumerate()
+                .filter(|(i, _)| i % 4 == 1)
+                .map(|(_, v)| v)
+                .enumerate();
+            for (stack, element) in stack_elements {
+                if element != ' ' {
+                    shipyard
+                        .stacks
+                        .get_mut(stack)
+                        .ok_or_else(|| ParseError::new("crate outside of stacks").span(line))?
+                        .crates
+                        .push(element.clone());
+                }
+            }
+        }
+        Ok(shipyard)
+    }
+}
+
+pub struct Instruction {
+    count: usize,
+    start: usize,
+    end: usize,
+}
+
+impl FromStr for Instruction {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let splits = s.split_whitespace().collect::<Vec<_>>();
+        let parse = |idx: usize, what: &str| -> Result<usize, ParseError> {
+            let value = splits
+                .get(idx)
+                .ok_or_else(|| ParseError::missing(what).span(s))?;
+            value
+                .parse()
+                .map_err(|err| ParseError::invalid(what, value).with_cause(err))
+        };
+        let count = parse(1, "count")?;
+        let start = parse(3, "start")?
+            .checked_sub(1)
+            .ok_or_else(|| ParseError::invalid("start", s))?;
+        let end = parse(5, "end")?
+            .checked_sub(1)
+            .ok_or_else(|| ParseError::invalid("end", s))?;
+        Ok(Instruction { count, start, end })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Solution;
+    use aoc::Part;
+
+    const EXAMPLE: &str = include_str!("../assets/example.txt");
+
+    #[test]
+    fn example() {
+        assert_eq!(aoc::solve::<Solution>(EXAMPLE, Part::One).unwrap(), "CMZ");
+        assert_eq!(aoc::solve::<Solution>(EXAMPLE, Part::Two).unwrap(), "MCD");
+    }
+}
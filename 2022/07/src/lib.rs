@@ -0,0 +1,291 @@
+use aoc::ParseError;
+use std::{fmt::Display, str::FromStr};
+
+pub const DAY: aoc::Day = aoc::day!(2022, 7, Solution);
+
+pub struct Solution;
+
+impl aoc::Solver for Solution {
+    type Input = Directory;
+    type Error = &'static str;
+
+    fn parse(input: &str) -> Result<Directory, ParseError> {
+        let mut root = Directory::new("/".to_string());
+        let mut stack = Vec::new();
+        for process in input.split("$ ").filter(|s| !s.is_empty()) {
+            match process.parse::<Process>()? {
+                Process::List(l) => {
+                    let dir = root
+                        .get_mut(stack.iter().cloned())
+                        .map_err(|_| ParseError::new("unknown directory").span(process))?;
+                    for entry in l.entries {
+                        dir.add(entry);
+                    }
+                }
+                Process::Nav(n) => match n {
+                    Nav::Root => stack.clear(),
+                    Nav::Back => {
+                        stack.pop().ok_or_else(|| {
+                            ParseError::new("cannot go back from root").span(process)
+                        })?;
+                    }
+                    Nav::Dir(d) => stack.push(d),
+                },
+            }
+        }
+        Ok(root)
+    }
+
+    fn part1(root: &Directory) -> Result<impl Display, &'static str> {
+        Ok(root
+            .into_iter()
+            .map(|d| d.get_size())
+            .filter(|s| *s <= 100000)
+            .sum::<usize>())
+    }
+
+    fn part2(root: &Directory) -> Result<impl Display, &'static str> {
+        let min_size = 30000000 - (70000000 - root.get_size());
+        root.into_iter()
+            .map(|d| d.get_size())
+            .filter(|s| *s > min_size)
+            .min()
+            .ok_or("no directory is large enough")
+    }
+}
+
+trait Sizeable {
+    fn get_size(&self) -> usize;
+}
+
+#[derive(Debug)]
+pub struct Directory {
+    name: String,
+    content: Vec<DirectoryEntry>,
+}
+
+#[derive(Debug)]
+struct InvalidPath;
+
+impl Directory {
+    fn new(s: String) -> Directory {
+        Directory {
+            name: s,
+            content: Vec::new(),
+        }
+    }
+
+    fn add(&mut self, entry: DirectoryEntry) {
+        self.content.push(entry);
+    }
+
+    fn get_mut<I>(&mut self, mut path: I) -> Result<&mut Directory, InvalidPath>
+    where
+        I: Iterator<Item = String>,
+    {
+        match path.next() {
+            None => Ok(self),
+            Some(name) => self.dir_named_mut(&name).ok_or(InvalidPath)?.get_mut(path),
+        }
+    }
+
+    fn dir_named_mut(&mut self, name: &str) -> Option<&mut Directory> {
+        self.content
+            .iter_mut()
+            .filter_map(|e| match e {
+                DirectoryEntry::Directory(d) => Some(d),
+                DirectoryEntry::File(_) => None,
+            })
+            .find_map(|d| if d.name == name { Some(d) } else { None })
+    }
+}
+
+impl<'a> IntoIterator for &'a Directory {
+    type Item = &'a Directory;
+    type IntoIter = std::vec::IntoIter<&'a Directory>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        fn append<'a>(dir: &'a Directory, v: &mut Vec<&'a Directory>) {
+            v.push(dir);
+            for d in dir.content.iter().filter_map(|e| match e {
+                DirectoryEntry::Directory(d) => Some(d),
+                DirectoryEntry::File(_) => None,
+            }) {
+                append(d, v)
+            }
+        }
+
+        let mut result = vec![];
+        append(self, &mut result);
+        result.into_iter()
+    }
+}
+
+impl FromStr for Directory {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.split_once(" ") {
+            Some(("dir", name)) => Ok(Directory::new(name.trim().to_string())),
+            _ => Err(ParseError::invalid("directory", s)),
+        }
+    }
+}
+
+impl Sizeable for Directory {
+    fn get_size(&self) -> usize {
+        self.content
+            .iter()
+            .map(|e| e.get_size())
+            .reduce(|a, b| a + b)
+            .unwrap_or(0)
+    }
+}
+
+#[derive(Debug)]
+enum DirectoryEntry {
+    Directory(Directory),
+    File(File),
+}
+
+impl FromStr for DirectoryEntry {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        if s.starts_with("dir ") {
+            s.parse::<Directory>().map(Self::from)
+        } else {
+            s.parse::<File>().map(Self::from)
+        }
+    }
+}
+
+impl From<Directory> for DirectoryEntry {
+    fn from(d: Directory) -> Self {
+        DirectoryEntry::Directory(d)
+    }
+}
+
+impl From<File> for DirectoryEntry {
+    fn from(f: File) -> Self {
+        DirectoryEntry::File(f)
+    }
+}
+
+impl FromStr for File {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut sp = s.split_whitespace();
+        let size = sp
+            .next()
+            .ok_or_else(|| ParseError::missing("file size").span(s))?;
+        let size = size
+            .parse::<usize>()
+            .map_err(|err| ParseError::invalid("file size", size).with_cause(err))?;
+        let name = sp
+            .next()
+            .ok_or_else(|| ParseError::missing("file name").span(s))?
+            .trim()
+            .to_string();
+        Ok(File { name, size })
+    }
+}
+
+impl Sizeable for DirectoryEntry {
+    fn get_size(&self) -> usize {
+        match self {
+            DirectoryEntry::Directory(d) => d.get_size(),
+            DirectoryEntry::File(f) => f.get_size(),
+        }
+    }
+}
+
+#[derive(Debug)]
+struct File {
+    name: String,
+    size: usize,
+}
+
+impl Sizeable for File {
+    fn get_size(&self) -> usize {
+        return self.size;
+    }
+}
+
+#[derive(Debug)]
+enum Process {
+    List(List),
+    Nav(Nav),
+}
+
+#[derive(Debug)]
+struct List {
+    entries: Vec<DirectoryEntry>,
+}
+
+#[derive(Debug)]
+enum Nav {
+    Root,
+    Back,
+    Dir(String),
+}
+
+impl FromStr for Nav {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "/" => Ok(Nav::Root),
+            ".." => Ok(Nav::Back),
+            _ => Ok(Nav::Dir(s.trim().to_string())),
+        }
+    }
+}
+
+impl FromStr for Process {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut lines = s.split("\n");
+        let line = lines.next().unwrap_or_default();
+        let mut cmd = line.split_whitespace();
+        match cmd
+            .next()
+            .ok_or_else(|| ParseError::missing("command").span(s))?
+        {
+            "ls" => {
+                let entries = lines
+                    .filter(|l| !l.is_empty())
+                    .map(|l| l.parse::<DirectoryEntry>())
+                    .collect::<Result<Vec<_>, _>>()?;
+                Ok(Process::List(List { entries }))
+            }
+            "cd" => {
+                let nav = cmd
+                    .next()
+                    .ok_or_else(|| ParseError::missing("directory").span(line))?
+                    .parse::<Nav>()?;
+                Ok(Process::Nav(nav))
+            }
+            command => Err(ParseError::invalid("command", command)),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Solution;
+    use aoc::Part;
+
+    const EXAMPLE: &str = include_str!("../assets/example.txt");
+
+    #[test]
+    fn example() {
+        assert_eq!(aoc::solve::<Solution>(EXAMPLE, Part::One).unwrap(), "95437");
+        assert_eq!(
+            aoc::solve::<Solution>(EXAMPLE, Part::Two).unwrap(),
+            "24933642"
+        );
+    }
+}
@@ -0,0 +1,105 @@
+use aoc::{
+    geom::{Dir4, Vec2},
+    ParseError,
+};
+use std::{collections::HashSet, fmt::Display, iter};
+
+pub const DAY: aoc::Day = aoc::day!(2022, 9, Solution);
+
+pub struct Solution;
+
+impl aoc::Solver for Solution {
+    type Input = Vec<Vec2<i64>>;
+    type Error = &'static str;
+
+    fn parse(input: &str) -> Result<Vec<Vec2<i64>>, ParseError> {
+        let mut deltas = Vec::new();
+        for line in input.lines() {
+            let mut sp = line.split_whitespace();
+            let direction = sp
+                .next()
+                .ok_or_else(|| ParseError::missing("instruction").span(line))?
+                .parse::<Dir4>()?;
+            let n = sp
+                .next()
+                .ok_or_else(|| ParseError::missing("count").span(line))?;
+            let n = n
+                .parse::<usize>()
+                .map_err(|err| ParseError::invalid("count", n).with_cause(err))?;
+            deltas.extend(iter::repeat(direction.delta()).take(n));
+        }
+        Ok(deltas)
+    }
+
+    fn part1(deltas: &Vec<Vec2<i64>>) -> Result<impl Display, &'static str> {
+        Ok(visited(deltas, 2))
+    }
+
+    fn part2(deltas: &Vec<Vec2<i64>>) -> Result<impl Display, &'static str> {
+        Ok(visited(deltas, 10))
+    }
+}
+
+fn follow(segment: &mut Vec2<i64>, target: &Vec2<i64>) {
+    if segment.chebyshev(*target) > 1 {
+        *segment += (*target - *segment).map(i64::signum);
+    }
+}
+
+#[derive(Debug)]
+struct Bridge {
+    segments: Vec<Vec2<i64>>,
+}
+
+impl Bridge {
+    fn new(n: usize) -> Bridge {
+        let segments = iter::repeat(Vec2::ZERO).take(n).collect();
+        Bridge { segments }
+    }
+
+    fn updated(mut self, delta: Vec2<i64>) -> Bridge {
+        let mut it = self.segments.iter_mut();
+        let mut head = it.next().unwrap();
+        *head += delta;
+        for el in it {
+            follow(el, head);
+            head = el;
+        }
+        self
+    }
+
+    fn tail(&self) -> &Vec2<i64> {
+        self.segments.last().unwrap()
+    }
+}
+
+fn visited(deltas: &[Vec2<i64>], n: usize) -> usize {
+    let mut bridge = Bridge::new(n);
+    let mut visited = HashSet::new();
+    visited.insert(*bridge.tail());
+    for delta in deltas.iter() {
+        bridge = bridge.updated(*delta);
+        visited.insert(*bridge.tail());
+    }
+    visited.len()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Solution;
+    use aoc::Part;
+
+    const EXAMPLE: &str = include_str!("../assets/example.txt");
+
+    #[test]
+    fn example() {
+        assert_eq!(aoc::solve::<Solution>(EXAMPLE, Part::One).unwrap(), "13");
+        assert_eq!(aoc::solve::<Solution>(EXAMPLE, Part::Two).unwrap(), "1");
+    }
+
+    #[test]
+    fn larger_example() {
+        let example = "R 5\nU 8\nL 8\nD 3\nR 17\nD 10\nL 25\nU 20\n";
+        assert_eq!(aoc::solve::<Solution>(example, Part::Two).unwrap(), "36");
+    }
+}
@@ -0,0 +1,115 @@
+use aoc::ParseError;
+use std::{fmt::Display, str::FromStr};
+
+pub const DAY: aoc::Day = aoc::day!(2022, 10, Solution);
+
+pub struct Solution;
+
+impl aoc::Solver for Solution {
+    type Input = Vec<i32>;
+    type Error = &'static str;
+
+    fn parse(input: &str) -> Result<Vec<i32>, ParseError> {
+        let mut states = vec![1];
+        let mut x = 1;
+        for line in input.lines() {
+            let instr = line.parse::<Instruction>()?;
+            states.extend(instr.get_xs(x));
+            x = instr.update(x);
+        }
+        Ok(states)
+    }
+
+    fn part1(states: &Vec<i32>) -> Result<impl Display, &'static str> {
+        let boundries = [20, 60, 100, 140, 180, 220];
+        boundries
+            .iter()
+            .map(|b| Ok(*b as i32 * states.get(*b).ok_or("program too short")?))
+            .sum::<Result<i32, _>>()
+    }
+
+    fn part2(states: &Vec<i32>) -> Result<impl Display, &'static str> {
+        let mut screen = String::new();
+        for idx in 0..40 * 6 {
+            if idx % 40 == 0 {
+                screen.push('\n');
+            }
+            let state = *states.get(idx + 1).ok_or("program too short")?;
+            let idx = (idx % 40) as i32;
+            if state - 1 == idx || state + 1 == idx || state == idx {
+                screen.push('#');
+            } else {
+                screen.push(' ');
+            }
+        }
+        Ok(screen)
+    }
+}
+
+enum Instruction {
+    Noop,
+    Addx(i32),
+}
+
+impl Instruction {
+    fn get_xs(&self, x: i32) -> Vec<i32> {
+        match self {
+            Instruction::Noop => vec![x],
+            Instruction::Addx(_) => vec![x, x],
+        }
+    }
+
+    fn update(&self, x: i32) -> i32 {
+        match self {
+            Instruction::Noop => x,
+            Instruction::Addx(val) => x + val,
+        }
+    }
+}
+
+impl FromStr for Instruction {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut it = s.split_whitespace();
+        match it
+            .next()
+            .ok_or_else(|| ParseError::missing("instruction").span(s))?
+        {
+            "addx" => {
+                let value = it
+                    .next()
+                    .ok_or_else(|| ParseError::missing("value").span(s))?;
+                let value = value
+                    .parse()
+                    .map_err(|err| ParseError::invalid("value", value).with_cause(err))?;
+                Ok(Instruction::Addx(value))
+            }
+            "noop" => Ok(Instruction::Noop),
+            instr => Err(ParseError::invalid("instruction", instr)),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Solution;
+    use aoc::Part;
+
+    const EXAMPLE: &str = include_str!("../assets/example.txt");
+
+    #[test]
+    fn example() {
+        assert_eq!(aoc::solve::<Solution>(EXAMPLE, Part::One).unwrap(), "13140");
+        assert_eq!(
+            aoc::solve::<Solution>(EXAMPLE, Part::Two).unwrap(),
+            "
+##  ##  ##  ##  ##  ##  ##  ##  ##  ##  
+###   ###   ###   ###   ###   ###   ### 
+####    ####    ####    ####    ####    
+#####     #####     #####     #####     
+######      ######      ######      ####
+#######       #######       #######     "
+        );
+    }
+}
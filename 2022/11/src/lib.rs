@@ -0,0 +1,219 @@
+use aoc::ParseError;
+use std::fmt::Display;
+use std::iter;
+use std::str::FromStr;
+
+pub const DAY: aoc::Day = aoc::day!(2022, 11, Solution);
+
+pub struct Solution;
+
+impl aoc::Solver for Solution {
+    type Input = Vec<Monkey>;
+    type Error = &'static str;
+
+    fn parse(input: &str) -> Result<Vec<Monkey>, ParseError> {
+        input.split("\n\n").map(str::parse).collect()
+    }
+
+    fn part1(monkeys: &Vec<Monkey>) -> Result<impl Display, &'static str> {
+        worry_level(monkeys.clone(), 20, |x| x / 3)
+    }
+
+    fn part2(monkeys: &Vec<Monkey>) -> Result<impl Display, &'static str> {
+        worry_level(monkeys.clone(), 10000, |x| x)
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct Monkey {
+    items: Vec<u64>,
+    action: Expr,
+    check: Check,
+    true_target: usize,
+    false_target: usize,
+}
+
+impl Monkey {
+    fn get_target(&self, val: &u64) -> &usize {
+        if self.check.eval(val) {
+            &self.true_target
+        } else {
+            &self.false_target
+        }
+    }
+}
+
+impl FromStr for Monkey {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut sp = s.lines();
+        sp.next().ok_or_else(|| ParseError::missing("monkey"))?;
+        let items = field(sp.next(), "Starting items:", s)?
+            .split(",")
+            .map(|s| s.trim())
+            .map(|s| {
+                s.parse::<u64>()
+                    .map_err(|err| ParseError::invalid("item", s).with_cause(err))
+            })
+            .collect::<Result<_, _>>()?;
+        let action = field(sp.next(), "Operation: new =", s)?.parse::<Expr>()?;
+        let check = field(sp.next(), "Test: divisible by", s)?.parse::<Check>()?;
+        let true_target = parse_target(field(sp.next(), "If true: throw to monkey", s)?)?;
+        let false_target = parse_target(field(sp.next(), "If false: throw to monkey", s)?)?;
+        Ok(Monkey {
+            items,
+            action,
+            check,
+            true_target,
+            false_target,
+        })
+    }
+}
+
+fn field<'a>(line: Option<&'a str>, prefix: &str, monkey: &str) -> Result<&'a str, ParseError> {
+    let line = line.ok_or_else(|| ParseError::new(format!("missing '{prefix}'")).span(monkey))?;
+    line.trim()
+        .strip_prefix(prefix)
+        .map(str::trim)
+        .ok_or_else(|| ParseError::new(format!("expected '{prefix}'")).span(line))
+}
+
+fn parse_target(s: &str) -> Result<usize, ParseError> {
+    s.parse()
+        .map_err(|err| ParseError::invalid("target", s).with_cause(err))
+}
+
+#[derive(Debug, Clone)]
+enum Expr {
+    Var,
+    Literal(u64),
+    Add(Box<Expr>, Box<Expr>),
+    Mul(Box<Expr>, Box<Expr>),
+}
+
+impl Expr {
+    fn eval(&self, v: u64) -> u64 {
+        match self {
+            Expr::Var => v,
+            Expr::Literal(l) => l.clone(),
+            Expr::Add(l, r) => l.eval(v) + r.eval(v),
+            Expr::Mul(l, r) => l.eval(v) * r.eval(v),
+        }
+    }
+}
+
+impl FromStr for Expr {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        if s.contains("+") {
+            let mut sp = s.split("+");
+            let l = Box::new(
+                sp.next()
+                    .ok_or_else(|| ParseError::missing("operand").span(s))?
+                    .trim()
+                    .parse::<Expr>()?,
+            );
+            let r = Box::new(
+                sp.next()
+                    .ok_or_else(|| ParseError::missing("operand").span(s))?
+                    .trim()
+                    .parse::<Expr>()?,
+            );
+            Ok(Expr::Add(l, r))
+        } else if s.contains("*") {
+            let mut sp = s.split("*");
+            let l = Box::new(
+                sp.next()
+                    .ok_or_else(|| ParseError::missing("operand").span(s))?
+                    .trim()
+                    .parse::<Expr>()?,
+            );
+            let r = Box::new(
+                sp.next()
+                    .ok_or_else(|| ParseError::missing("operand").span(s))?
+                    .trim()
+                    .parse::<Expr>()?,
+            );
+            Ok(Expr::Mul(l, r))
+        } else if s == "old" {
+            Ok(Expr::Var)
+        } else {
+            let n = s
+                .parse::<u64>()
+                .map_err(|err| ParseError::invalid("operand", s).with_cause(err))?;
+            Ok(Expr::Literal(n))
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+struct Check(u64);
+
+impl Check {
+    fn eval(&self, val: &u64) -> bool {
+        val % self.0 == 0
+    }
+}
+
+impl FromStr for Check {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        s.parse::<u64>()
+            .map(|v| Check(v))
+            .map_err(|err| ParseError::invalid("divisor", s).with_cause(err))
+    }
+}
+
+fn worry_level<F>(
+    mut monkeys: Vec<Monkey>,
+    rounds: usize,
+    mut relax_fn: F,
+) -> Result<u64, &'static str>
+where
+    F: FnMut(u64) -> u64,
+{
+    let mut throws = iter::repeat(0).take(monkeys.len()).collect::<Vec<_>>();
+    let modulus = monkeys.iter().map(|m| m.check.0).product::<u64>();
+    for _ in 0..rounds {
+        for idx in 0..monkeys.len() {
+            let items = monkeys
+                .get_mut(idx)
+                .unwrap()
+                .items
+                .drain(..)
+                .collect::<Vec<_>>();
+            for item in items {
+                *throws.get_mut(idx).unwrap() += 1;
+                let new = relax_fn(monkeys.get(idx).unwrap().action.eval(item)) % modulus;
+                let target = monkeys.get(idx).unwrap().get_target(&new).clone();
+                monkeys
+                    .get_mut(target)
+                    .ok_or("invalid target monkey")?
+                    .items
+                    .push(new);
+            }
+        }
+    }
+    throws.sort();
+    Ok(throws.iter().rev().take(2).cloned().product())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Solution;
+    use aoc::Part;
+
+    const EXAMPLE: &str = include_str!("../assets/example.txt");
+
+    #[test]
+    fn example() {
+        assert_eq!(aoc::solve::<Solution>(EXAMPLE, Part::One).unwrap(), "10605");
+        assert_eq!(
+            aoc::solve::<Solution>(EXAMPLE, Part::Two).unwrap(),
+            "2713310158"
+        );
+    }
+}
@@ -0,0 +1,210 @@
+use aoc::ParseError;
+use std::fmt::{Debug, Display};
+use std::str::FromStr;
+
+pub const DAY: aoc::Day = aoc::day!(2022, 12, Solution);
+
+pub struct Solution;
+
+impl aoc::Solver for Solution {
+    type Input = Map;
+    type Error = &'static str;
+
+    fn parse(input: &str) -> Result<Map, ParseError> {
+        input.parse()
+    }
+
+    fn part1(map: &Map) -> Result<impl Display, &'static str> {
+        let start = map.get_start().ok_or("missing start")?;
+        map.climb([start])
+    }
+
+    fn part2(map: &Map) -> Result<impl Display, &'static str> {
+        let a = Tile::try_from('a').unwrap();
+        let starts = (0..map.tiles.len())
+            .map(|i| Position {
+                x: i % map.width,
+                y: i / map.width,
+            })
+            .filter(|p| *map.get_tile(p).unwrap() == a);
+        map.climb(starts)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+struct Tile(u8);
+
+impl Tile {
+    fn to_height(c: char) -> u8 {
+        c as u8 - 'a' as u8 + 1
+    }
+
+    fn is_start(&self) -> bool {
+        self.0 == Tile::to_height('a') - 1
+    }
+
+    fn is_end(&self) -> bool {
+        self.0 == Tile::to_height('z') + 1
+    }
+
+    fn can_go(&self, other: &Tile) -> bool {
+        self.0 + 1 >= other.0
+    }
+}
+
+impl TryFrom<char> for Tile {
+    type Error = ParseError;
+
+    fn try_from(value: char) -> Result<Self, Self::Error> {
+        match value {
+            'S' => Ok(Tile(Tile::to_height('a') - 1)),
+            'E' => Ok(Tile(Tile::to_height('z') + 1)),
+            o => {
+                if o >= 'a' && o <= 'z' {
+                    Ok(Tile(Tile::to_height(o)))
+                } else {
+                    Err(ParseError::new(format!("invalid tile '{o}'")))
+                }
+            }
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct Map {
+    width: usize,
+    tiles: Vec<Tile>,
+}
+
+impl Map {
+    fn get_start(&self) -> Option<Position> {
+        self.tiles
+            .iter()
+            .enumerate()
+            .find(|(_, t)| t.is_start())
+            .map(|(i, _)| Position {
+                x: i % self.width,
+                y: i / self.width,
+            })
+    }
+
+    fn get_next(&self, pos: &Position) -> Vec<Position> {
+        [pos.up(), pos.down(), pos.left(), pos.right()]
+            .into_iter()
+            .filter_map(|p| p)
+            .filter(|p| self.in_bounds(p))
+            .filter(|p| {
+                self.get_tile(pos)
+                    .unwrap()
+                    .can_go(self.get_tile(p).unwrap())
+            })
+            .collect()
+    }
+
+    fn get_tile(&self, pos: &Position) -> Option<&Tile> {
+        self.tiles.get(pos.x + pos.y * self.width)
+    }
+
+    fn in_bounds(&self, pos: &Position) -> bool {
+        pos.x < self.width && pos.y < self.get_height()
+    }
+
+    fn get_height(&self) -> usize {
+        self.tiles.len() / self.width
+    }
+
+    fn climb(&self, starts: impl IntoIterator<Item = Position>) -> Result<usize, &'static str> {
+        let end = self.get_end().ok_or("missing end")?;
+        aoc::search::astar(
+            starts,
+            |pos| self.get_next(pos).into_iter().map(|next| (next, 1)),
+            |pos| pos.x.abs_diff(end.x) + pos.y.abs_diff(end.y),
+            |pos| *pos == end,
+        )
+        .cost()
+        .ok_or("no path to the summit")
+    }
+
+    fn get_end(&self) -> Option<Position> {
+        self.tiles
+            .iter()
+            .enumerate()
+            .find(|(_, t)| t.is_end())
+            .map(|(i, _)| Position {
+                x: i % self.width,
+                y: i / self.width,
+            })
+    }
+}
+
+impl FromStr for Map {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let width = s
+            .lines()
+            .next()
+            .ok_or_else(|| ParseError::missing("map"))?
+            .len();
+        let tiles = s
+            .lines()
+            .flat_map(|line| {
+                line.char_indices().map(|(i, c)| {
+                    Tile::try_from(c).map_err(|err| err.span(&line[i..i + c.len_utf8()]))
+                })
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+        Ok(Map { width, tiles })
+    }
+}
+
+#[derive(Debug, PartialEq, Eq, Hash, Clone)]
+struct Position {
+    x: usize,
+    y: usize,
+}
+
+impl Position {
+    fn up(&self) -> Option<Position> {
+        let x = self.x;
+        let y = self.y.checked_sub(1)?;
+        Some(Position { x, y })
+    }
+
+    fn down(&self) -> Option<Position> {
+        let x = self.x;
+        let y = self.y.checked_add(1)?;
+        Some(Position { x, y })
+    }
+
+    fn right(&self) -> Option<Position> {
+        let x = self.x.checked_add(1)?;
+        let y = self.y;
+        Some(Position { x, y })
+    }
+
+    fn left(&self) -> Option<Position> {
+        let x = self.x.checked_sub(1)?;
+        let y = self.y;
+        Some(Position { x, y })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Solution;
+    use aoc::Part;
+
+    const EXAMPLE: &str = include_str!("../assets/example.txt");
+
+    #[test]
+    fn example() {
+        assert_eq!(aoc::solve::<Solution>(EXAMPLE, Part::One).unwrap(), "31");
+        assert_eq!(aoc::solve::<Solution>(EXAMPLE, Part::Two).unwrap(), "29");
+    }
+
+    #[test]
+    fn unreachable_summit() {
+        assert!(aoc::solve::<Solution>("Sz\nzE", Part::One).is_err());
+    }
+}
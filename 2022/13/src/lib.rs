@@ -1,4 +1,45 @@
-use std::{num::ParseIntError, str::FromStr};
+use aoc::ParseError;
+use std::{fmt::Display, str::FromStr};
+
+pub const DAY: aoc::Day = aoc::day!(2022, 13, Solution);
+
+pub struct Solution;
+
+impl aoc::Solver for Solution {
+    type Input = Vec<Packet>;
+    type Error = &'static str;
+
+    fn parse(input: &str) -> Result<Vec<Packet>, ParseError> {
+        input
+            .lines()
+            .filter(|s| !s.is_empty())
+            .map(|s| s.parse::<Packet>())
+            .collect()
+    }
+
+    fn part1(packets: &Vec<Packet>) -> Result<impl Display, &'static str> {
+        Ok(packets
+            .chunks(2)
+            .enumerate()
+            .filter_map(|(i, p)| match p {
+                [left, right] if left <= right => Some(i + 1),
+                _ => None,
+            })
+            .sum::<usize>())
+    }
+
+    fn part2(packets: &Vec<Packet>) -> Result<impl Display, &'static str> {
+        let mut packets = packets.clone();
+        packets.push(Packet::first());
+        packets.push(Packet::second());
+        packets.sort();
+        let first = Packet::first();
+        let second = Packet::second();
+        let first = packets.iter().position(|p| *p == first).unwrap() + 1;
+        let second = packets.iter().position(|p| *p == second).unwrap() + 1;
+        Ok(first * second)
+    }
+}
 
 #[derive(Debug, PartialEq, Eq, Clone)]
 pub enum Packet {
@@ -16,22 +57,8 @@ impl Packet {
     }
 }
 
-#[derive(Debug)]
-pub enum PacketParseError {
-    UnclosedBracket(String),
-    ExcesiveClosing(String),
-    NumberWithoutList(String),
-    ParseIntError(ParseIntError),
-}
-
-impl From<ParseIntError> for PacketParseError {
-    fn from(err: ParseIntError) -> Self {
-        PacketParseError::ParseIntError(err)
-    }
-}
-
 impl FromStr for Packet {
-    type Err = PacketParseError;
+    type Err = ParseError;
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
         let mut res = None;
@@ -44,29 +71,31 @@ impl FromStr for Packet {
             let close_count = frag.chars().rev().take_while(|c| *c == ']').count();
             if let Packet::List(l) = stack
                 .last_mut()
-                .ok_or(PacketParseError::NumberWithoutList(s.to_string()))?
+                .ok_or_else(|| ParseError::new("number outside of a list").span(frag))?
             {
                 let substr = &frag[open_count..(frag.len() - close_count)];
                 if !substr.is_empty() {
-                    l.push(Packet::Integer(substr.parse::<i32>()?))
+                    l.push(Packet::Integer(substr.parse::<i32>().map_err(|err| {
+                        ParseError::invalid("integer", substr).with_cause(err)
+                    })?))
                 }
             }
             for _ in 0..close_count {
                 let val = stack
                     .pop()
-                    .ok_or(PacketParseError::UnclosedBracket(s.to_string()))?;
+                    .ok_or_else(|| ParseError::new("excessive closing bracket").span(frag))?;
                 if let Some(Packet::List(l)) = stack.last_mut() {
                     l.push(val)
                 } else {
                     if res.is_some() {
-                        return Err(PacketParseError::ExcesiveClosing(s.to_string()));
+                        return Err(ParseError::new("excessive closing bracket").span(frag));
                     } else {
                         res = Some(val);
                     }
                 }
             }
         }
-        res.ok_or(PacketParseError::UnclosedBracket(s.to_string()))
+        res.ok_or_else(|| ParseError::new("unclosed bracket").span(s))
     }
 }
 
@@ -78,7 +107,7 @@ impl Ord for Packet {
 
 impl PartialOrd for Packet {
     fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
-        let res =  match (self, other) {
+        let res = match (self, other) {
             (Packet::List(l), Packet::List(r)) => l.cmp(r),
             (Packet::List(l), Packet::Integer(r)) => l.cmp(&vec![Packet::Integer(r.clone())]),
             (Packet::Integer(l), Packet::List(r)) => vec![Packet::Integer(l.clone())].cmp(r),
@@ -86,4 +115,18 @@ impl PartialOrd for Packet {
         };
         Some(res)
     }
-}
\ No newline at end of file
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Solution;
+    use aoc::Part;
+
+    const EXAMPLE: &str = include_str!("../assets/example.txt");
+
+    #[test]
+    fn example() {
+        assert_eq!(aoc::solve::<Solution>(EXAMPLE, Part::One).unwrap(), "13");
+        assert_eq!(aoc::solve::<Solution>(EXAMPLE, Part::Two).unwrap(), "140");
+    }
+}
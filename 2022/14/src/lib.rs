@@ -0,0 +1,199 @@
+use aoc::ParseError;
+use std::{collections::HashSet, fmt::Display, ops::Add, str::FromStr};
+
+pub const DAY: aoc::Day = aoc::day!(2022, 14, Solution);
+
+pub struct Solution;
+
+impl aoc::Solver for Solution {
+    type Input = Map;
+    type Error = &'static str;
+
+    fn parse(input: &str) -> Result<Map, ParseError> {
+        let mut rock_tiles = HashSet::new();
+        for line in input.lines() {
+            let positions = line
+                .trim()
+                .split("->")
+                .map(|p| p.parse::<Position>())
+                .collect::<Result<Vec<_>, _>>()?;
+            rock_tiles.extend(
+                positions
+                    .windows(2)
+                    .flat_map(|p| Position::of_line(&p[0], &p[1])),
+            );
+        }
+        Ok(Map {
+            rock_tiles,
+            sand_tiles: HashSet::new(),
+        })
+    }
+
+    fn part1(map: &Map) -> Result<impl Display, &'static str> {
+        let void_y = map.void_y()?;
+        let mut map = map.clone();
+        let mut count = 0;
+        while map.add_sand_tile(Position { x: 500, y: 0 }, void_y) {
+            count += 1;
+        }
+        Ok(count)
+    }
+
+    fn part2(map: &Map) -> Result<impl Display, &'static str> {
+        let void_y = map.void_y()?;
+        let mut map = map.clone();
+        let mut count = 1;
+        while map.add_sand_tile_ground(Position { x: 500, y: 0 }, void_y + 2) {
+            count += 1;
+        }
+        Ok(count)
+    }
+}
+
+#[derive(Debug, Hash, PartialEq, Eq, Clone)]
+struct Position {
+    x: i32,
+    y: i32,
+}
+
+impl Position {
+    fn down() -> Position {
+        Position { x: 0, y: 1 }
+    }
+
+    fn down_left() -> Position {
+        Position { x: -1, y: 1 }
+    }
+
+    fn down_right() -> Position {
+        Position { x: 1, y: 1 }
+    }
+}
+
+impl Add for &Position {
+    type Output = Position;
+
+    fn add(self, rhs: Self) -> Self::Output {
+        Position {
+            x: self.x + rhs.x,
+            y: self.y + rhs.y,
+        }
+    }
+}
+
+impl Position {
+    fn of_line(start: &Position, end: &Position) -> Vec<Position> {
+        if start.x == end.x {
+            let min = start.y.min(end.y);
+            let max = start.y.max(end.y);
+            (min..=max).map(|y| Position { x: start.x, y }).collect()
+        } else {
+            let min = start.x.min(end.x);
+            let max = start.x.max(end.x);
+            (min..=max).map(|x| Position { x, y: start.y }).collect()
+        }
+    }
+}
+
+impl FromStr for Position {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut it = s.trim().split(",").map(|n| {
+            n.parse::<i32>()
+                .map_err(|err| ParseError::invalid("coordinate", n).with_cause(err))
+        });
+        let x = it
+            .next()
+            .ok_or_else(|| ParseError::missing("x coordinate").span(s))??;
+        let y = it
+            .next()
+            .ok_or_else(|| ParseError::missing("y coordinate").span(s))??;
+        Ok(Position { x, y })
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct Map {
+    rock_tiles: HashSet<Position>,
+    sand_tiles: HashSet<Position>,
+}
+
+impl Map {
+    fn add_sand_tile(&mut self, mut starting: Position, void_y: i32) -> bool {
+        let mut stopped = false;
+        let moves = [
+            Position::down(),
+            Position::down_left(),
+            Position::down_right(),
+        ];
+        while !stopped {
+            match moves.iter().find_map(|m| {
+                let new_p = m + &starting;
+                if self.contains(&new_p) {
+                    None
+                } else {
+                    Some(new_p)
+                }
+            }) {
+                Some(new_p) => starting = new_p,
+                None => stopped = true,
+            }
+            if starting.y > void_y {
+                return false;
+            }
+        }
+        self.sand_tiles.insert(starting);
+        true
+    }
+
+    fn add_sand_tile_ground(&mut self, mut starting: Position, ground: i32) -> bool {
+        let mut stopped = false;
+        let mut first = true;
+        let moves = [
+            Position::down(),
+            Position::down_left(),
+            Position::down_right(),
+        ];
+        while !stopped {
+            match moves.iter().find_map(|m| {
+                let new_p = m + &starting;
+                if self.contains(&new_p) || new_p.y == ground {
+                    None
+                } else {
+                    Some(new_p)
+                }
+            }) {
+                Some(new_p) => {
+                    starting = new_p;
+                    first = false;
+                }
+                None => stopped = true,
+            }
+        }
+        self.sand_tiles.insert(starting);
+        !first
+    }
+
+    fn void_y(&self) -> Result<i32, &'static str> {
+        self.rock_tiles.iter().map(|t| t.y).max().ok_or("no rocks")
+    }
+
+    fn contains(&self, p: &Position) -> bool {
+        self.rock_tiles.contains(p) || self.sand_tiles.contains(p)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Solution;
+    use aoc::Part;
+
+    const EXAMPLE: &str = include_str!("../assets/example.txt");
+
+    #[test]
+    fn example() {
+        assert_eq!(aoc::solve::<Solution>(EXAMPLE, Part::One).unwrap(), "24");
+        assert_eq!(aoc::solve::<Solution>(EXAMPLE, Part::Two).unwrap(), "93");
+    }
+}
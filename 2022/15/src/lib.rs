@@ -0,0 +1,128 @@
+use aoc::{
+    interval::{Interval, IntervalSet},
+    parse, ParseError,
+};
+use std::{fmt::Display, str::FromStr};
+
+pub const DAY: aoc::Day = aoc::day!(2022, 15, Solution);
+
+pub struct Solution;
+
+impl aoc::Solver for Solution {
+    type Input = Vec<Sensor>;
+    type Error = &'static str;
+
+    fn parse(input: &str) -> Result<Vec<Sensor>, ParseError> {
+        input.lines().map(|l| l.parse::<Sensor>()).collect()
+    }
+
+    fn part1(sensors: &Vec<Sensor>) -> Result<impl Display, &'static str> {
+        Ok(covered(sensors, 2000000))
+    }
+
+    fn part2(sensors: &Vec<Sensor>) -> Result<impl Display, &'static str> {
+        tuning_frequency(sensors, 4000000).ok_or("no distress beacon")
+    }
+}
+
+#[derive(Debug, PartialEq, Eq, Hash)]
+struct Position {
+    x: i32,
+    y: i32,
+}
+
+impl Position {
+    fn manhattan_distance(&self, other: &Self) -> u32 {
+        self.x.abs_diff(other.x) + self.y.abs_diff(other.y)
+    }
+}
+
+fn parse_position(input: &mut &str) -> Result<Position, ParseError> {
+    let x = parse::key_value(input, "x", parse::signed)?;
+    parse::literal(input, ", ")?;
+    let y = parse::key_value(input, "y", parse::signed)?;
+    Ok(Position { x, y })
+}
+
+#[derive(Debug, PartialEq, Eq, Hash)]
+pub struct Sensor {
+    position: Position,
+    closest_becon: Position,
+}
+
+impl Sensor {
+    fn in_range_y(&self, y: &i32) -> bool {
+        let distance = self.position.manhattan_distance(&self.closest_becon);
+        self.position.y.abs_diff(*y) <= distance
+    }
+
+    fn get_range_y(&self, y: &i32) -> Interval<i32> {
+        let distance = self.position.manhattan_distance(&self.closest_becon);
+        let freedom = (distance - self.position.y.abs_diff(*y)) as i32;
+        Interval::inclusive(self.position.x - freedom, self.position.x + freedom)
+    }
+}
+
+impl FromStr for Sensor {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        parse::complete(s, |input| {
+            parse::literal(input, "Sensor at ")?;
+            let position = parse_position(input)?;
+            parse::literal(input, ": closest beacon is at ")?;
+            let closest_becon = parse_position(input)?;
+            Ok(Sensor {
+                position,
+                closest_becon,
+            })
+        })
+    }
+}
+
+fn ranges_y(sensors: &Vec<Sensor>, y: &i32) -> IntervalSet<i32> {
+    sensors
+        .iter()
+        .filter(|sensor| sensor.in_range_y(y))
+        .map(|sensor| sensor.get_range_y(y))
+        .collect()
+}
+
+fn covered(sensors: &Vec<Sensor>, y: i32) -> i32 {
+    let mut ranges = ranges_y(sensors, &y);
+    for sensor in sensors.iter() {
+        if sensor.position.y == y {
+            ranges.remove(Interval::inclusive(sensor.position.x, sensor.position.x));
+        }
+        if sensor.closest_becon.y == y {
+            ranges.remove(Interval::inclusive(
+                sensor.closest_becon.x,
+                sensor.closest_becon.x,
+            ));
+        }
+    }
+    ranges.len()
+}
+
+fn tuning_frequency(sensors: &Vec<Sensor>, max: i32) -> Option<i64> {
+    let area = IntervalSet::from(Interval::inclusive(0, max));
+    (0..=max).find_map(|y| {
+        let x = area.difference(&ranges_y(sensors, &y)).first()?;
+        Some((x as i64) * 4000000 + (y as i64))
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{covered, tuning_frequency, Solution};
+    use aoc::Solver;
+
+    const EXAMPLE: &str = include_str!("../assets/example.txt");
+
+    #[test]
+    fn example() {
+        let sensors = Solution::parse(EXAMPLE).unwrap();
+        assert_eq!(covered(&sensors, 10), 26);
+        assert_eq!(tuning_frequency(&sensors, 20), Some(56000011));
+    }
+}
@@ -0,0 +1,181 @@
+use aoc::ParseError;
+use std::{collections::HashSet, fmt::Display, str::FromStr};
+
+pub const DAY: aoc::Day = aoc::day!(2022, 18, Solution);
+
+pub struct Solution;
+
+impl aoc::Solver for Solution {
+    type Input = HashSet<Boulder>;
+    type Error = &'static str;
+
+    fn parse(input: &str) -> Result<HashSet<Boulder>, ParseError> {
+        let boulders = input
+            .lines()
+            .map(|line| line.parse())
+            .collect::<Result<HashSet<_>, _>>()?;
+        if boulders.is_empty() {
+            return Err(ParseError::missing("boulders"));
+        }
+        Ok(boulders)
+    }
+
+    fn part1(boulders: &HashSet<Boulder>) -> Result<impl Display, &'static str> {
+        Ok(boulders
+            .iter()
+            .map(|boulder| boulder.get_adjacent().difference(boulders).count())
+            .sum::<usize>())
+    }
+
+    fn part2(boulders: &HashSet<Boulder>) -> Result<impl Display, &'static str> {
+        let mut outside_exposed_faces = 0;
+        let mut free_face_checker = FreeFaceChecker::new(boulders);
+        for boulder in boulders.iter() {
+            let adjacents = boulder.get_adjacent();
+            for free_face in adjacents.difference(boulders) {
+                if free_face_checker.check(free_face, boulders, &mut HashSet::new()) {
+                    outside_exposed_faces += 1;
+                }
+            }
+        }
+        Ok(outside_exposed_faces)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Boulder {
+    x: i64,
+    y: i64,
+    z: i64,
+}
+
+impl FromStr for Boulder {
+    type Err = ParseError;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        let mut it = value.split(",").map(|c| {
+            c.parse::<i64>()
+                .map_err(|err| ParseError::invalid("coordinate", c).with_cause(err))
+        });
+        let mut next = || {
+            it.next()
+                .ok_or_else(|| ParseError::missing("coordinate").span(value))?
+        };
+        let (x, y, z) = (next()?, next()?, next()?);
+        Ok(Self { x, y, z })
+    }
+}
+
+impl Boulder {
+    fn get_adjacent(&self) -> HashSet<Boulder> {
+        let Boulder { x, y, z } = self;
+        let mut adjacents = HashSet::new();
+        adjacents.insert(Boulder {
+            x: x - 1,
+            y: *y,
+            z: *z,
+        });
+        adjacents.insert(Boulder {
+            x: x + 1,
+            y: *y,
+            z: *z,
+        });
+        adjacents.insert(Boulder {
+            x: *x,
+            y: y - 1,
+            z: *z,
+        });
+        adjacents.insert(Boulder {
+            x: *x,
+            y: y + 1,
+            z: *z,
+        });
+        adjacents.insert(Boulder {
+            x: *x,
+            y: *y,
+            z: z - 1,
+        });
+        adjacents.insert(Boulder {
+            x: *x,
+            y: *y,
+            z: z + 1,
+        });
+        adjacents
+    }
+}
+
+struct FreeFaceChecker {
+    x_min: i64,
+    x_max: i64,
+    y_min: i64,
+    y_max: i64,
+    z_min: i64,
+    z_max: i64,
+    exterior: HashSet<Boulder>,
+}
+
+impl FreeFaceChecker {
+    fn new(boulders: &HashSet<Boulder>) -> Self {
+        Self {
+            x_min: boulders.iter().map(|b| b.x).min().unwrap(),
+            x_max: boulders.iter().map(|b| b.x).max().unwrap(),
+            y_min: boulders.iter().map(|b| b.y).min().unwrap(),
+            y_max: boulders.iter().map(|b| b.y).max().unwrap(),
+            z_min: boulders.iter().map(|b| b.z).min().unwrap(),
+            z_max: boulders.iter().map(|b| b.z).max().unwrap(),
+            exterior: HashSet::new(),
+        }
+    }
+
+    fn check(
+        &mut self,
+        boulder: &Boulder,
+        boulders: &HashSet<Boulder>,
+        visited: &mut HashSet<Boulder>,
+    ) -> bool {
+        match self.exterior.contains(boulder) {
+            true => true,
+            false => {
+                if self.is_outside(boulder) {
+                    true
+                } else {
+                    visited.insert(boulder.clone());
+                    let is_exterior = boulder
+                        .get_adjacent()
+                        .difference(visited)
+                        .cloned()
+                        .collect::<HashSet<_>>()
+                        .difference(boulders)
+                        .any(|adjacent| self.check(adjacent, boulders, visited));
+                    if is_exterior {
+                        self.exterior.insert(boulder.clone());
+                    }
+                    is_exterior
+                }
+            }
+        }
+    }
+
+    fn is_outside(&self, boulder: &Boulder) -> bool {
+        boulder.x < self.x_min
+            || boulder.x > self.x_max
+            || boulder.y < self.y_min
+            || boulder.y > self.y_max
+            || boulder.z < self.z_min
+            || boulder.z > self.z_max
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Solution;
+    use aoc::Part;
+
+    const EXAMPLE: &str = include_str!("../assets/example.txt");
+
+    #[test]
+    fn example() {
+        assert_eq!(aoc::solve::<Solution>(EXAMPLE, Part::One).unwrap(), "64");
+        assert_eq!(aoc::solve::<Solution>(EXAMPLE, Part::Two).unwrap(), "58");
+    }
+}
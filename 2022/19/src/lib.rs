@@ -0,0 +1,279 @@
+use aoc::ParseError;
+use std::{fmt::Display, str::FromStr};
+
+pub const DAY: aoc::Day = aoc::day!(2022, 19, Solution);
+
+pub struct Solution;
+
+impl aoc::Solver for Solution {
+    type Input = Vec<Blueprint>;
+    type Error = &'static str;
+
+    fn parse(input: &str) -> Result<Vec<Blueprint>, ParseError> {
+        input.lines().map(|line| line.parse()).collect()
+    }
+
+    fn part1(blueprints: &Vec<Blueprint>) -> Result<impl Display, &'static str> {
+        let indexed: Vec<_> = blueprints.iter().enumerate().collect();
+        Ok(aoc::par::map_reduce(
+            &indexed,
+            |&(idx, blueprint)| (idx + 1) as u32 * blueprint.max_geodes(24),
+            |a, b| a + b,
+        )
+        .unwrap_or(0))
+    }
+
+    fn part2(blueprints: &Vec<Blueprint>) -> Result<impl Display, &'static str> {
+        Ok(aoc::par::map_reduce(
+            &blueprints[..blueprints.len().min(3)],
+            |blueprint| blueprint.max_geodes(32),
+            |a, b| a * b,
+        )
+        .unwrap_or(1))
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct Blueprint {
+    ore_cost: Cost,
+    clay_cost: Cost,
+    obsidian_cost: Cost,
+    geode_cost: Cost,
+}
+
+impl Blueprint {
+    fn max_geodes(&self, depth: u8) -> u32 {
+        let mut stack = Vec::new();
+        stack.push(State::default());
+        let mut best: Option<State> = None;
+        while let Some(state) = stack.pop() {
+            if state.minute == depth {
+                if let Some(best_state) = best {
+                    if state.geodes > best_state.geodes {
+                        best = Some(state);
+                    } else {
+                        best = Some(best_state);
+                    }
+                } else {
+                    best = Some(state);
+                }
+            } else {
+                match &best {
+                    Some(best) => {
+                        if state.can_improve(best.geodes, depth) {
+                            let mut new_states = state.get_states(&self);
+                            stack.append(&mut new_states);
+                        }
+                    }
+                    None => {
+                        let mut new_states = state.get_states(&self);
+                        stack.append(&mut new_states);
+                    }
+                }
+            }
+        }
+        best.unwrap().geodes
+    }
+}
+
+#[derive(Debug, Clone)]
+struct State {
+    minute: u8,
+    ore: u32,
+    clay: u32,
+    obsidian: u32,
+    geodes: u32,
+    ore_robots: u32,
+    clay_robots: u32,
+    obsidian_robots: u32,
+    geode_robots: u32,
+}
+
+impl Default for State {
+    fn default() -> Self {
+        Self {
+            minute: 0,
+            ore: 0,
+            clay: 0,
+            obsidian: 0,
+            geodes: 0,
+            ore_robots: 1,
+            clay_robots: 0,
+            obsidian_robots: 0,
+            geode_robots: 0,
+        }
+    }
+}
+
+impl State {
+    fn get_states(&self, blueprint: &Blueprint) -> Vec<State> {
+        let mut states = Vec::new();
+        let template = self.do_nothing_state();
+        states.push(template.clone());
+        if self.can_build(&blueprint.ore_cost) && self.ore_makes_sense(blueprint) {
+            let mut new = template.with_cost(&blueprint.ore_cost);
+            new.ore_robots += 1;
+            states.push(new);
+        }
+        if self.can_build(&blueprint.clay_cost) && self.clay_makes_sense(blueprint) {
+            let mut new = template.with_cost(&blueprint.clay_cost);
+            new.clay_robots += 1;
+            states.push(new);
+        }
+        if self.can_build(&blueprint.obsidian_cost) && self.obsidian_make_sense(blueprint) {
+            let mut new = template.with_cost(&blueprint.obsidian_cost);
+            new.obsidian_robots += 1;
+            states.push(new);
+        }
+        if self.can_build(&blueprint.geode_cost) {
+            let mut new = template.with_cost(&blueprint.geode_cost);
+            new.geode_robots += 1;
+            states.push(new);
+        }
+        states
+    }
+
+    fn ore_makes_sense(&self, blueprint: &Blueprint) -> bool {
+        let max_ore_cost = [
+            &blueprint.ore_cost,
+            &blueprint.clay_cost,
+            &blueprint.obsidian_cost,
+            &blueprint.geode_cost,
+        ]
+        .into_iter()
+        .map(|cost| cost.ore)
+        .max()
+        .unwrap();
+        self.ore_robots < max_ore_cost
+    }
+
+    fn clay_makes_sense(&self, blueprint: &Blueprint) -> bool {
+        self.clay_robots < blueprint.obsidian_cost.clay
+    }
+
+    fn obsidian_make_sense(&self, blueprint: &Blueprint) -> bool {
+        self.obsidian_robots < blueprint.geode_cost.obsidian
+    }
+
+    fn can_improve(&self, geodes: u32, max_minutes: u8) -> bool {
+        let remaining = (max_minutes - self.minute) as u32;
+        let current_robots_profit = self.geode_robots * remaining;
+        // We assume we can build a robot each turn
+        let new_robots_profit = remaining * (remaining - 1) / 2;
+        self.geodes + current_robots_profit + new_robots_profit > geodes
+    }
+
+    fn with_cost(&self, cost: &Cost) -> Self {
+        let mut new = self.clone();
+        new.ore -= cost.ore;
+        new.clay -= cost.clay;
+        new.obsidian -= cost.obsidian;
+        new
+    }
+
+    fn can_build(&self, cost: &Cost) -> bool {
+        self.ore >= cost.ore && self.clay >= cost.clay && self.obsidian >= cost.obsidian
+    }
+
+    fn do_nothing_state(&self) -> Self {
+        let mut template = self.clone();
+        template.minute += 1;
+        template.ore += self.ore_robots;
+        template.clay += self.clay_robots;
+        template.obsidian += self.obsidian_robots;
+        template.geodes += self.geode_robots;
+        template
+    }
+}
+
+impl FromStr for Blueprint {
+    type Err = ParseError;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        let (_, costs) = value
+            .split_once(":")
+            .ok_or_else(|| ParseError::missing("':'").span(value))?;
+        let mut it = costs.trim().split(".");
+        let mut next = |what: &'static str| -> Result<Cost, ParseError> {
+            it.next()
+                .ok_or_else(|| ParseError::new(format!("missing {what} cost")).span(value))?
+                .trim()
+                .parse()
+                .map_err(|err: ParseError| err.context(what))
+        };
+        Ok(Self {
+            ore_cost: next("ore robot")?,
+            clay_cost: next("clay robot")?,
+            obsidian_cost: next("obsidian robot")?,
+            geode_cost: next("geode robot")?,
+        })
+    }
+}
+
+#[derive(Debug, Clone)]
+struct Cost {
+    ore: u32,
+    clay: u32,
+    obsidian: u32,
+}
+
+impl FromStr for Cost {
+    type Err = ParseError;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        let mut it = value.split_whitespace().skip(4);
+        let ore = parse_amount(it.next(), value)?;
+        let mut cost = Cost {
+            ore,
+            clay: 0,
+            obsidian: 0,
+        };
+        if let Some(additional) = Additional::new(it.collect(), value)? {
+            match additional {
+                Additional::Clay(v) => cost.clay = v,
+                Additional::Obsidian(v) => cost.obsidian = v,
+            };
+        }
+        Ok(cost)
+    }
+}
+
+fn parse_amount(amount: Option<&str>, cost: &str) -> Result<u32, ParseError> {
+    let amount = amount.ok_or_else(|| ParseError::missing("amount").span(cost))?;
+    amount
+        .parse()
+        .map_err(|err| ParseError::invalid("amount", amount).with_cause(err))
+}
+
+enum Additional {
+    Clay(u32),
+    Obsidian(u32),
+}
+
+impl Additional {
+    fn new(value: Vec<&str>, cost: &str) -> Result<Option<Self>, ParseError> {
+        let Some(v) = value.get(2) else {
+            return Ok(None);
+        };
+        let v = parse_amount(Some(v), cost)?;
+        match value.get(3).copied() {
+            Some("clay") => Ok(Some(Self::Clay(v))),
+            Some("obsidian") => Ok(Some(Self::Obsidian(v))),
+            Some(material) => Err(ParseError::invalid("material", material)),
+            None => Err(ParseError::missing("material").span(cost)),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Solution;
+    use aoc::Part;
+
+    const EXAMPLE: &str = include_str!("../assets/example.txt");
+
+    #[test]
+    fn example() {
+        assert_eq!(aoc::solve::<Solution>(EXAMPLE, Part::One).unwrap(), "33");
+    }
+}
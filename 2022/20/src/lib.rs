@@ -0,0 +1,115 @@
+use aoc::ParseError;
+use std::fmt::Display;
+
+pub const DAY: aoc::Day = aoc::day!(2022, 20, Solution);
+
+pub struct Solution;
+
+impl aoc::Solver for Solution {
+    type Input = Vec<i64>;
+    type Error = &'static str;
+
+    fn parse(input: &str) -> Result<Vec<i64>, ParseError> {
+        input
+            .lines()
+            .map(|l| {
+                l.parse()
+                    .map_err(|err| ParseError::invalid("number", l).with_cause(err))
+            })
+            .collect()
+    }
+
+    fn part1(nums: &Vec<i64>) -> Result<impl Display, &'static str> {
+        mix(nums, 1, 1)
+    }
+
+    fn part2(nums: &Vec<i64>) -> Result<impl Display, &'static str> {
+        mix(nums, 811589153, 10)
+    }
+}
+
+fn mix(nums: &[i64], key: i64, rounds: usize) -> Result<i64, &'static str> {
+    let mut nums: Vec<_> = nums.iter().map(|v| v * key).enumerate().collect();
+    for _ in 0..rounds {
+        for num in 0..nums.len() {
+            shuffle(&mut nums, num);
+        }
+    }
+    let start = nums
+        .iter()
+        .position(|&v| v.1 == 0)
+        .ok_or("start not found")?;
+    Ok((1..=3)
+        .map(|v| v * 1000)
+        .map(|v| start + v)
+        .map(|v| v % nums.len())
+        .map(|idx| nums[idx].1)
+        .sum())
+}
+
+fn shuffle(nums: &mut Vec<(usize, i64)>, num: usize) {
+    let mut idx = nums
+        .iter()
+        .position(|&v| v.0 == num)
+        .expect("number not found");
+    let wrap = nums.len() as i64;
+    let mut destination = idx as i64 + nums.get(idx).unwrap().1;
+    if destination < 0 {
+        destination += (wrap - 1) * (-destination / wrap);
+        while destination < 0 {
+            destination += wrap - 1;
+        }
+    } else if destination >= wrap {
+        destination -= (wrap - 1) * (destination / wrap);
+        while destination >= wrap {
+            destination -= wrap - 1;
+        }
+    }
+
+    let movement = destination - idx as i64;
+    let dist = movement.abs();
+    let dir = movement.signum();
+    for _ in 0..dist {
+        let next = (idx as i64 + dir) as usize;
+        nums.swap(idx, next);
+        idx = next;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aoc::Part;
+
+    #[test]
+    fn positive_movement() {
+        let mut nums = vec![4, 5, 6, 1, 7, 8, 9].into_iter().enumerate().collect();
+        shuffle(&mut nums, 3);
+        assert_eq!(
+            nums.into_iter().map(|v| v.1).collect::<Vec<_>>(),
+            vec![4, 5, 6, 7, 1, 8, 9]
+        );
+    }
+
+    #[test]
+    fn negative_movement() {
+        let mut nums = vec![4, -2, 5, 6, 7, 8, 9].into_iter().enumerate().collect();
+        shuffle(&mut nums, 1);
+        assert_eq!(
+            nums.into_iter().map(|v| v.1).collect::<Vec<_>>(),
+            vec![4, 5, 6, 7, 8, -2, 9]
+        );
+    }
+
+    #[test]
+    fn example_part1() {
+        let s = include_str!("../assets/example.txt");
+        assert_eq!(aoc::solve::<Solution>(s, Part::One).unwrap(), "3");
+    }
+
+    #[test]
+    fn example_part2() {
+        let s = include_str!("../assets/example.txt");
+        assert_eq!(aoc::solve::<Solution>(s, Part::Two).unwrap(), "1623178306");
+    }
+}
@@ -0,0 +1,233 @@
+use aoc::ParseError;
+use std::{collections::HashMap, fmt::Display, str::FromStr};
+
+pub const DAY: aoc::Day = aoc::day!(2022, 21, Solution);
+
+pub struct Solution;
+
+impl aoc::Solver for Solution {
+    type Input = Problem;
+    type Error = &'static str;
+
+    fn parse(input: &str) -> Result<Problem, ParseError> {
+        input.parse()
+    }
+
+    fn part1(problem: &Problem) -> Result<impl Display, &'static str> {
+        Ok(problem.evaluate("root"))
+    }
+
+    fn part2(problem: &Problem) -> Result<impl Display, &'static str> {
+        Ok(problem.equality("root", "humn"))
+    }
+}
+
+pub struct Problem {
+    monkeys: HashMap<String, Monkey>,
+}
+
+impl FromStr for Problem {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let monkeys = s
+            .lines()
+            .map(|line| line.parse::<Monkey>())
+            .map(|monkey| monkey.map(|monkey| (monkey.name.clone(), monkey)))
+            .collect::<Result<_, _>>()?;
+        Ok(Self { monkeys })
+    }
+}
+
+impl Problem {
+    fn value(&self, name: &str) -> &MonkeyValue {
+        &self.monkeys.get(name).unwrap().value
+    }
+
+    fn evaluate(&self, name: &str) -> i64 {
+        match self.value(name) {
+            MonkeyValue::Literal(value) => *value,
+            MonkeyValue::Operation {
+                operator,
+                left,
+                right,
+            } => {
+                let left = self.evaluate(left);
+                let right = self.evaluate(right);
+                operator.apply(left, right)
+            }
+        }
+    }
+
+    fn equality(&self, equality: &str, unkown: &str) -> i64 {
+        match self.value(equality) {
+            MonkeyValue::Literal(_) => panic!(),
+            MonkeyValue::Operation { left, right, .. } => {
+                match (
+                    self.has_unkown(left, unkown),
+                    self.has_unkown(right, unkown),
+                ) {
+                    (true, false) => self.solve(self.evaluate(right), left, unkown),
+                    (false, true) => self.solve(self.evaluate(left), right, unkown),
+                    (_, _) => panic!(),
+                }
+            }
+        }
+    }
+
+    fn has_unkown(&self, name: &str, unkown: &str) -> bool {
+        match (name == unkown, self.value(name)) {
+            (true, _) => true,
+            (false, MonkeyValue::Literal(_)) => false,
+            (false, MonkeyValue::Operation { left, right, .. }) => {
+                self.has_unkown(left, unkown) || self.has_unkown(right, unkown)
+            }
+        }
+    }
+
+    fn solve(&self, result: i64, name: &str, unkown: &str) -> i64 {
+        if name == unkown {
+            result
+        } else {
+            if let MonkeyValue::Operation {
+                operator,
+                left,
+                right,
+            } = self.value(name)
+            {
+                match (
+                    self.has_unkown(left, unkown),
+                    self.has_unkown(right, unkown),
+                ) {
+                    (true, false) => self.solve(
+                        operator.missing_left(result, self.evaluate(right)),
+                        left,
+                        unkown,
+                    ),
+                    (false, true) => self.solve(
+                        operator.missing_right(result, self.evaluate(left)),
+                        right,
+                        unkown,
+                    ),
+                    (_, _) => panic!(),
+                }
+            } else {
+                panic!()
+            }
+        }
+    }
+}
+
+#[derive(Debug)]
+struct Monkey {
+    name: String,
+    value: MonkeyValue,
+}
+
+impl FromStr for Monkey {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (name, value) = s
+            .split_once(": ")
+            .ok_or_else(|| ParseError::missing("':'").span(s))?;
+        let name = name.to_string();
+        let value = value.parse()?;
+        Ok(Self { name, value })
+    }
+}
+
+#[derive(Debug)]
+enum MonkeyValue {
+    Literal(i64),
+    Operation {
+        operator: Operator,
+        left: String,
+        right: String,
+    },
+}
+
+impl FromStr for MonkeyValue {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        if let Ok(literal) = s.parse() {
+            Ok(MonkeyValue::Literal(literal))
+        } else {
+            let mut it = s.split_whitespace();
+            let mut next = |what| it.next().ok_or_else(|| ParseError::missing(what).span(s));
+            let left = next("left operand")?.to_string();
+            let operator = next("operator")?.parse()?;
+            let right = next("right operand")?.to_string();
+            Ok(Self::Operation {
+                operator,
+                left,
+                right,
+            })
+        }
+    }
+}
+
+#[derive(Debug)]
+enum Operator {
+    Add,
+    Sub,
+    Mul,
+    Div,
+}
+impl Operator {
+    fn apply(&self, left: i64, right: i64) -> i64 {
+        match self {
+            Operator::Add => left + right,
+            Operator::Sub => left - right,
+            Operator::Mul => left * right,
+            Operator::Div => left / right,
+        }
+    }
+
+    fn missing_left(&self, result: i64, right: i64) -> i64 {
+        match self {
+            Operator::Add => result - right,
+            Operator::Sub => result + right,
+            Operator::Mul => result / right,
+            Operator::Div => result * right,
+        }
+    }
+
+    fn missing_right(&self, result: i64, left: i64) -> i64 {
+        match self {
+            Operator::Add => result - left,
+            Operator::Sub => left - result,
+            Operator::Mul => result / left,
+            Operator::Div => left / result,
+        }
+    }
+}
+
+impl FromStr for Operator {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "+" => Ok(Operator::Add),
+            "-" => Ok(Operator::Sub),
+            "*" => Ok(Operator::Mul),
+            "/" => Ok(Operator::Div),
+            _ => Err(ParseError::invalid("operator", s)),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Solution;
+    use aoc::Part;
+
+    const EXAMPLE: &str = include_str!("../assets/example.txt");
+
+    #[test]
+    fn example() {
+        assert_eq!(aoc::solve::<Solution>(EXAMPLE, Part::One).unwrap(), "152");
+        assert_eq!(aoc::solve::<Solution>(EXAMPLE, Part::Two).unwrap(), "301");
+    }
+}
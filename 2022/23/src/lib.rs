@@ -0,0 +1,251 @@
+use aoc::{
+    geom::{Dir8, Vec2},
+    ParseError,
+};
+use std::{collections::HashSet, fmt::Display, str::FromStr};
+
+pub const DAY: aoc::Day = aoc::day!(2022, 23, Solution);
+
+pub struct Solution;
+
+impl aoc::Solver for Solution {
+    type Input = State;
+    type Error = &'static str;
+
+    fn parse(input: &str) -> Result<State, ParseError> {
+        input.parse()
+    }
+
+    fn part1(state: &State) -> Result<impl Display, &'static str> {
+        let mut state = state.clone();
+        for _ in 0..10 {
+            state.next();
+        }
+        Ok(state.empty_tiles())
+    }
+
+    fn part2(state: &State) -> Result<impl Display, &'static str> {
+        Ok(state.clone().rounds_until_spaced())
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct State {
+    elf_positions: Vec<Vec2<i64>>,
+    moves: [Move; 4],
+}
+
+impl State {
+    fn rounds_until_spaced(&mut self) -> usize {
+        let mut count = 0;
+        while self.next() {
+            count += 1
+        }
+        count + 1
+    }
+
+    fn next(&mut self) -> bool {
+        let mut elf_positions = Vec::with_capacity(self.elf_positions.len());
+        for elf_position in self.elf_positions.iter() {
+            let has_other_arround = Dir8::ALL
+                .iter()
+                .map(|dir| *elf_position + dir.delta())
+                .any(|position| self.elf_positions.contains(&position));
+            let new_position = if has_other_arround {
+                self.moves
+                    .iter()
+                    .find(|m| {
+                        let is_occupied = m.deltas().into_iter().any(|delta| {
+                            let new_position = *elf_position + delta;
+                            self.elf_positions.contains(&new_position)
+                        });
+                        !is_occupied
+                    })
+                    .map(|m| *elf_position + m.delta())
+                    .unwrap_or(*elf_position)
+            } else {
+                *elf_position
+            };
+            elf_positions.push(new_position);
+        }
+        let mut found = HashSet::new();
+        let mut repeated = HashSet::new();
+        for elf_position in elf_positions.iter() {
+            if found.contains(elf_position) {
+                repeated.insert(*elf_position);
+            } else {
+                found.insert(*elf_position);
+            }
+        }
+        for (idx, elf_position) in elf_positions.iter_mut().enumerate() {
+            if repeated.contains(elf_position) {
+                *elf_position = self.elf_positions[idx];
+            }
+        }
+        for i in 0..self.moves.len() - 1 {
+            self.moves.swap(i, i + 1);
+        }
+        if self.elf_positions != elf_positions {
+            self.elf_positions = elf_positions;
+            true
+        } else {
+            false
+        }
+    }
+
+    fn empty_tiles(&self) -> usize {
+        let width = self.elf_positions.iter().map(|p| p.x).max().unwrap()
+            - self.elf_positions.iter().map(|p| p.x).min().unwrap()
+            + 1;
+        let height = self.elf_positions.iter().map(|p| p.y).max().unwrap()
+            - self.elf_positions.iter().map(|p| p.y).min().unwrap()
+            + 1;
+        width as usize * height as usize - self.elf_positions.len()
+    }
+}
+
+impl FromStr for State {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut position = Vec2::ZERO;
+        let mut elf_positions = Vec::new();
+        for line in s.lines() {
+            for (idx, c) in line.char_indices() {
+                match c {
+                    '#' => elf_positions.push(position),
+                    '.' => {}
+                    _ => return Err(ParseError::invalid("tile", &line[idx..idx + c.len_utf8()])),
+                }
+                position += Dir8::East.delta();
+            }
+            position += Dir8::South.delta();
+            position.x = 0;
+        }
+        let moves = [Move::North, Move::South, Move::West, Move::East];
+        Ok(Self {
+            elf_positions,
+            moves,
+        })
+    }
+}
+
+#[derive(Debug, Clone)]
+enum Move {
+    North,
+    East,
+    South,
+    West,
+}
+
+impl Move {
+    fn deltas(&self) -> [Vec2<i64>; 3] {
+        let dir = self.dir();
+        [dir, dir.turn_left(), dir.turn_right()].map(Dir8::delta)
+    }
+
+    fn delta(&self) -> Vec2<i64> {
+        self.dir().delta()
+    }
+
+    fn dir(&self) -> Dir8 {
+        match self {
+            Move::North => Dir8::North,
+            Move::East => Dir8::East,
+            Move::South => Dir8::South,
+            Move::West => Dir8::West,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_small_example() {
+        let example = include_str!("../assets/small_example.txt");
+        let state: State = example.parse().expect("expected parsed initial state");
+        assert_eq!(state.elf_positions.len(), 5);
+        assert!(state.elf_positions.contains(&Vec2::new(2, 1)));
+        assert!(state.elf_positions.contains(&Vec2::new(3, 1)));
+        assert!(state.elf_positions.contains(&Vec2::new(2, 2)));
+        assert!(state.elf_positions.contains(&Vec2::new(2, 4)));
+        assert!(state.elf_positions.contains(&Vec2::new(3, 4)));
+    }
+
+    #[test]
+    fn one_state_transition() {
+        let example = include_str!("../assets/small_example.txt");
+        let mut state: State = example.parse().expect("expected parsed initial state");
+        state.next();
+        assert_eq!(state.elf_positions.len(), 5);
+        assert!(state.elf_positions.contains(&Vec2::new(2, 0)));
+        assert!(state.elf_positions.contains(&Vec2::new(3, 0)));
+        assert!(state.elf_positions.contains(&Vec2::new(2, 2)));
+        assert!(state.elf_positions.contains(&Vec2::new(2, 4)));
+        assert!(state.elf_positions.contains(&Vec2::new(3, 3)));
+    }
+
+    #[test]
+    fn two_state_transition() {
+        let example = include_str!("../assets/small_example.txt");
+        let mut state: State = example.parse().expect("expected parsed initial state");
+        state.next();
+        state.next();
+        assert_eq!(state.elf_positions.len(), 5);
+        assert!(state.elf_positions.contains(&Vec2::new(2, 1)));
+        assert!(state.elf_positions.contains(&Vec2::new(3, 1)));
+        assert!(state.elf_positions.contains(&Vec2::new(1, 2)));
+        assert!(state.elf_positions.contains(&Vec2::new(4, 3)));
+        assert!(state.elf_positions.contains(&Vec2::new(2, 5)));
+    }
+
+    #[test]
+    fn stable_state_when_separated() {
+        let example = include_str!("../assets/small_example.txt");
+        let mut state: State = example.parse().expect("expected parsed initial state");
+        for _ in 0..10 {
+            state.next();
+        }
+        assert_eq!(state.elf_positions.len(), 5);
+        assert!(state.elf_positions.contains(&Vec2::new(2, 0)));
+        assert!(state.elf_positions.contains(&Vec2::new(4, 1)));
+        assert!(state.elf_positions.contains(&Vec2::new(0, 2)));
+        assert!(state.elf_positions.contains(&Vec2::new(4, 3)));
+        assert!(state.elf_positions.contains(&Vec2::new(2, 5)));
+    }
+
+    #[test]
+    fn example_final_state() {
+        let example = include_str!("../assets/example.txt");
+        let mut state: State = example.parse().expect("expected parsed initial state");
+        for _ in 0..10 {
+            state.next();
+        }
+        assert_eq!(state.elf_positions.len(), 22);
+        assert!(state.elf_positions.contains(&Vec2::new(7, 0)));
+        assert!(state.elf_positions.contains(&Vec2::new(11, 1)));
+        assert!(state.elf_positions.contains(&Vec2::new(2, 2)));
+        assert!(state.elf_positions.contains(&Vec2::new(4, 2)));
+        assert!(state.elf_positions.contains(&Vec2::new(7, 2)));
+        assert!(state.elf_positions.contains(&Vec2::new(6, 3)));
+    }
+
+    #[test]
+    fn example_part1() {
+        let example = include_str!("../assets/example.txt");
+        let mut state: State = example.parse().expect("expected parsed initial state");
+        for _ in 0..10 {
+            state.next();
+        }
+        assert_eq!(state.empty_tiles(), 110);
+    }
+
+    #[test]
+    fn example_part2() {
+        let example = include_str!("../assets/example.txt");
+        let mut state: State = example.parse().expect("expected parsed initial state");
+        assert_eq!(state.rounds_until_spaced(), 20);
+    }
+}
@@ -0,0 +1,392 @@
+use aoc::{
+    geom::{Dir4, Vec2},
+    math::Integer,
+    ParseError,
+};
+use std::{collections::HashSet, fmt::Display};
+
+pub const DAY: aoc::Day = aoc::day!(2022, 24, Solution);
+
+pub struct Solution;
+
+impl aoc::Solver for Solution {
+    type Input = Valley;
+    type Error = &'static str;
+
+    fn parse(input: &str) -> Result<Valley, ParseError> {
+        input.parse()
+    }
+
+    fn part1(valley: &Valley) -> Result<impl Display, &'static str> {
+        valley.faster_crossing().ok_or("no path through the valley")
+    }
+
+    fn part2(valley: &Valley) -> Result<impl Display, &'static str> {
+        valley
+            .faster_roundtrip()
+            .ok_or("no path through the valley")
+    }
+}
+
+const DELTAS: [Vec2<i64>; 5] = [
+    Vec2::new(0, -1),
+    Vec2::new(0, 1),
+    Vec2::new(1, 0),
+    Vec2::new(-1, 0),
+    Vec2::ZERO,
+];
+
+#[derive(Debug, Clone)]
+pub struct Valley {
+    positions: HashSet<Vec2<i64>>,
+    blizzards: Vec<Blizzard>,
+}
+
+impl Valley {
+    fn faster_crossing(&self) -> Option<usize> {
+        let blocked = self.blocked();
+        self.go(&blocked, self.start(), &self.end(), 0)
+    }
+
+    fn faster_roundtrip(&self) -> Option<usize> {
+        let blocked = self.blocked();
+        let start = self.start();
+        let end = self.end();
+        let there = self.go(&blocked, start, &end, 0)?;
+        let back = self.go(&blocked, end, &start, there)?;
+        let again = self.go(&blocked, start, &end, there + back)?;
+        Some(there + back + again)
+    }
+
+    fn go(
+        &self,
+        blocked: &[HashSet<Vec2<i64>>],
+        start: Vec2<i64>,
+        end: &Vec2<i64>,
+        time: usize,
+    ) -> Option<usize> {
+        aoc::search::bfs(
+            [(start, time % blocked.len())],
+            |(position, time)| {
+                let time = (time + 1) % blocked.len();
+                DELTAS
+                    .iter()
+                    .map(|delta| *position + *delta)
+                    .filter(|new_position| {
+                        self.positions.contains(new_position)
+                            && !blocked[time].contains(new_position)
+                    })
+                    .map(|new_position| (new_position, time))
+                    .collect::<Vec<_>>()
+            },
+            |(position, _)| position == end,
+        )
+        .cost()
+    }
+
+    fn blocked(&self) -> Vec<HashSet<Vec2<i64>>> {
+        let north_west = self.north_west();
+        let south_east = self.south_east();
+        let rows = south_east.y - north_west.y + 1;
+        let cols = south_east.x - north_west.x + 1;
+        let period = rows.lcm(cols).unwrap_or(1).max(1);
+        let mut valley = self.clone();
+        let mut blocked = Vec::new();
+        for _ in 0..period {
+            blocked.push(
+                valley
+                    .blizzards
+                    .iter()
+                    .map(|blizzard| blizzard.position)
+                    .collect(),
+            );
+            valley = valley.next();
+        }
+        blocked
+    }
+
+    fn start(&self) -> Vec2<i64> {
+        self.positions
+            .iter()
+            .min_by_key(|position| position.y)
+            .copied()
+            .unwrap()
+    }
+
+    fn end(&self) -> Vec2<i64> {
+        self.positions
+            .iter()
+            .max_by_key(|position| position.y)
+            .copied()
+            .unwrap()
+    }
+
+    fn next(&self) -> Self {
+        let positions = self.positions.clone();
+        let north_west = self.north_west();
+        let south_east = self.south_east();
+        let blizzards = self
+            .blizzards
+            .iter()
+            .map(|blizzard| blizzard.advance(&north_west, &south_east))
+            .collect();
+        Self {
+            positions,
+            blizzards,
+        }
+    }
+
+    fn north_west(&self) -> Vec2<i64> {
+        let north = self
+            .positions
+            .iter()
+            .map(|position| position.y)
+            .min()
+            .unwrap()
+            + 1;
+        let west = self
+            .positions
+            .iter()
+            .map(|position| position.x)
+            .min()
+            .unwrap();
+        Vec2::new(west, north)
+    }
+
+    fn south_east(&self) -> Vec2<i64> {
+        let south = self
+            .positions
+            .iter()
+            .map(|position| position.y)
+            .max()
+            .unwrap()
+            - 1;
+        let east = self
+            .positions
+            .iter()
+            .map(|position| position.x)
+            .max()
+            .unwrap();
+        Vec2::new(east, south)
+    }
+}
+
+impl std::str::FromStr for Valley {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut positions = HashSet::new();
+        let mut blizzards = Vec::new();
+
+        let mut position = Vec2::ZERO;
+        for line in s.lines() {
+            for (idx, tile) in line.char_indices() {
+                parse_tile(&mut positions, &mut blizzards, &position, tile)
+                    .map_err(|err| err.span(&line[idx..idx + tile.len_utf8()]))?;
+                position.x += 1;
+            }
+            position.y += 1;
+            position.x = 0;
+        }
+
+        Ok(Self {
+            positions,
+            blizzards,
+        })
+    }
+}
+
+fn parse_tile(
+    positions: &mut HashSet<Vec2<i64>>,
+    blizzards: &mut Vec<Blizzard>,
+    position: &Vec2<i64>,
+    tile: char,
+) -> Result<(), ParseError> {
+    match tile {
+        '.' => {
+            positions.insert(*position);
+            Ok(())
+        }
+        '>' => {
+            positions.insert(*position);
+            blizzards.push(Blizzard {
+                position: *position,
+                delta: Dir4::East.delta(),
+            });
+            Ok(())
+        }
+        '<' => {
+            positions.insert(*position);
+            blizzards.push(Blizzard {
+                position: *position,
+                delta: Dir4::West.delta(),
+            });
+            Ok(())
+        }
+        '^' => {
+            positions.insert(*position);
+            blizzards.push(Blizzard {
+                position: *position,
+                delta: Dir4::North.delta(),
+            });
+            Ok(())
+        }
+        'v' => {
+            positions.insert(*position);
+            blizzards.push(Blizzard {
+                position: *position,
+                delta: Dir4::South.delta(),
+            });
+            Ok(())
+        }
+        '#' => Ok(()),
+        _ => Err("unexpected symbol".into()),
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct Blizzard {
+    position: Vec2<i64>,
+    delta: Vec2<i64>,
+}
+impl Blizzard {
+    fn advance(&self, north_west: &Vec2<i64>, south_east: &Vec2<i64>) -> Blizzard {
+        let mut position = self.position + self.delta;
+        let delta = self.delta;
+        if position.y > south_east.y {
+            position.y = north_west.y;
+        } else if position.y < north_west.y {
+            position.y = south_east.y;
+        }
+        if position.x > south_east.x {
+            position.x = north_west.x;
+        } else if position.x < north_west.x {
+            position.x = south_east.x;
+        }
+        Blizzard { position, delta }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn simple_example_parsing() {
+        let s = include_str!("../assets/simple_example.txt");
+        let valley: Valley = s.parse().expect("should parse valley");
+        assert!(valley.positions.contains(&Vec2::new(1, 0)));
+        for i in 1..=5 {
+            for j in 1..=5 {
+                assert!(valley.positions.contains(&Vec2::new(j, i)))
+            }
+        }
+        assert!(valley.positions.contains(&Vec2::new(5, 6)));
+        assert_eq!(valley.blizzards.len(), 2);
+        assert!(valley.blizzards.contains(&Blizzard {
+            position: Vec2::new(1, 2),
+            delta: Dir4::East.delta()
+        }));
+        assert!(valley.blizzards.contains(&Blizzard {
+            position: Vec2::new(4, 4),
+            delta: Dir4::South.delta()
+        }));
+    }
+
+    #[test]
+    fn regular_move() {
+        let s = include_str!("../assets/simple_example.txt");
+        let valley: Valley = s.parse().expect("should parse valley");
+        let new_valley = valley.next();
+        assert!(new_valley.blizzards.contains(&Blizzard {
+            position: Vec2::new(2, 2),
+            delta: Dir4::East.delta()
+        }));
+        assert!(new_valley.blizzards.contains(&Blizzard {
+            position: Vec2::new(4, 5),
+            delta: Dir4::South.delta()
+        }));
+    }
+
+    #[test]
+    fn bottom_wrapping_move() {
+        let s = include_str!("../assets/simple_example.txt");
+        let mut valley: Valley = s.parse().expect("should parse valley");
+        for _ in 0..2 {
+            valley = valley.next();
+        }
+        assert!(valley.blizzards.contains(&Blizzard {
+            position: Vec2::new(3, 2),
+            delta: Dir4::East.delta()
+        }));
+        assert!(valley.blizzards.contains(&Blizzard {
+            position: Vec2::new(4, 1),
+            delta: Dir4::South.delta()
+        }));
+    }
+
+    #[test]
+    fn superposition_move() {
+        let s = include_str!("../assets/simple_example.txt");
+        let mut valley: Valley = s.parse().expect("should parse valley");
+        for _ in 0..3 {
+            valley = valley.next();
+        }
+        assert!(valley.blizzards.contains(&Blizzard {
+            position: Vec2::new(4, 2),
+            delta: Dir4::East.delta()
+        }));
+        assert!(valley.blizzards.contains(&Blizzard {
+            position: Vec2::new(4, 2),
+            delta: Dir4::South.delta()
+        }));
+    }
+
+    #[test]
+    fn after_superposition() {
+        let s = include_str!("../assets/simple_example.txt");
+        let mut valley: Valley = s.parse().expect("should parse valley");
+        for _ in 0..4 {
+            valley = valley.next();
+        }
+        assert!(valley.blizzards.contains(&Blizzard {
+            position: Vec2::new(5, 2),
+            delta: Dir4::East.delta()
+        }));
+        assert!(valley.blizzards.contains(&Blizzard {
+            position: Vec2::new(4, 3),
+            delta: Dir4::South.delta()
+        }));
+    }
+
+    #[test]
+    fn right_wrapping() {
+        let s = include_str!("../assets/simple_example.txt");
+        let mut valley: Valley = s.parse().expect("should parse valley");
+        for _ in 0..5 {
+            valley = valley.next();
+        }
+        assert!(valley.blizzards.contains(&Blizzard {
+            position: Vec2::new(1, 2),
+            delta: Dir4::East.delta()
+        }));
+        assert!(valley.blizzards.contains(&Blizzard {
+            position: Vec2::new(4, 4),
+            delta: Dir4::South.delta()
+        }));
+    }
+
+    #[test]
+    fn fastest_route() {
+        let s = include_str!("../assets/example.txt");
+        let valley: Valley = s.parse().expect("should parse valley");
+        assert_eq!(valley.faster_crossing(), Some(18));
+    }
+
+    #[test]
+    fn round_trip() {
+        let s = include_str!("../assets/example.txt");
+        let valley: Valley = s.parse().expect("should parse valley");
+        assert_eq!(valley.faster_roundtrip(), Some(54));
+    }
+}
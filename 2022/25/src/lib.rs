@@ -0,0 +1,248 @@
+use aoc::ParseError;
+use std::fmt::Display;
+
+pub const DAY: aoc::Day = aoc::day!(2022, 25, Solution);
+
+pub struct Solution;
+
+impl aoc::Solver for Solution {
+    type Input = Vec<i64>;
+    type Error = &'static str;
+
+    fn parse(input: &str) -> Result<Vec<i64>, ParseError> {
+        input
+            .lines()
+            .map(|line| {
+                line.char_indices()
+                    .map(|(idx, c)| {
+                        SnafuPengit::try_from(c)
+                            .map_err(|err| err.span(&line[idx..idx + c.len_utf8()]))
+                    })
+                    .collect::<Result<Vec<_>, _>>()
+                    .map(|n| to_decimal(&n))
+            })
+            .collect()
+    }
+
+    fn part1(numbers: &Vec<i64>) -> Result<impl Display, &'static str> {
+        Ok(to_snafu(numbers.iter().sum())
+            .into_iter()
+            .map(char::from)
+            .collect::<String>())
+    }
+
+    fn part2(_numbers: &Vec<i64>) -> Result<impl Display, &'static str> {
+        Ok("Done")
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum SnafuPengit {
+    Two,
+    One,
+    Zero,
+    Minus,
+    Equal,
+}
+
+impl From<SnafuPengit> for char {
+    fn from(value: SnafuPengit) -> Self {
+        match value {
+            SnafuPengit::Two => '2',
+            SnafuPengit::One => '1',
+            SnafuPengit::Zero => '0',
+            SnafuPengit::Minus => '-',
+            SnafuPengit::Equal => '=',
+        }
+    }
+}
+
+impl TryFrom<char> for SnafuPengit {
+    type Error = ParseError;
+
+    fn try_from(value: char) -> Result<Self, Self::Error> {
+        match value {
+            '2' => Ok(Self::Two),
+            '1' => Ok(Self::One),
+            '0' => Ok(Self::Zero),
+            '-' => Ok(Self::Minus),
+            '=' => Ok(Self::Equal),
+            _ => Err(ParseError::new(format!("invalid digit '{value}'"))),
+        }
+    }
+}
+
+impl From<SnafuPengit> for i64 {
+    fn from(value: SnafuPengit) -> Self {
+        match value {
+            SnafuPengit::Two => 2,
+            SnafuPengit::One => 1,
+            SnafuPengit::Zero => 0,
+            SnafuPengit::Minus => -1,
+            SnafuPengit::Equal => -2,
+        }
+    }
+}
+
+fn to_decimal(n: &[SnafuPengit]) -> i64 {
+    n.iter()
+        .rev()
+        .enumerate()
+        .map(|(idx, s)| i64::from(*s) * 5i64.pow(idx as u32))
+        .sum()
+}
+
+fn to_snafu(n: i64) -> Vec<SnafuPengit> {
+    let mut start = vec![SnafuPengit::Two];
+    while to_decimal(&start) < n {
+        start.push(SnafuPengit::Two);
+    }
+    for i in 0..start.len() {
+        let mut last = SnafuPengit::Two;
+        for other in [
+            SnafuPengit::One,
+            SnafuPengit::Zero,
+            SnafuPengit::Minus,
+            SnafuPengit::Equal,
+        ] {
+            *start.get_mut(i).unwrap() = other;
+            if to_decimal(&start) < n {
+                break;
+            }
+            last = other;
+        }
+        *start.get_mut(i).unwrap() = last;
+    }
+    start
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aoc::Part;
+
+    #[test]
+    fn convert_one() {
+        assert_eq!(to_snafu(1), vec![SnafuPengit::One]);
+    }
+
+    #[test]
+    fn convert_two() {
+        assert_eq!(to_snafu(2), vec![SnafuPengit::Two]);
+    }
+
+    #[test]
+    fn convert_three() {
+        assert_eq!(to_snafu(3), vec![SnafuPengit::One, SnafuPengit::Equal]);
+    }
+
+    #[test]
+    fn convert_four() {
+        assert_eq!(to_snafu(4), vec![SnafuPengit::One, SnafuPengit::Minus]);
+    }
+
+    #[test]
+    fn convert_five() {
+        assert_eq!(to_snafu(5), vec![SnafuPengit::One, SnafuPengit::Zero]);
+    }
+
+    #[test]
+    fn convert_six() {
+        assert_eq!(to_snafu(6), vec![SnafuPengit::One, SnafuPengit::One]);
+    }
+
+    #[test]
+    fn convert_seven() {
+        assert_eq!(to_snafu(7), vec![SnafuPengit::One, SnafuPengit::Two]);
+    }
+
+    #[test]
+    fn convert_eight() {
+        assert_eq!(to_snafu(8), vec![SnafuPengit::Two, SnafuPengit::Equal]);
+    }
+
+    #[test]
+    fn convert_nine() {
+        assert_eq!(to_snafu(9), vec![SnafuPengit::Two, SnafuPengit::Minus]);
+    }
+
+    #[test]
+    fn convert_ten() {
+        assert_eq!(to_snafu(10), vec![SnafuPengit::Two, SnafuPengit::Zero]);
+    }
+
+    #[test]
+    fn convert_fifteen() {
+        assert_eq!(
+            to_snafu(15),
+            vec![SnafuPengit::One, SnafuPengit::Equal, SnafuPengit::Zero]
+        );
+    }
+
+    #[test]
+    fn convert_twenty() {
+        assert_eq!(
+            to_snafu(20),
+            vec![SnafuPengit::One, SnafuPengit::Minus, SnafuPengit::Zero]
+        );
+    }
+
+    #[test]
+    fn convert_2022() {
+        assert_eq!(
+            to_snafu(2022),
+            vec![
+                SnafuPengit::One,
+                SnafuPengit::Equal,
+                SnafuPengit::One,
+                SnafuPengit::One,
+                SnafuPengit::Minus,
+                SnafuPengit::Two
+            ]
+        );
+    }
+
+    #[test]
+    fn convert_12345() {
+        assert_eq!(
+            to_snafu(12345),
+            vec![
+                SnafuPengit::One,
+                SnafuPengit::Minus,
+                SnafuPengit::Zero,
+                SnafuPengit::Minus,
+                SnafuPengit::Minus,
+                SnafuPengit::Minus,
+                SnafuPengit::Zero,
+            ]
+        );
+    }
+
+    #[test]
+    fn convert_314159265() {
+        assert_eq!(
+            to_snafu(314159265),
+            vec![
+                SnafuPengit::One,
+                SnafuPengit::One,
+                SnafuPengit::Two,
+                SnafuPengit::One,
+                SnafuPengit::Minus,
+                SnafuPengit::One,
+                SnafuPengit::One,
+                SnafuPengit::One,
+                SnafuPengit::Zero,
+                SnafuPengit::Minus,
+                SnafuPengit::One,
+                SnafuPengit::Equal,
+                SnafuPengit::Zero,
+            ]
+        );
+    }
+
+    #[test]
+    fn example_part1() {
+        let s = include_str!("../assets/example.txt");
+        assert_eq!(aoc::solve::<Solution>(s, Part::One).unwrap(), "2=-1=0")
+    }
+}
@@ -1,249 +1,96 @@
-use crate::{generator::PathGenerator, graph::Graph, ice::Ice, path::Path, position::Position};
+use aoc::{
+    geom::{Dir4, Vec2},
+    graph::Graph,
+};
 use std::{
     collections::{HashMap, HashSet},
     str::FromStr,
 };
 
 pub struct Puzzle {
-    open: HashSet<Position>,
-    ice: HashMap<Position, Ice>,
-    start: Position,
-    end: Position,
+    open: HashSet<Vec2<i64>>,
+    ice: HashMap<Vec2<i64>, Dir4>,
+    start: Vec2<i64>,
+    end: Vec2<i64>,
 }
 
 impl Puzzle {
-    pub fn part1(self) -> usize {
-        let graph = self.graph(|puzzle, path, position| puzzle.part1_filter(path, position));
-        GraphPathGenerator { graph: &graph }
-            .paths()
-            .into_iter()
-            .map(|path| {
-                path.distance(|a, b| {
-                    graph
-                        .edges(a)
-                        .find(|edge| edge.destination == b)
-                        .unwrap()
-                        .distance
-                })
-            })
-            .max()
-            .unwrap_or(0)
+    pub fn part1(&self) -> Result<usize, &'static str> {
+        self.longest_walk(|position| self.downhill(position))
     }
 
-    pub fn part2(self) -> usize {
-        let graph = self.graph(|puzzle, path, position| puzzle.part2_filter(path, position));
-        GraphPathGenerator { graph: &graph }
-            .paths()
-            .into_iter()
-            .map(|path| {
-                path.distance(|a, b| {
-                    graph
-                        .edges(a)
-                        .find(|edge| edge.destination == b)
-                        .unwrap()
-                        .distance
-                })
-            })
-            .max()
-            .unwrap_or(0)
+    pub fn part2(&self) -> Result<usize, &'static str> {
+        self.longest_walk(|position| self.steps(position))
     }
 
-    pub fn graph<F>(&self, filter: F) -> Graph
-    where
-        F: Fn(&Puzzle, &Path<Position>, &Position) -> bool + Copy,
-    {
-        let mut nodes = vec![self.start.clone(), self.end.clone()];
-        nodes.extend(
-            self.open
-                .iter()
-                .chain(self.ice.keys())
-                .cloned()
-                .filter(|position| {
-                    Ice::all()
-                        .map(|ice| ice.delta())
-                        .map(|delta| *position + delta)
-                        .into_iter()
-                        .filter(|position| self.valid(position))
-                        .count()
-                        > 2
-                }),
-        );
-        let edges = nodes
-            .iter()
-            .enumerate()
-            .map(|(source_idx, node)| {
-                (
-                    source_idx,
-                    NodePaths {
-                        node: *node,
-                        filter,
-                        puzzle: self,
-                        nodes: &nodes,
-                    }
-                    .paths()
-                    .into_iter()
-                    .map(|path| {
-                        (
-                            nodes.iter().position(|node| node == &path.tail()).unwrap(),
-                            path.len(),
-                        )
-                    })
-                    .collect(),
-                )
-            })
-            .collect();
-        Graph { nodes, edges }
-    }
-
-    fn part1_filter(&self, path: &Path<Position>, position: &Position) -> bool {
-        self.valid(position) && !path.contains(&position) && !self.is_climbing_ice(path, position)
-    }
-
-    fn part2_filter(&self, path: &Path<Position>, position: &Position) -> bool {
-        self.valid(position) && !path.contains(&position)
-    }
-
-    fn valid(&self, position: &Position) -> bool {
-        self.open.contains(position) || self.ice.contains_key(position)
+    fn longest_walk(
+        &self,
+        neighbours: impl FnMut(&Vec2<i64>) -> Vec<Vec2<i64>>,
+    ) -> Result<usize, &'static str> {
+        Graph::contract([self.start, self.end], neighbours)
+            .longest_path(&self.start, &self.end)
+            .map(|path| path.cost)
+            .ok_or("no path from start to end")
     }
 
-    fn is_climbing_ice(&self, path: &Path<Position>, position: &Position) -> bool {
-        self.ice
-            .get(position)
-            .map(|ice| ice.delta())
-            .map(|delta| delta != *position - path.tail())
-            .unwrap_or(false)
-    }
-}
-
-struct Part2PathGenerator {
-    puzzle: Puzzle,
-}
-
-impl PathGenerator for Part2PathGenerator {
-    type Item = Position;
-
-    fn start(&self) -> Self::Item {
-        self.puzzle.start
-    }
-
-    fn next(&self, path: &Path<Self::Item>) -> Vec<Self::Item> {
-        Ice::all()
-            .map(|ice| ice.delta())
-            .map(|delta| path.tail() + delta)
+    fn steps(&self, position: &Vec2<i64>) -> Vec<Vec2<i64>> {
+        Dir4::ALL
+            .map(|dir| *position + dir.delta())
             .into_iter()
-            .filter(|new| self.puzzle.part2_filter(&path, new))
+            .filter(|next| self.valid(next))
             .collect()
     }
 
-    fn close(&self, next: &Self::Item) -> bool {
-        next == &self.puzzle.end
-    }
-}
-
-struct GraphPathGenerator<'a> {
-    graph: &'a Graph,
-}
-
-impl PathGenerator for GraphPathGenerator<'_> {
-    type Item = usize;
-
-    fn start(&self) -> Self::Item {
-        0
-    }
-
-    fn next(&self, path: &Path<Self::Item>) -> Vec<Self::Item> {
-        self.graph
-            .edges(path.tail())
-            .map(|edge| edge.destination)
-            .filter(|destination| !path.contains(destination))
-            .collect()
-    }
-
-    fn close(&self, next: &Self::Item) -> bool {
-        *next == 1
-    }
-}
-
-trait PositionPathFilter {
-    fn filter(&self, path: &Path<Position>, new: &Position) -> bool;
-}
-
-struct Part1<'a> {
-    puzzle: &'a Puzzle,
-}
-
-impl<'a> PositionPathFilter for Part1<'a> {
-    fn filter(&self, path: &Path<Position>, new: &Position) -> bool {
-        self.puzzle.part1_filter(path, new)
-    }
-}
-
-struct NodePaths<'a, F> {
-    node: Position,
-    filter: F,
-    puzzle: &'a Puzzle,
-    nodes: &'a [Position],
-}
-
-impl<F> PathGenerator for NodePaths<'_, F>
-where
-    F: Fn(&Puzzle, &Path<Position>, &Position) -> bool,
-{
-    type Item = Position;
-
-    fn start(&self) -> Self::Item {
-        self.node
-    }
-
-    fn next(&self, path: &Path<Self::Item>) -> Vec<Self::Item> {
-        Ice::all()
-            .map(|ice| ice.delta())
-            .map(|delta| path.tail() + delta)
+    fn downhill(&self, position: &Vec2<i64>) -> Vec<Vec2<i64>> {
+        Dir4::ALL
             .into_iter()
-            .filter(|new| (self.filter)(self.puzzle, &path, new))
+            .filter(|dir| self.ice.get(position).is_none_or(|slope| slope == dir))
+            .map(|dir| (*position + dir.delta(), dir))
+            .filter(|(next, dir)| {
+                self.valid(next) && self.ice.get(next).is_none_or(|slope| slope == dir)
+            })
+            .map(|(next, _)| next)
             .collect()
     }
 
-    fn close(&self, next: &Self::Item) -> bool {
-        self.nodes.contains(next)
+    fn valid(&self, position: &Vec2<i64>) -> bool {
+        self.open.contains(position) || self.ice.contains_key(position)
     }
 }
 
 impl FromStr for Puzzle {
-    type Err = &'static str;
+    type Err = aoc::ParseError;
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
         let mut open = HashSet::new();
         let mut ice = HashMap::new();
         for (row, line) in s.lines().enumerate() {
             for (column, char) in line.chars().enumerate() {
-                if let Some(new) = Ice::from_char(char) {
-                    ice.insert(
-                        Position::new(
-                            row.try_into().map_err(|_| "row overflow")?,
-                            column.try_into().map_err(|_| "column overflow")?,
-                        ),
-                        new,
-                    );
-                } else if char == '.' {
-                    open.insert(Position::new(
-                        row.try_into().map_err(|_| "row overflow")?,
-                        column.try_into().map_err(|_| "column overflow")?,
-                    ));
+                let position = Vec2::new(
+                    column.try_into().map_err(|_| "column overflow")?,
+                    row.try_into().map_err(|_| "row overflow")?,
+                );
+                match char {
+                    '#' => {}
+                    '.' => {
+                        open.insert(position);
+                    }
+                    _ => {
+                        ice.insert(position, Dir4::try_from(char)?);
+                    }
                 }
             }
         }
         let start = open
             .iter()
-            .min_by_key(|key| key.row())
-            .ok_or("start not found")?
-            .clone();
+            .min_by_key(|key| key.y)
+            .copied()
+            .ok_or_else(|| aoc::ParseError::missing("start"))?;
         let end = open
             .iter()
-            .max_by_key(|key| key.row())
-            .ok_or("start not found")?
-            .clone();
+            .max_by_key(|key| key.y)
+            .copied()
+            .ok_or_else(|| aoc::ParseError::missing("end"))?;
         Ok(Puzzle {
             open,
             ice,
@@ -264,26 +111,26 @@ mod tests {
         let s = "#.##\n#..#\n#..#\n##.#";
         let puzzle: Puzzle = s.parse().unwrap();
         assert_eq!(puzzle.open.len(), 6);
-        assert!(puzzle.open.contains(&Position::new(0, 1)));
-        assert!(puzzle.open.contains(&Position::new(1, 1)));
-        assert!(puzzle.open.contains(&Position::new(1, 2)));
-        assert!(puzzle.open.contains(&Position::new(2, 1)));
-        assert!(puzzle.open.contains(&Position::new(2, 2)));
-        assert!(puzzle.open.contains(&Position::new(3, 2)));
+        assert!(puzzle.open.contains(&Vec2::new(1, 0)));
+        assert!(puzzle.open.contains(&Vec2::new(1, 1)));
+        assert!(puzzle.open.contains(&Vec2::new(2, 1)));
+        assert!(puzzle.open.contains(&Vec2::new(1, 2)));
+        assert!(puzzle.open.contains(&Vec2::new(2, 2)));
+        assert!(puzzle.open.contains(&Vec2::new(2, 3)));
     }
 
     #[test]
     fn parse_start() {
         let s = "#.##\n#..#\n#..#\n##.#";
         let puzzle: Puzzle = s.parse().unwrap();
-        assert_eq!(puzzle.start, Position::new(0, 1));
+        assert_eq!(puzzle.start, Vec2::new(1, 0));
     }
 
     #[test]
     fn parse_end() {
         let s = "#.##\n#..#\n#..#\n##.#";
         let puzzle: Puzzle = s.parse().unwrap();
-        assert_eq!(puzzle.end, Position::new(3, 2));
+        assert_eq!(puzzle.end, Vec2::new(2, 3));
     }
 
     #[test]
@@ -291,28 +138,36 @@ mod tests {
         let s = "#.##\n#><#\n#v^#\n##.#";
         let puzzle: Puzzle = s.parse().unwrap();
         assert_eq!(puzzle.ice.len(), 4);
-        assert_eq!(puzzle.ice.get(&Position::new(1, 1)).unwrap(), &Ice::East);
-        assert_eq!(puzzle.ice.get(&Position::new(1, 2)).unwrap(), &Ice::West);
-        assert_eq!(puzzle.ice.get(&Position::new(2, 1)).unwrap(), &Ice::South);
-        assert_eq!(puzzle.ice.get(&Position::new(2, 2)).unwrap(), &Ice::North);
+        assert_eq!(puzzle.ice.get(&Vec2::new(1, 1)).unwrap(), &Dir4::East);
+        assert_eq!(puzzle.ice.get(&Vec2::new(2, 1)).unwrap(), &Dir4::West);
+        assert_eq!(puzzle.ice.get(&Vec2::new(1, 2)).unwrap(), &Dir4::South);
+        assert_eq!(puzzle.ice.get(&Vec2::new(2, 2)).unwrap(), &Dir4::North);
     }
 
     #[test]
     fn single_solution_part1() {
         let s = "#.##\n#..#\n##.#\n##.#";
         let puzzle: Puzzle = s.parse().unwrap();
-        assert_eq!(puzzle.part1(), 4);
+        assert_eq!(puzzle.part1(), Ok(4));
     }
 
     #[test]
     fn example_part1() {
         let puzzle: Puzzle = EXAMPLE.parse().unwrap();
-        assert_eq!(puzzle.part1(), 94);
+        assert_eq!(puzzle.part1(), Ok(94));
     }
 
     #[test]
     fn example_part2() {
         let puzzle: Puzzle = EXAMPLE.parse().unwrap();
-        assert_eq!(puzzle.part2(), 154);
+        assert_eq!(puzzle.part2(), Ok(154));
+    }
+
+    #[test]
+    fn blocked_by_slope() {
+        let s = "#.#\n#^#\n#.#";
+        let puzzle: Puzzle = s.parse().unwrap();
+        assert_eq!(puzzle.part1(), Err("no path from start to end"));
+        assert_eq!(puzzle.part2(), Ok(2));
     }
 }
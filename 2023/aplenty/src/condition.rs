@@ -1,4 +1,5 @@
 use crate::{part::Part, part_combination::PartCombination};
+use aoc::{interval::Interval, ParseError};
 
 pub struct Condition {
     operator: Operator,
@@ -26,46 +27,33 @@ impl Condition {
         &self,
         combination: PartCombination,
     ) -> (Option<PartCombination>, Option<PartCombination>) {
+        let (below, above) = match self.operator {
+            Operator::Less => self.range(&combination).split_at(self.constant),
+            Operator::Greater => self.range(&combination).split_at(self.constant + 1),
+        };
+        let with_range = |range: Option<Interval<u64>>| {
+            range.map(|range| {
+                let mut combination = combination.clone();
+                *self.range_mut(&mut combination) = range;
+                combination
+            })
+        };
         match self.operator {
-            Operator::Less => {
-                let true_combination = self.split_with_tranform(combination.clone(), |range| {
-                    range.1 = self.constant - 1;
-                });
-                let false_combination = self.split_with_tranform(combination, |range| {
-                    range.0 = self.constant;
-                });
-                (true_combination, false_combination)
-            }
-            Operator::Greater => {
-                let true_combination = self.split_with_tranform(combination.clone(), |range| {
-                    range.0 = self.constant + 1;
-                });
-                let false_combination = self.split_with_tranform(combination, |range| {
-                    range.1 = self.constant;
-                });
-                (true_combination, false_combination)
-            }
+            Operator::Less => (with_range(below), with_range(above)),
+            Operator::Greater => (with_range(above), with_range(below)),
         }
     }
 
-    fn split_with_tranform<F>(
-        &self,
-        mut combination: PartCombination,
-        mutator: F,
-    ) -> Option<PartCombination>
-    where
-        F: FnOnce(&mut (u64, u64)) -> (),
-    {
-        let mut range = self.range(&mut combination);
-        mutator(&mut range);
-        if range.0 <= range.1 {
-            Some(combination)
-        } else {
-            None
+    fn range(&self, combination: &PartCombination) -> Interval<u64> {
+        match self.variable {
+            Variable::X => combination.x,
+            Variable::M => combination.m,
+            Variable::A => combination.a,
+            Variable::S => combination.s,
         }
     }
 
-    fn range<'a>(&self, combination: &'a mut PartCombination) -> &'a mut (u64, u64) {
+    fn range_mut<'a>(&self, combination: &'a mut PartCombination) -> &'a mut Interval<u64> {
         match self.variable {
             Variable::X => &mut combination.x,
             Variable::M => &mut combination.m,
@@ -76,7 +64,7 @@ impl Condition {
 }
 
 impl std::str::FromStr for Condition {
-    type Err = &'static str;
+    type Err = ParseError;
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
         let condition = s
@@ -86,20 +74,24 @@ impl std::str::FromStr for Condition {
                 s.split_once('>')
                     .map(|(left, right)| parse_with_op(Operator::Greater, left, right))
             })
-            .ok_or("invalid operator")??;
+            .ok_or_else(|| ParseError::missing("operator").span(s).context("condition"))??;
         Ok(condition)
     }
 }
 
-fn parse_with_op(operator: Operator, left: &str, right: &str) -> Result<Condition, &'static str> {
+fn parse_with_op(operator: Operator, left: &str, right: &str) -> Result<Condition, ParseError> {
     let variable = match left {
         "x" => Ok(Variable::X),
         "m" => Ok(Variable::M),
         "a" => Ok(Variable::A),
         "s" => Ok(Variable::S),
-        _ => Err("invalid variable in condition"),
+        _ => Err(ParseError::invalid("variable", left).context("condition")),
     }?;
-    let constant = right.parse().map_err(|_| "invalid constant in condition")?;
+    let constant = right.parse().map_err(|err| {
+        ParseError::invalid("constant", right)
+            .context("condition")
+            .with_cause(err)
+    })?;
     Ok(Condition {
         operator,
         variable,
@@ -144,6 +136,16 @@ mod tests {
         assert!(s.parse::<Condition>().is_err())
     }
 
+    #[test]
+    fn report_invalid_constant() {
+        let s = "a<bbb";
+        let err = s.parse::<Condition>().err().unwrap().locate(s);
+        assert_eq!(
+            err.to_string(),
+            "line 1, col 3: invalid constant 'bbb' in condition"
+        );
+    }
+
     #[test]
     fn fail_on_invalid_left() {
         let s = "b<2131";
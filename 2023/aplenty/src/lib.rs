@@ -0,0 +1,32 @@
+use std::fmt::Display;
+
+use puzzle::Puzzle;
+
+mod condition;
+mod part;
+mod part_combination;
+mod puzzle;
+mod statement;
+mod value;
+mod workflow;
+
+pub const DAY: aoc::Day = aoc::day!(2023, 19, Solution);
+
+pub struct Solution;
+
+impl aoc::Solver for Solution {
+    type Input = Puzzle;
+    type Error = &'static str;
+
+    fn parse(input: &str) -> Result<Puzzle, aoc::ParseError> {
+        input.parse()
+    }
+
+    fn part1(puzzle: &Puzzle) -> Result<impl Display, &'static str> {
+        Ok(puzzle.part1())
+    }
+
+    fn part2(puzzle: &Puzzle) -> Result<impl Display, &'static str> {
+        Ok(puzzle.part2())
+    }
+}
@@ -1,3 +1,5 @@
+use aoc::{parse, ParseError};
+
 pub struct Part {
     pub x: u64,
     pub m: u64,
@@ -6,43 +8,39 @@ pub struct Part {
 }
 
 impl std::str::FromStr for Part {
-    type Err = &'static str;
+    type Err = ParseError;
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
-        let mut ratings = s
-            .strip_prefix('{')
-            .ok_or("missing prefix")?
-            .strip_suffix('}')
-            .ok_or("missing suffix")?
-            .split(',');
-        let x = ratings
-            .next()
-            .ok_or("missing x")?
-            .strip_prefix("x=")
-            .ok_or("missing x prefix")?
-            .parse()
-            .map_err(|_| "nan")?;
-        let m = ratings
-            .next()
-            .ok_or("missing m")?
-            .strip_prefix("m=")
-            .ok_or("missing m prefix")?
-            .parse()
-            .map_err(|_| "nan")?;
-        let a = ratings
-            .next()
-            .ok_or("missing a")?
-            .strip_prefix("a=")
-            .ok_or("missing a prefix")?
-            .parse()
-            .map_err(|_| "nan")?;
-        let s = ratings
-            .next()
-            .ok_or("missing s")?
-            .strip_prefix("s=")
-            .ok_or("missing s prefix")?
-            .parse()
-            .map_err(|_| "nan")?;
-        Ok(Part { x, m, a, s })
+        parse::complete(s, |input| {
+            parse::literal(input, "{")?;
+            let x = parse::key_value(input, "x", parse::unsigned)?;
+            parse::literal(input, ",")?;
+            let m = parse::key_value(input, "m", parse::unsigned)?;
+            parse::literal(input, ",")?;
+            let a = parse::key_value(input, "a", parse::unsigned)?;
+            parse::literal(input, ",")?;
+            let s = parse::key_value(input, "s", parse::unsigned)?;
+            parse::literal(input, "}")?;
+            Ok(Part { x, m, a, s })
+        })
+        .map_err(|err| err.context("part"))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Part;
+
+    #[test]
+    fn parse_part() {
+        let part: Part = "{x=787,m=2655,a=1222,s=2876}".parse().unwrap();
+        assert_eq!((part.x, part.m, part.a, part.s), (787, 2655, 1222, 2876));
+    }
+
+    #[test]
+    fn report_unexpected_rating() {
+        let s = "{x=787,n=2655,a=1222,s=2876}";
+        let err = s.parse::<Part>().err().unwrap().locate(s);
+        assert_eq!(err.to_string(), "line 1, col 8: expected 'm' in part");
     }
 }
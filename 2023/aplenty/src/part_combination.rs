@@ -1,18 +1,21 @@
+use aoc::interval::Interval;
+
 #[derive(Clone)]
 pub struct PartCombination {
-    pub x: (u64, u64),
-    pub m: (u64, u64),
-    pub a: (u64, u64),
-    pub s: (u64, u64),
+    pub x: Interval<u64>,
+    pub m: Interval<u64>,
+    pub a: Interval<u64>,
+    pub s: Interval<u64>,
 }
 
 impl Default for PartCombination {
     fn default() -> Self {
+        let ratings = Interval::inclusive(1, 4000);
         PartCombination {
-            x: (1, 4000),
-            m: (1, 4000),
-            a: (1, 4000),
-            s: (1, 4000),
+            x: ratings,
+            m: ratings,
+            a: ratings,
+            s: ratings,
         }
     }
 }
@@ -20,24 +23,22 @@ impl Default for PartCombination {
 impl PartCombination {
     pub fn combinations(&self) -> u64 {
         let Self { x, m, a, s } = self;
-        [x, m, a, s]
-            .map(|v| v.1 - v.0 + 1)
-            .into_iter()
-            .fold(1, |a, b| a * b)
+        [x, m, a, s].map(Interval::len).into_iter().product()
     }
 }
 
 #[cfg(test)]
 mod tests {
     use super::PartCombination;
+    use aoc::interval::Interval;
 
     #[test]
     fn single_combination() {
         let combinations = PartCombination {
-            x: (2, 2),
-            m: (3, 3),
-            a: (6, 6),
-            s: (4000, 4000),
+            x: Interval::inclusive(2, 2),
+            m: Interval::inclusive(3, 3),
+            a: Interval::inclusive(6, 6),
+            s: Interval::inclusive(4000, 4000),
         };
         assert_eq!(combinations.combinations(), 1);
     }
@@ -45,10 +46,10 @@ mod tests {
     #[test]
     fn single_rating_combinations() {
         let combinations = PartCombination {
-            x: (2, 2),
-            m: (3, 4),
-            a: (6, 6),
-            s: (4000, 4000),
+            x: Interval::inclusive(2, 2),
+            m: Interval::inclusive(3, 4),
+            a: Interval::inclusive(6, 6),
+            s: Interval::inclusive(4000, 4000),
         };
         assert_eq!(combinations.combinations(), 2);
     }
@@ -56,10 +57,10 @@ mod tests {
     #[test]
     fn multi_rating_combinations() {
         let combinations = PartCombination {
-            x: (2, 2),
-            m: (3, 4),
-            a: (6, 8),
-            s: (4000, 4000),
+            x: Interval::inclusive(2, 2),
+            m: Interval::inclusive(3, 4),
+            a: Interval::inclusive(6, 8),
+            s: Interval::inclusive(4000, 4000),
         };
         assert_eq!(combinations.combinations(), 6);
     }
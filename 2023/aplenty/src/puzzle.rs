@@ -1,4 +1,5 @@
 use crate::{part::Part, part_combination::PartCombination, value::Value, workflow::Workflow};
+use aoc::ParseError;
 
 pub struct Puzzle {
     workflows: Vec<Workflow>,
@@ -6,7 +7,7 @@ pub struct Puzzle {
 }
 
 impl Puzzle {
-    pub fn part1(self) -> u64 {
+    pub fn part1(&self) -> u64 {
         self.parts
             .iter()
             .filter(|part| self.eval(part))
@@ -26,7 +27,7 @@ impl Puzzle {
         }
     }
 
-    pub fn part2(self) -> u64 {
+    pub fn part2(&self) -> u64 {
         self.count_recursive(PartCombination::default(), "in")
     }
 
@@ -51,7 +52,7 @@ impl Puzzle {
 }
 
 impl std::str::FromStr for Puzzle {
-    type Err = &'static str;
+    type Err = ParseError;
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
         let mut state = ParserState::Workflows;
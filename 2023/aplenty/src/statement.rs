@@ -1,4 +1,5 @@
 use crate::{condition::Condition, part::Part, part_combination::PartCombination, value::Value};
+use aoc::ParseError;
 
 pub struct Statement {
     condition: Option<Condition>,
@@ -34,7 +35,7 @@ impl Statement {
 }
 
 impl std::str::FromStr for Statement {
-    type Err = &'static str;
+    type Err = ParseError;
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
         let statement = match s.split_once(":") {
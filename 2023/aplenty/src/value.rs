@@ -6,7 +6,7 @@ pub enum Value {
 }
 
 impl std::str::FromStr for Value {
-    type Err = &'static str;
+    type Err = aoc::ParseError;
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
         Ok(match s {
@@ -1,4 +1,5 @@
 use crate::{part::Part, part_combination::PartCombination, statement::Statement, value::Value};
+use aoc::ParseError;
 
 pub struct Workflow {
     pub name: String,
@@ -31,11 +32,19 @@ impl Workflow {
 }
 
 impl std::str::FromStr for Workflow {
-    type Err = &'static str;
+    type Err = ParseError;
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
-        let s = s.strip_suffix('}').ok_or("missing closing bracket")?;
-        let (name, definition) = s.split_once('{').ok_or("missing opening bracket")?;
+        let s = s.strip_suffix('}').ok_or_else(|| {
+            ParseError::missing("closing bracket")
+                .span(s)
+                .context("workflow")
+        })?;
+        let (name, definition) = s.split_once('{').ok_or_else(|| {
+            ParseError::missing("opening bracket")
+                .span(s)
+                .context("workflow")
+        })?;
         let name = name.to_string();
         let mut statements = Vec::new();
         for statement_definition in definition.split(",") {
@@ -1,6 +1,8 @@
 use crate::{hand::Hand, play::Play};
+use aoc::ParseError;
 use std::str::FromStr;
 
+#[derive(Clone)]
 pub struct Game {
     plays: Vec<Play<Hand>>,
 }
@@ -21,7 +23,7 @@ impl Game {
 }
 
 impl FromStr for Game {
-    type Err = &'static str;
+    type Err = ParseError;
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
         let plays = s
@@ -1,3 +1,4 @@
+use aoc::ParseError;
 use std::str::FromStr;
 
 const CARD_COUNT: usize = 13;
@@ -144,7 +145,7 @@ fn joker_less_hand_type(counters: &[u8]) -> HandType {
     }
 }
 
-#[derive(Debug, PartialEq, Eq)]
+#[derive(Clone, Debug, PartialEq, Eq)]
 pub struct Hand {
     cards: [char; 5],
 }
@@ -177,30 +178,32 @@ impl Hand {
 }
 
 impl FromStr for Hand {
-    type Err = &'static str;
+    type Err = ParseError;
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
-        let mut it = s.chars();
+        let mut it = s.char_indices();
         let cards = [
-            parse_card(&mut it)?,
-            parse_card(&mut it)?,
-            parse_card(&mut it)?,
-            parse_card(&mut it)?,
-            parse_card(&mut it)?,
+            parse_card(s, &mut it)?,
+            parse_card(s, &mut it)?,
+            parse_card(s, &mut it)?,
+            parse_card(s, &mut it)?,
+            parse_card(s, &mut it)?,
         ];
         Ok(Hand { cards })
     }
 }
 
-fn parse_card<I>(it: &mut I) -> Result<char, &'static str>
+fn parse_card<I>(s: &str, it: &mut I) -> Result<char, ParseError>
 where
-    I: Iterator<Item = char>,
+    I: Iterator<Item = (usize, char)>,
 {
-    let c = it.next().ok_or("missing card in hand")?;
+    let (idx, c) = it
+        .next()
+        .ok_or_else(|| ParseError::missing("card").span(s).context("hand"))?;
     if CARDS.contains(&c) {
         Ok(c)
     } else {
-        Err("invalid char for card")
+        Err(ParseError::invalid("card", &s[idx..idx + c.len_utf8()]).context("hand"))
     }
 }
 
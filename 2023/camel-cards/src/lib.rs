@@ -0,0 +1,28 @@
+use game::Game;
+use hand::{NewHand, OldHand};
+use std::fmt::Display;
+
+mod game;
+mod hand;
+mod play;
+
+pub const DAY: aoc::Day = aoc::day!(2023, 7, Solution);
+
+pub struct Solution;
+
+impl aoc::Solver for Solution {
+    type Input = Game;
+    type Error = &'static str;
+
+    fn parse(input: &str) -> Result<Game, aoc::ParseError> {
+        input.parse()
+    }
+
+    fn part1(game: &Game) -> Result<impl Display, &'static str> {
+        Ok(game.clone().winings(OldHand::new))
+    }
+
+    fn part2(game: &Game) -> Result<impl Display, &'static str> {
+        Ok(game.clone().winings(NewHand::new))
+    }
+}
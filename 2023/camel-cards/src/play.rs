@@ -1,7 +1,9 @@
+use aoc::ParseError;
 use std::str::FromStr;
 
 use crate::hand::Hand;
 
+#[derive(Clone)]
 pub struct Play<T> {
     pub hand: T,
     pub bid: u64,
@@ -16,16 +18,20 @@ impl<T> Play<T> {
 }
 
 impl FromStr for Play<Hand> {
-    type Err = &'static str;
+    type Err = ParseError;
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
         let mut it = s.split_whitespace();
-        let hand = it.next().ok_or("missing hand")?.parse()?;
+        let hand = it
+            .next()
+            .ok_or_else(|| ParseError::missing("hand").span(s))?
+            .parse()?;
         let bid = it
             .next()
-            .ok_or("missing bid")?
+            .ok_or_else(|| ParseError::missing("bid").span(s))?;
+        let bid = bid
             .parse()
-            .map_err(|_| "invalid bid")?;
+            .map_err(|err| ParseError::invalid("bid", bid).with_cause(err))?;
         Ok(Play { hand, bid })
     }
 }
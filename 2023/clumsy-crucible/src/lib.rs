@@ -0,0 +1,25 @@
+use map::{LongPath, Map, ShortPath};
+use std::fmt::Display;
+
+mod map;
+
+pub const DAY: aoc::Day = aoc::day!(2023, 17, Solution);
+
+pub struct Solution;
+
+impl aoc::Solver for Solution {
+    type Input = Map;
+    type Error = &'static str;
+
+    fn parse(input: &str) -> Result<Map, aoc::ParseError> {
+        input.parse()
+    }
+
+    fn part1(map: &Map) -> Result<impl Display, &'static str> {
+        map.heat_loss::<ShortPath>().ok_or("no path")
+    }
+
+    fn part2(map: &Map) -> Result<impl Display, &'static str> {
+        map.heat_loss::<LongPath>().ok_or("no path")
+    }
+}
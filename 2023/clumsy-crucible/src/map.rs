@@ -1,5 +1,5 @@
-use crate::dijkstra::{dijkstra, State};
-use std::str::FromStr;
+use aoc::ParseError;
+use std::{hash::Hash, str::FromStr};
 
 pub struct Map {
     blocks: Vec<usize>,
@@ -8,11 +8,12 @@ pub struct Map {
 
 impl Map {
     pub fn heat_loss<P: Path>(&self) -> Option<usize> {
-        dijkstra(
+        aoc::search::dijkstra(
+            [P::default()],
             |path| path.next(self),
-            P::default(),
             |path| path.last() == self.blocks.len() - 1,
         )
+        .cost()
     }
 
     fn rows(&self) -> usize {
@@ -53,15 +54,17 @@ impl Map {
 }
 
 impl FromStr for Map {
-    type Err = &'static str;
+    type Err = ParseError;
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
         let mut blocks = Vec::new();
         let mut columns = 0;
         for line in s.lines() {
             columns = line.len();
-            for c in line.chars() {
-                let block = c.to_digit(10).ok_or("invalid digit character")? as usize;
+            for (idx, c) in line.char_indices() {
+                let block = c.to_digit(10).ok_or_else(|| {
+                    ParseError::invalid("heat loss", &line[idx..idx + c.len_utf8()])
+                })? as usize;
                 blocks.push(block);
             }
         }
@@ -69,18 +72,18 @@ impl FromStr for Map {
     }
 }
 
-pub trait Path: Clone + PartialEq + Eq + PartialOrd + Ord + Default {
-    fn next(&self, map: &Map) -> Vec<State<Self>>;
+pub trait Path: Clone + Eq + Hash + Default {
+    fn next(&self, map: &Map) -> Vec<(Self, usize)>;
     fn last(&self) -> usize;
 }
 
-#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
 pub struct ShortPath {
     path: [Option<usize>; 4],
 }
 
 impl ShortPath {
-    fn build_next(&self, map: &Map, direction: Direction) -> Option<State<Self>> {
+    fn build_next(&self, map: &Map, direction: Direction) -> Option<(Self, usize)> {
         let (last_row, last_column) = map.to_coordinates(self.last());
         if self.is_same_than_last(map, direction) || self.over_max_consecutives(map, direction) {
             return None;
@@ -93,7 +96,7 @@ impl ShortPath {
         let path = [self.path[1], self.path[2], self.path[3], Some(index)];
         let node = ShortPath { path };
         let cost = map.get(row, column);
-        Some(State { cost, node })
+        Some((node, cost))
     }
 
     fn is_same_than_last(&self, map: &Map, direction: Direction) -> bool {
@@ -115,7 +118,7 @@ impl Default for ShortPath {
 }
 
 impl Path for ShortPath {
-    fn next(&self, map: &Map) -> Vec<State<Self>> {
+    fn next(&self, map: &Map) -> Vec<(Self, usize)> {
         Direction::all()
             .into_iter()
             .filter_map(|direction| self.build_next(map, direction))
@@ -127,13 +130,13 @@ impl Path for ShortPath {
     }
 }
 
-#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
 pub struct LongPath {
     path: [Option<usize>; 11],
 }
 
 impl LongPath {
-    fn get_advance_path(&self, map: &Map, direction: Direction) -> Option<State<Self>> {
+    fn get_advance_path(&self, map: &Map, direction: Direction) -> Option<(Self, usize)> {
         let mut new: [Option<usize>; 4] = [None; 4];
         let mut cost = 0;
         for index in 0..4 {
@@ -151,7 +154,7 @@ impl LongPath {
         path[..(11 - 4)].clone_from_slice(&self.path[11 - (11 - 4)..]);
         path[(11 - 4)..].clone_from_slice(&new);
         let node = LongPath { path };
-        Some(State { cost, node })
+        Some((node, cost))
     }
 
     fn last_direction(&self, map: &Map) -> Option<Direction> {
@@ -160,7 +163,7 @@ impl LongPath {
         Some(Direction::from_delta(first, second))
     }
 
-    fn continue_direction(&self, map: &Map, direction: Direction) -> Option<State<LongPath>> {
+    fn continue_direction(&self, map: &Map, direction: Direction) -> Option<(LongPath, usize)> {
         if self
             .path
             .windows(2)
@@ -179,7 +182,7 @@ impl LongPath {
             path[10] = Some(map.to_index(row, column));
             let node = LongPath { path };
             let cost = map.get(row, column);
-            Some(State { cost, node })
+            Some((node, cost))
         }
     }
 }
@@ -204,7 +207,7 @@ impl Default for LongPath {
 }
 
 impl Path for LongPath {
-    fn next(&self, map: &Map) -> Vec<State<Self>> {
+    fn next(&self, map: &Map) -> Vec<(Self, usize)> {
         if let Some(last_direction) = self.last_direction(map) {
             let mut paths = Vec::with_capacity(3);
             if let Some(path) = self.continue_direction(map, last_direction) {
@@ -0,0 +1,25 @@
+use std::fmt::Display;
+use universe::Universe;
+
+mod universe;
+
+pub const DAY: aoc::Day = aoc::day!(2023, 11, Solution);
+
+pub struct Solution;
+
+impl aoc::Solver for Solution {
+    type Input = Universe;
+    type Error = &'static str;
+
+    fn parse(input: &str) -> Result<Universe, aoc::ParseError> {
+        input.parse()
+    }
+
+    fn part1(universe: &Universe) -> Result<impl Display, &'static str> {
+        Ok(universe.clone().shortest_path_sum(2))
+    }
+
+    fn part2(universe: &Universe) -> Result<impl Display, &'static str> {
+        Ok(universe.clone().shortest_path_sum(1_000_000))
+    }
+}
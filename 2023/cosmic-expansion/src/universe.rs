@@ -1,5 +1,6 @@
 use std::str::FromStr;
 
+#[derive(Clone)]
 pub struct Universe {
     galaxies: Vec<(i64, i64)>,
 }
@@ -49,7 +50,7 @@ impl Universe {
 }
 
 impl FromStr for Universe {
-    type Err = &'static str;
+    type Err = aoc::ParseError;
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
         let mut galaxies = Vec::new();
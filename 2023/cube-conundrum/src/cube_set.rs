@@ -1,3 +1,4 @@
+use aoc::ParseError;
 use std::{iter::Sum, ops::Add, str::FromStr};
 
 #[derive(Default)]
@@ -12,7 +13,7 @@ impl CubeSet {
         CubeSet {
             red: self.red.max(other.red),
             green: self.green.max(other.green),
-            blue: self.blue.max(other.blue)
+            blue: self.blue.max(other.blue),
         }
     }
 }
@@ -62,20 +63,24 @@ impl Sum for CubeSet {
 }
 
 impl FromStr for CubeSet {
-    type Err = &'static str;
+    type Err = ParseError;
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
-        Ok(s.split(", ")
-            .map(|s| parse_color(s))
-            .map(CubeSet::from)
-            .sum::<CubeSet>())
+        s.split(", ").map(|s| parse_color(s)).sum()
     }
 }
 
-fn parse_color<'a>(s: &'a str) -> (u32, &'a str) {
-    let mut it = s.split(" ");
-    let count = it.next().unwrap().parse().unwrap();
-    (count, it.next().unwrap())
+fn parse_color(s: &str) -> Result<CubeSet, ParseError> {
+    let (count, color) = s
+        .split_once(" ")
+        .ok_or_else(|| ParseError::missing("color").span(s))?;
+    let count = count
+        .parse()
+        .map_err(|err| ParseError::invalid("count", count).with_cause(err))?;
+    match color {
+        "red" | "green" | "blue" => Ok(CubeSet::from((count, color))),
+        _ => Err(ParseError::invalid("color", color)),
+    }
 }
 
 #[cfg(test)]
@@ -1,3 +1,4 @@
+use aoc::ParseError;
 use std::str::FromStr;
 
 use crate::cube_set::CubeSet;
@@ -20,15 +21,18 @@ impl Game {
 }
 
 impl FromStr for Game {
-    type Err = &'static str;
+    type Err = ParseError;
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
-        let (game_s, grabs_s) = s.split_once(": ").ok_or("missing description separator")?;
-        let index = game_s
+        let (game_s, grabs_s) = s
+            .split_once(": ")
+            .ok_or_else(|| ParseError::missing("description separator").span(s))?;
+        let index_s = game_s
             .strip_prefix("Game ")
-            .ok_or("missing game prefix")?
+            .ok_or_else(|| ParseError::missing("game prefix").span(game_s))?;
+        let index = index_s
             .parse()
-            .map_err(|_| "invalid game index")?;
+            .map_err(|err| ParseError::invalid("game index", index_s).with_cause(err))?;
         let grab = grabs_s
             .split("; ")
             .map(|grab| grab.parse())
@@ -0,0 +1,66 @@
+use std::fmt::Display;
+
+use cube_set::CubeSet;
+use game::Game;
+
+mod cube_set;
+mod game;
+
+pub const DAY: aoc::Day = aoc::day!(2023, 2, Solution);
+
+const MAX: CubeSet = CubeSet {
+    red: 12,
+    green: 13,
+    blue: 14,
+};
+
+pub struct Solution;
+
+impl aoc::Solver for Solution {
+    type Input = Vec<Game>;
+    type Error = &'static str;
+
+    fn parse(input: &str) -> Result<Vec<Game>, aoc::ParseError> {
+        input.lines().map(|line| line.parse()).collect()
+    }
+
+    fn part1(games: &Vec<Game>) -> Result<impl Display, &'static str> {
+        Ok(sum_all(games, |game| {
+            if game.is_possible(&MAX) {
+                game.id
+            } else {
+                0
+            }
+        }))
+    }
+
+    fn part2(games: &Vec<Game>) -> Result<impl Display, &'static str> {
+        Ok(sum_all(games, |game| game.power()))
+    }
+}
+
+fn sum_all(games: &[Game], mapper: fn(&Game) -> u32) -> u32 {
+    games.iter().map(mapper).sum()
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::Solution;
+    use aoc::Part;
+
+    #[test]
+    fn part1_example() {
+        assert_eq!(
+            aoc::solve::<Solution>(include_str!("../assets/example.txt"), Part::One).unwrap(),
+            "8"
+        )
+    }
+
+    #[test]
+    fn part2_example() {
+        assert_eq!(
+            aoc::solve::<Solution>(include_str!("../assets/example.txt"), Part::Two).unwrap(),
+            "2286"
+        )
+    }
+}
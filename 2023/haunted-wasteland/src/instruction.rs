@@ -4,13 +4,15 @@ pub enum Instruction {
 }
 
 impl TryFrom<char> for Instruction {
-    type Error = &'static str;
+    type Error = aoc::ParseError;
 
     fn try_from(value: char) -> Result<Self, Self::Error> {
         match value {
             'R' => Ok(Instruction::Right),
             'L' => Ok(Instruction::Left),
-            _ => Err("invalid instruction"),
+            _ => Err(aoc::ParseError::new(format!(
+                "invalid instruction '{value}'"
+            ))),
         }
     }
 }
@@ -0,0 +1,27 @@
+use map::Map;
+use std::fmt::Display;
+
+mod instruction;
+mod line;
+mod map;
+
+pub const DAY: aoc::Day = aoc::day!(2023, 8, Solution);
+
+pub struct Solution;
+
+impl aoc::Solver for Solution {
+    type Input = Map;
+    type Error = &'static str;
+
+    fn parse(input: &str) -> Result<Map, aoc::ParseError> {
+        input.parse()
+    }
+
+    fn part1(map: &Map) -> Result<impl Display, &'static str> {
+        Ok(map.steps())
+    }
+
+    fn part2(map: &Map) -> Result<impl Display, &'static str> {
+        map.steps_all()
+    }
+}
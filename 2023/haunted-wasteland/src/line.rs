@@ -1,3 +1,4 @@
+use aoc::ParseError;
 use std::str::FromStr;
 
 pub struct Line {
@@ -7,17 +8,19 @@ pub struct Line {
 }
 
 impl FromStr for Line {
-    type Err = &'static str;
+    type Err = ParseError;
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
-        let (source, expr) = s.split_once(" = ").ok_or("no expression split")?;
+        let (source, expr) = s
+            .split_once(" = ")
+            .ok_or_else(|| ParseError::missing("expression separator").span(s))?;
         let (left, right) = expr
             .strip_prefix("(")
-            .ok_or("missing open parenthesis")?
+            .ok_or_else(|| ParseError::missing("open parenthesis").span(expr))?
             .strip_suffix(")")
-            .ok_or("missing close parenthesis")?
+            .ok_or_else(|| ParseError::missing("close parenthesis").span(expr))?
             .split_once(", ")
-            .ok_or("missing left and right delimiter")?;
+            .ok_or_else(|| ParseError::missing("left and right delimiter").span(expr))?;
         let source = source.to_string();
         let left = left.to_string();
         let right = right.to_string();
@@ -1,6 +1,6 @@
 use crate::{instruction::Instruction, line::Line};
-use aoc::lcm::Lcm;
-use std::str::FromStr;
+use aoc::ParseError;
+use std::{iter, str::FromStr};
 
 pub struct Map {
     instructions: Vec<Instruction>,
@@ -12,57 +12,88 @@ impl Map {
         self.steps_end_condition("AAA", |position| position == "ZZZ")
     }
 
-    pub fn steps_all(&self) -> usize {
-        self.lines
+    pub fn steps_all(&self) -> Result<usize, &'static str> {
+        let periods = self
+            .lines
             .iter()
-            .map(|line| &line.source)
-            .filter(|source| source.chars().last().unwrap() == 'A')
-            .map(|source| {
-                self.steps_end_condition(source, |postition| {
-                    postition.chars().last().unwrap() == 'Z'
-                })
-            })
-            .reduce(|a, b| a.lcm(b).unwrap())
-            .unwrap()
+            .map(|line| line.source.as_str())
+            .filter(|source| source.ends_with('A'))
+            .map(|source| self.ghost_period(source))
+            .collect::<Result<Vec<_>, _>>()?;
+        aoc::math::lcm_all(periods).ok_or("step count overflows")
+    }
+
+    fn ghost_period(&self, start: &str) -> Result<usize, &'static str> {
+        if self.instructions.is_empty() {
+            return Err("no instructions");
+        }
+        let states = iter::successors(Some((start, 0)), |&(position, idx)| {
+            let next = self.step(position, &self.instructions[idx]);
+            Some((next, (idx + 1) % self.instructions.len()))
+        });
+        let mut ends = Vec::new();
+        let cycle = aoc::cycle::detect(states.inspect(|(position, _)| {
+            ends.push(position.ends_with('Z'));
+        }))
+        .ok_or("ghost never repeats")?;
+        let hits = (0..cycle.start + cycle.length)
+            .filter(|&idx| ends[idx])
+            .collect::<Vec<_>>();
+        let period = *hits.first().ok_or("ghost never reaches an end node")?;
+        let expected = (period..cycle.start + cycle.length).step_by(period);
+        if period < cycle.start || cycle.length % period != 0 || !hits.iter().copied().eq(expected)
+        {
+            return Err("ghost does not reach end nodes periodically");
+        }
+        Ok(period)
     }
 
     fn steps_end_condition(&self, start: &str, condition: fn(&str) -> bool) -> usize {
         let mut position = start;
-        std::iter::repeat(self.instructions.iter())
-            .flat_map(|iter| iter)
+        self.instructions
+            .iter()
+            .cycle()
             .take_while(|instr| {
                 if condition(position) {
                     false
                 } else {
-                    let line = self
-                        .lines
-                        .iter()
-                        .find(|line| &line.source == position)
-                        .unwrap();
-                    position = match instr {
-                        Instruction::Left => &line.left,
-                        Instruction::Right => &line.right,
-                    };
+                    position = self.step(position, instr);
                     true
                 }
             })
             .count()
     }
+
+    fn step(&self, position: &str, instr: &Instruction) -> &str {
+        let line = self
+            .lines
+            .iter()
+            .find(|line| line.source == position)
+            .unwrap();
+        match instr {
+            Instruction::Left => &line.left,
+            Instruction::Right => &line.right,
+        }
+    }
 }
 
 impl FromStr for Map {
-    type Err = &'static str;
+    type Err = ParseError;
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
         let mut it = s.lines();
-        let instructions = it
+        let line = it
             .next()
-            .ok_or("instructions lines missing")?
-            .chars()
-            .map(|c| Instruction::try_from(c))
+            .ok_or_else(|| ParseError::missing("instructions line"))?;
+        let instructions = line
+            .char_indices()
+            .map(|(idx, c)| {
+                Instruction::try_from(c).map_err(|err| err.span(&line[idx..idx + c.len_utf8()]))
+            })
             .collect::<Result<_, _>>()?;
-        if !it.next().ok_or("missing empty line")?.is_empty() {
-            return Err("missing required white line");
+        let separator = it.next().ok_or_else(|| ParseError::missing("empty line"))?;
+        if !separator.is_empty() {
+            return Err(ParseError::new("expected empty line").span(separator));
         }
         let lines = it
             .map(|line| line.parse::<Line>())
@@ -96,6 +127,6 @@ mod tests {
     fn example3() {
         let string = include_str!("../assets/example3.txt");
         let map: Map = string.parse().unwrap();
-        assert_eq!(map.steps_all(), 6);
+        assert_eq!(map.steps_all(), Ok(6));
     }
 }
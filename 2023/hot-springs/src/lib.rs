@@ -0,0 +1,28 @@
+use springs::Springs;
+use std::fmt::Display;
+
+mod counter_iter;
+mod spring_condition;
+mod spring_row;
+mod springs;
+
+pub const DAY: aoc::Day = aoc::day!(2023, 12, Solution);
+
+pub struct Solution;
+
+impl aoc::Solver for Solution {
+    type Input = Springs;
+    type Error = &'static str;
+
+    fn parse(input: &str) -> Result<Springs, aoc::ParseError> {
+        input.parse()
+    }
+
+    fn part1(springs: &Springs) -> Result<impl Display, &'static str> {
+        Ok(springs.count_sum())
+    }
+
+    fn part2(springs: &Springs) -> Result<impl Display, &'static str> {
+        Ok(springs.clone().expanded().count_sum())
+    }
+}
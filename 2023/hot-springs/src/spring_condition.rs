@@ -5,12 +5,12 @@ pub enum SpringCondition {
 }
 
 impl SpringCondition {
-    pub fn new(value: char) -> Result<Option<SpringCondition>, &'static str> {
+    pub fn new(value: char) -> Result<Option<SpringCondition>, aoc::ParseError> {
         match value {
             '.' => Ok(Some(SpringCondition::Operational)),
             '#' => Ok(Some(SpringCondition::Damaged)),
             '?' => Ok(None),
-            _ => Err("invalid condition char"),
+            _ => Err(aoc::ParseError::new(format!("invalid condition '{value}'"))),
         }
     }
 }
@@ -1,5 +1,6 @@
 use crate::{counter_iter::IteratorExt, spring_condition::SpringCondition};
-use std::{collections::HashMap, str::FromStr};
+use aoc::{memo::Memo, ParseError};
+use std::str::FromStr;
 
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct SpringRow {
@@ -10,7 +11,7 @@ pub struct SpringRow {
 impl SpringRow {
     pub fn combinations(&self) -> usize {
         let mut copy = self.clone();
-        let mut cache = Cache::default();
+        let mut cache = vec![Memo::new(); self.validation.len() + 1];
         let mut row_ref = SpringRowRef {
             reference: &mut copy,
             conditions_offset: 0,
@@ -47,20 +48,26 @@ impl SpringRow {
 }
 
 impl FromStr for SpringRow {
-    type Err = &'static str;
+    type Err = ParseError;
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
         let (conditions, validation) = s
             .split_once(' ')
-            .ok_or("failed to split conditions and validation")?;
+            .ok_or_else(|| ParseError::missing("validation separator").span(s))?;
         let conditions = conditions
-            .chars()
-            .into_iter()
-            .map(|char| SpringCondition::new(char))
+            .char_indices()
+            .map(|(idx, char)| {
+                SpringCondition::new(char)
+                    .map_err(|err| err.span(&conditions[idx..idx + char.len_utf8()]))
+            })
             .collect::<Result<_, _>>()?;
         let validation = validation
             .split(',')
-            .map(|value| value.parse::<usize>().map_err(|_| "invalid number"))
+            .map(|value| {
+                value
+                    .parse::<usize>()
+                    .map_err(|err| ParseError::invalid("number", value).with_cause(err))
+            })
             .collect::<Result<_, _>>()?;
         Ok(SpringRow {
             conditions,
@@ -96,11 +103,18 @@ impl<'a> SpringRowRef<'a> {
         let initial_condition_offset = self.conditions_offset;
         let initial_validation_offset = self.validation_offset;
         self.simplified();
-        let combinations = cache.cache_or_else_calc(self, |value, cache| match value.is_valid() {
-            Some(true) => 1,
-            Some(false) => 0,
-            None => value.sum_both_options(cache),
-        });
+        let combinations = match cache[self.validation_offset].get(self.conditions()) {
+            Some(&combinations) => combinations,
+            None => {
+                let combinations = match self.is_valid() {
+                    Some(true) => 1,
+                    Some(false) => 0,
+                    None => self.sum_both_options(cache),
+                };
+                cache[self.validation_offset].insert(self.conditions().to_vec(), combinations);
+                combinations
+            }
+        };
         self.conditions_offset = initial_condition_offset;
         self.validation_offset = initial_validation_offset;
         combinations
@@ -186,46 +200,11 @@ impl<'a> SpringRowRef<'a> {
         self.conditions_mut()[empty_idx] = None;
         first + second
     }
-
-    fn to_row(&self) -> SpringRow {
-        let conditions = self.conditions().iter().cloned().collect();
-        let validation = self.validations().iter().cloned().collect();
-        SpringRow {
-            conditions,
-            validation,
-        }
-    }
-}
-
-#[derive(Default)]
-struct Cache {
-    table: HashMap<Vec<Option<SpringCondition>>, HashMap<Vec<usize>, usize>>,
 }
 
-impl Cache {
-    fn cache_or_else_calc(
-        &mut self,
-        curr: &mut SpringRowRef,
-        calc: fn(&mut SpringRowRef, &mut Self) -> usize,
-    ) -> usize {
-        self.table
-            .get(curr.conditions())
-            .and_then(|condition| condition.get(curr.validations()))
-            .cloned()
-            .unwrap_or_else(|| {
-                let value = calc(curr, self);
-                let SpringRow {
-                    conditions,
-                    validation,
-                } = curr.to_row();
-                self.table
-                    .entry(conditions)
-                    .or_default()
-                    .insert(validation, value);
-                value
-            })
-    }
-}
+// The validations are never modified, so their offset stands in for the
+// slice; the conditions are not, since unknown springs are filled in place.
+type Cache = Vec<Memo<Vec<Option<SpringCondition>>, usize>>;
 
 #[cfg(test)]
 mod tests {
@@ -1,6 +1,7 @@
 use crate::spring_row::SpringRow;
 use std::str::FromStr;
 
+#[derive(Clone)]
 pub struct Springs {
     rows: Vec<SpringRow>,
 }
@@ -17,7 +18,7 @@ impl Springs {
 }
 
 impl FromStr for Springs {
-    type Err = &'static str;
+    type Err = aoc::ParseError;
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
         let rows = s
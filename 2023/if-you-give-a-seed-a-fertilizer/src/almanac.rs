@@ -1,9 +1,10 @@
+use aoc::{
+    interval::{Interval, IntervalSet},
+    ParseError,
+};
 use std::str::FromStr;
 
-use crate::{
-    mapping::Mapping,
-    range::{Range, RangeMapping},
-};
+use crate::mapping::Mapping;
 
 pub struct Almanac {
     seeds: Vec<u64>,
@@ -23,43 +24,39 @@ impl Almanac {
     }
 
     pub fn get_min_ranges_location(&self) -> Option<u64> {
-        self.seeds
+        let seeds: IntervalSet<u64> = self
+            .seeds
             .iter()
             .pairs()
-            .map(|(&start, &length)| Range::new(start, length))
-            .filter_map(|range| self.get_min_range_location(range))
-            .min()
-    }
-
-    fn get_min_range_location(&self, range: Range) -> Option<u64> {
-        let mut it: Box<dyn Iterator<Item = Range>> = Box::new(std::iter::once(range));
-        for mapping in self.mappings.iter() {
-            it = Box::new(it.flat_map(|range| mapping.ranges(range)));
-        }
-        it.map(|range| range.start()).min()
+            .map(|(&start, &length)| Interval::with_len(start, length))
+            .collect();
+        self.mappings
+            .iter()
+            .fold(seeds, |ranges, mapping| mapping.ranges(&ranges))
+            .first()
     }
 }
 
 impl FromStr for Almanac {
-    type Err = &'static str;
+    type Err = ParseError;
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
         let mut it = s.lines();
-        let seeds = it
-            .next()
-            .ok_or("missing seeds line")?
+        let line = it.next().ok_or_else(|| ParseError::missing("seeds line"))?;
+        let seeds = line
             .strip_prefix("seeds:")
-            .ok_or("missing seed prefix")?
+            .ok_or_else(|| ParseError::missing("seeds prefix").span(line))?
             .split_whitespace()
-            .map(|num| num.parse::<u64>())
-            .collect::<Result<Vec<_>, _>>()
-            .map_err(|_| "invalid seed number")?;
-        if !it
+            .map(|num| {
+                num.parse::<u64>()
+                    .map_err(|err| ParseError::invalid("seed number", num).with_cause(err))
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+        let line = it
             .next()
-            .ok_or("missing empty line after seeds")?
-            .is_empty()
-        {
-            return Err("line after seeds should be empty");
+            .ok_or_else(|| ParseError::missing("empty line after seeds"))?;
+        if !line.is_empty() {
+            return Err(ParseError::new("expected empty line after seeds").span(line));
         }
         let mut mappings = Vec::new();
         mappings.push(start_mapping(&mut it)?);
@@ -71,27 +68,15 @@ impl FromStr for Almanac {
                     mappings.push(start_mapping(&mut it)?);
                 } else {
                     let mut it = line.split_whitespace();
-                    let destination_start = it
-                        .next()
-                        .ok_or("missing range destination start")?
-                        .parse()
-                        .map_err(|_| "invalid range destination start")?;
-                    let source_start = it
-                        .next()
-                        .ok_or("missing range source start")?
-                        .parse()
-                        .map_err(|_| "invalid range source start")?;
-                    let length = it
-                        .next()
-                        .ok_or("missing range length")?
-                        .parse()
-                        .map_err(|_| "invalid range length")?;
-                    let range = RangeMapping {
-                        source_start,
-                        destination_start,
-                        length,
-                    };
-                    mappings.last_mut().unwrap().ranges.push(range);
+                    let destination_start =
+                        parse_range_value(line, it.next(), "destination start")?;
+                    let source_start = parse_range_value(line, it.next(), "source start")?;
+                    let length = parse_range_value(line, it.next(), "length")?;
+                    mappings
+                        .last_mut()
+                        .unwrap()
+                        .offsets
+                        .insert(Interval::with_len(source_start, length), destination_start);
                 }
             } else {
                 input_consumed = true;
@@ -101,17 +86,27 @@ impl FromStr for Almanac {
     }
 }
 
-fn start_mapping<'a, I>(it: &mut I) -> Result<Mapping, &'static str>
+fn parse_range_value(line: &str, value: Option<&str>, what: &str) -> Result<u64, ParseError> {
+    let value = value.ok_or_else(|| ParseError::missing(what).span(line).context("range"))?;
+    value.parse().map_err(|err| {
+        ParseError::invalid(what, value)
+            .context("range")
+            .with_cause(err)
+    })
+}
+
+fn start_mapping<'a, I>(it: &mut I) -> Result<Mapping, ParseError>
 where
     I: Iterator<Item = &'a str>,
 {
-    it.next()
-        .ok_or("missing map section")?
-        .strip_suffix(" map:")
-        .ok_or("missing map suffix")?
+    let line = it
+        .next()
+        .ok_or_else(|| ParseError::missing("map section"))?;
+    line.strip_suffix(" map:")
+        .ok_or_else(|| ParseError::missing("map suffix").span(line))?
         .split_once("-to-")
         .map(|(source, destination)| Mapping::new(source, destination))
-        .ok_or("missing map source to destination separator")
+        .ok_or_else(|| ParseError::missing("map source to destination separator").span(line))
 }
 
 struct PairIterator<I>
@@ -155,11 +150,10 @@ mod tests {
         let mapping = almanac.mappings.get(0).unwrap();
         assert_eq!(mapping.source, "seed");
         assert_eq!(mapping.destination, "soil");
-        assert_eq!(mapping.ranges.len(), 2);
-        let range = mapping.ranges.get(0).unwrap();
-        assert_eq!(range.source_start, 98);
-        assert_eq!(range.destination_start, 50);
-        assert_eq!(range.length, 2);
+        assert_eq!(mapping.offsets.len(), 2);
+        assert_eq!(mapping.apply(98), 50);
+        assert_eq!(mapping.apply(99), 51);
+        assert_eq!(mapping.apply(100), 100);
     }
 
     #[test]
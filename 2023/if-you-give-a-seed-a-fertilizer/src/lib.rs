@@ -0,0 +1,26 @@
+use almanac::Almanac;
+use std::fmt::Display;
+
+mod almanac;
+mod mapping;
+
+pub const DAY: aoc::Day = aoc::day!(2023, 5, Solution);
+
+pub struct Solution;
+
+impl aoc::Solver for Solution {
+    type Input = Almanac;
+    type Error = &'static str;
+
+    fn parse(input: &str) -> Result<Almanac, aoc::ParseError> {
+        input.parse()
+    }
+
+    fn part1(almanac: &Almanac) -> Result<impl Display, &'static str> {
+        almanac.get_min_location().ok_or("no min location")
+    }
+
+    fn part2(almanac: &Almanac) -> Result<impl Display, &'static str> {
+        almanac.get_min_ranges_location().ok_or("no min location")
+    }
+}
@@ -1,9 +1,9 @@
-use crate::range::{Range, RangeMapping};
+use aoc::interval::{IntervalMap, IntervalSet};
 
 pub struct Mapping {
     pub source: String,
     pub destination: String,
-    pub ranges: Vec<RangeMapping>,
+    pub offsets: IntervalMap<u64>,
 }
 
 impl Mapping {
@@ -13,63 +13,37 @@ impl Mapping {
         Mapping {
             source,
             destination,
-            ranges: Vec::new(),
+            offsets: IntervalMap::new(),
         }
     }
 
     pub fn apply(&self, value: u64) -> u64 {
-        self.ranges
-            .iter()
-            .find(|range| range.is_in(value))
-            .map(|range| range.apply(value))
-            .unwrap_or(value)
+        self.offsets.map(value)
     }
 
-    pub fn ranges(&self, mut in_range: Range) -> impl Iterator<Item = Range> {
-        let mut sorted: Vec<_> = self.ranges.iter().collect();
-        sorted.sort_by_key(|range| range.source_start);
-        let mut out: Vec<Range> = Vec::new();
-        for range in sorted {
-            if range.intersects(&in_range) {
-                if let Some(prefix) = range.align(&mut in_range) {
-                    out.push(prefix);
-                }
-                let partition = range.partition(&mut in_range);
-                out.push(partition);
-            }
-        }
-        if in_range.length() > 0 {
-            out.push(in_range);
-        }
-        out.into_iter()
+    pub fn ranges(&self, ranges: &IntervalSet<u64>) -> IntervalSet<u64> {
+        self.offsets.map_set(ranges)
     }
 }
 
 #[cfg(test)]
 mod tests {
-    use crate::{
-        mapping::Mapping,
-        range::{Range, RangeMapping},
-    };
+    use crate::mapping::Mapping;
+    use aoc::interval::{Interval, IntervalSet};
+
+    fn mapping(offsets: &[(u64, u64, u64)]) -> Mapping {
+        let mut mapping = Mapping::new("s", "d");
+        for &(source_start, destination_start, length) in offsets {
+            mapping
+                .offsets
+                .insert(Interval::with_len(source_start, length), destination_start);
+        }
+        mapping
+    }
 
     #[test]
     fn apply_mapping() {
-        let mapping = Mapping {
-            source: "s".to_string(),
-            destination: "d".to_string(),
-            ranges: vec![
-                RangeMapping {
-                    source_start: 98,
-                    destination_start: 50,
-                    length: 2,
-                },
-                RangeMapping {
-                    source_start: 95,
-                    destination_start: 0,
-                    length: 2,
-                },
-            ],
-        };
+        let mapping = mapping(&[(98, 50, 2), (95, 0, 2)]);
         assert_eq!(mapping.apply(95), 0);
         assert_eq!(mapping.apply(96), 1);
         assert_eq!(mapping.apply(97), 97);
@@ -80,39 +54,22 @@ mod tests {
 
     #[test]
     fn ranges_no_match() {
-        let mapping = Mapping {
-            source: "s".to_string(),
-            destination: "d".to_string(),
-            ranges: vec![],
-        };
-        let ranges: Vec<_> = mapping.ranges(Range::new(0, 2)).collect();
-        assert_eq!(ranges.len(), 1);
-        let range = ranges.get(0).unwrap();
-        assert_eq!(range.start(), 0);
-        assert_eq!(range.length(), 2);
+        let mapping = mapping(&[]);
+        let ranges = mapping.ranges(&Interval::with_len(0, 2).into());
+        assert_eq!(ranges, Interval::with_len(0, 2).into());
     }
 
     #[test]
     fn ranges_inner_match() {
-        let mapping = Mapping {
-            source: "s".to_string(),
-            destination: "d".to_string(),
-            ranges: vec![RangeMapping {
-                source_start: 1,
-                destination_start: 10,
-                length: 2,
-            }],
-        };
-        let ranges: Vec<_> = mapping.ranges(Range::new(0, 4)).collect();
-        assert_eq!(ranges.len(), 3);
-        let range = ranges.get(0).unwrap();
-        assert_eq!(range.start(), 0);
-        assert_eq!(range.end(), 0);
-        let range = ranges.get(1).unwrap();
-        assert_eq!(range.start(), 10);
-        assert_eq!(range.end(), 11);
-        let range = ranges.get(2).unwrap();
-        assert_eq!(range.start(), 3);
-        assert_eq!(range.end(), 3);
+        let mapping = mapping(&[(1, 10, 2)]);
+        let ranges = mapping.ranges(&Interval::with_len(0, 4).into());
+        let expected: IntervalSet<u64> = [
+            Interval::inclusive(0, 0),
+            Interval::inclusive(10, 11),
+            Interval::inclusive(3, 3),
+        ]
+        .into_iter()
+        .collect();
+        assert_eq!(ranges, expected);
     }
 }
@@ -1,5 +1,6 @@
+use crate::instruction::Instruction;
 use crate::polygon::Polygon;
-use crate::{instruction::Instruction, point::Point};
+use aoc::{geom::Vec2, ParseError};
 use std::collections::BTreeSet;
 
 pub struct DigPlan {
@@ -18,7 +19,7 @@ impl DigPlan {
             .map(|x_range| {
                 y_ranges
                     .iter()
-                    .filter(|&y_range| polygon.is_in(&Point::new(x_range.0, y_range.0)))
+                    .filter(|&y_range| polygon.is_in(&Vec2::new(x_range.0, y_range.0)))
                     .map(|&y_range| {
                         ((y_range.1 - y_range.0 + 1) * (x_range.1 - x_range.0 + 1)) as usize
                     })
@@ -28,7 +29,7 @@ impl DigPlan {
     }
 
     fn polygon(&self) -> Polygon {
-        let mut points = vec![Point::new(0, 0)];
+        let mut points = vec![Vec2::new(0, 0)];
         for instruction in self.instructions.iter() {
             let point = instruction.apply(points.last().unwrap());
             points.push(point);
@@ -36,18 +37,18 @@ impl DigPlan {
         Polygon { points }
     }
 
-    pub fn parse_v1(s: &str) -> Result<Self, &'static str> {
+    pub fn parse_v1(s: &str) -> Result<Self, ParseError> {
         DigPlan::parse(s, Instruction::parse_v1)
     }
 
-    pub fn parse_v2(s: &str) -> Result<Self, &'static str> {
+    pub fn parse_v2(s: &str) -> Result<Self, ParseError> {
         DigPlan::parse(s, Instruction::parse_v2)
     }
 
     pub fn parse(
         s: &str,
-        parse: fn(&str) -> Result<Instruction, &'static str>,
-    ) -> Result<Self, &'static str> {
+        parse: fn(&str) -> Result<Instruction, ParseError>,
+    ) -> Result<Self, ParseError> {
         let instructions = s.lines().map(parse).collect::<Result<_, _>>()?;
         Ok(DigPlan { instructions })
     }
@@ -69,7 +70,8 @@ fn to_ranges(values: BTreeSet<i64>) -> Vec<(i64, i64)> {
 
 #[cfg(test)]
 mod tests {
-    use crate::{dig::DigPlan, point::Point};
+    use crate::dig::DigPlan;
+    use aoc::geom::Vec2;
 
     const EXAMPLE: &str = include_str!("../assets/example.txt");
 
@@ -83,7 +85,7 @@ mod tests {
     fn in_line_is_in() {
         let dig_plan: DigPlan = DigPlan::parse_v1(EXAMPLE).unwrap();
         let polygon = dig_plan.polygon();
-        assert!(polygon.is_in(&Point::new(0, 0)));
+        assert!(polygon.is_in(&Vec2::new(0, 0)));
     }
 
     #[test]
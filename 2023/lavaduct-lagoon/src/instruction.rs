@@ -1,78 +1,72 @@
-use crate::{direction::Direction, point::Point};
+use aoc::{
+    geom::{Dir4, Vec2},
+    ParseError,
+};
 
 pub struct Instruction {
-    direction: Direction,
+    direction: Dir4,
     length: i64,
 }
 
 impl Instruction {
-    pub fn apply(&self, source: &Point) -> Point {
-        let Self {
-            direction, length, ..
-        } = self;
-        match direction {
-            Direction::North => *source + Point::new(0, *length),
-            Direction::South => *source + Point::new(0, -length),
-            Direction::East => *source + Point::new(*length, 0),
-            Direction::West => *source + Point::new(-length, 0),
-        }
+    pub fn apply(&self, source: &Vec2<i64>) -> Vec2<i64> {
+        *source + self.direction.delta() * self.length
     }
 
-    pub fn parse_v1(s: &str) -> Result<Self, &'static str> {
+    pub fn parse_v1(s: &str) -> Result<Self, ParseError> {
         let mut it = s.split_whitespace();
-        let direction = parse_direction(it.next().ok_or("missing trench direction")?)?;
+        let direction = it
+            .next()
+            .ok_or_else(|| ParseError::missing("trench direction").span(s))?
+            .parse()?;
         let length = it
             .next()
-            .ok_or("missing trench length")?
+            .ok_or_else(|| ParseError::missing("trench length").span(s))?;
+        let length = length
             .parse()
-            .map_err(|_| "could not parse trench length")?;
+            .map_err(|err| ParseError::invalid("trench length", length).with_cause(err))?;
         Ok(Instruction { direction, length })
     }
 
-    pub fn parse_v2(s: &str) -> Result<Self, &'static str> {
+    pub fn parse_v2(s: &str) -> Result<Self, ParseError> {
         let mut it = s.split_whitespace();
         it.next();
         it.next();
-        let final_section = it
+        let color = it
             .next()
-            .ok_or("missing final section")?
+            .ok_or_else(|| ParseError::missing("color").span(s))?;
+        let final_section = color
             .strip_prefix("(#")
-            .ok_or("missing prefix")?
+            .ok_or_else(|| ParseError::missing("color prefix").span(color))?
             .strip_suffix(")")
-            .ok_or("missing suffix")?;
-        let length = i64::from_str_radix(&final_section[..5], 16).map_err(|_| "invalid length")?;
-        let chars = final_section.chars();
-        let direction = match chars.last().ok_or("missing direction digit")? {
-            '0' => Ok(Direction::East),
-            '1' => Ok(Direction::South),
-            '2' => Ok(Direction::West),
-            '3' => Ok(Direction::North),
-            _ => Err("unkown direction"),
-        }?;
+            .ok_or_else(|| ParseError::missing("color suffix").span(color))?;
+        let (length, direction) = final_section
+            .split_at_checked(5)
+            .ok_or_else(|| ParseError::invalid("color", final_section))?;
+        let length = i64::from_str_radix(length, 16)
+            .map_err(|err| ParseError::invalid("trench length", length).with_cause(err))?;
+        let direction = direction.parse()?;
         Ok(Instruction { direction, length })
     }
 }
 
-fn parse_direction(s: &str) -> Result<Direction, &'static str> {
-    if s.len() != 1 {
-        Err("invalid length for direction")
-    } else {
-        s.chars().next().unwrap().try_into()
-    }
-}
-
 #[cfg(test)]
 mod tests {
     use super::Instruction;
-    use crate::{direction::Direction, point::Point};
+    use aoc::geom::{Dir4, Vec2};
 
     #[test]
     fn parse_example_first_line() {
         let instruction = Instruction::parse_v1("R 6 (#70c710)").unwrap();
-        assert_eq!(instruction.direction, Direction::East);
+        assert_eq!(instruction.direction, Dir4::East);
         assert_eq!(instruction.length, 6);
     }
 
+    #[test]
+    fn parse_fail_on_invalid_direction() {
+        assert!(Instruction::parse_v1("X 6 (#70c710)").is_err());
+    }
+
     #[test]
     fn parse_fail_on_missing_segment() {
         assert!(Instruction::parse_v1("R").is_err());
@@ -80,22 +74,22 @@ mod tests {
 
     #[test]
     fn apply_example1() {
-        let start = Point::new(0, 0);
+        let start = Vec2::new(0, 0);
         let instruction = Instruction::parse_v1("R 6 (#70c710)").unwrap();
-        assert_eq!(instruction.apply(&start), Point::new(6, 0));
+        assert_eq!(instruction.apply(&start), Vec2::new(6, 0));
     }
 
     #[test]
     fn apply_example2() {
-        let start = Point::new(0, 0);
+        let start = Vec2::new(0, 0);
         let instruction = Instruction::parse_v1("D 8 (#70c710)").unwrap();
-        assert_eq!(instruction.apply(&start), Point::new(0, -8));
+        assert_eq!(instruction.apply(&start), Vec2::new(0, 8));
     }
 
     #[test]
     fn parse_v2_first_line() {
         let instruction = Instruction::parse_v2("R 6 (#70c710)").unwrap();
-        assert_eq!(instruction.direction, Direction::East);
+        assert_eq!(instruction.direction, Dir4::East);
         assert_eq!(instruction.length, 461937);
     }
 }
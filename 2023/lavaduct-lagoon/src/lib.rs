@@ -0,0 +1,28 @@
+use std::fmt::Display;
+
+use dig::DigPlan;
+
+mod dig;
+mod instruction;
+mod polygon;
+
+pub const DAY: aoc::Day = aoc::day!(2023, 18, Solution);
+
+pub struct Solution;
+
+impl aoc::Solver for Solution {
+    type Input = (DigPlan, DigPlan);
+    type Error = &'static str;
+
+    fn parse(input: &str) -> Result<(DigPlan, DigPlan), aoc::ParseError> {
+        Ok((DigPlan::parse_v1(input)?, DigPlan::parse_v2(input)?))
+    }
+
+    fn part1(dig_plans: &(DigPlan, DigPlan)) -> Result<impl Display, &'static str> {
+        Ok(dig_plans.0.cubic_meters())
+    }
+
+    fn part2(dig_plans: &(DigPlan, DigPlan)) -> Result<impl Display, &'static str> {
+        Ok(dig_plans.1.cubic_meters())
+    }
+}
@@ -1,11 +1,11 @@
-use crate::point::Point;
+use aoc::geom::Vec2;
 
 pub struct Polygon {
-    pub points: Vec<Point>,
+    pub points: Vec<Vec2<i64>>,
 }
 
 impl Polygon {
-    pub fn is_in(&self, point: &Point) -> bool {
+    pub fn is_in(&self, point: &Vec2<i64>) -> bool {
         let mut winding_number = 0;
         for i in 0..self.points.len() {
             let current_point = &self.points[i];
@@ -52,8 +52,8 @@ enum Angle {
 }
 
 impl Angle {
-    fn from_points(a: &Point, b: &Point, c: &Point) -> Angle {
-        let cross_product_value = a.cross_product(b, c);
+    fn from_points(a: &Vec2<i64>, b: &Vec2<i64>, c: &Vec2<i64>) -> Angle {
+        let cross_product_value = (*c - *a).cross(*b - *a);
 
         if cross_product_value > 0 {
             Angle::Left
@@ -64,3 +64,27 @@ impl Angle {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::Angle;
+    use aoc::geom::Vec2;
+
+    #[test]
+    fn angle_linear() {
+        let angle = Angle::from_points(&Vec2::ZERO, &Vec2::new(0, -1), &Vec2::new(0, 1));
+        assert!(angle == Angle::Colinear);
+    }
+
+    #[test]
+    fn angle_left() {
+        let angle = Angle::from_points(&Vec2::ZERO, &Vec2::new(0, -1), &Vec2::new(-1, 0));
+        assert!(angle == Angle::Left);
+    }
+
+    #[test]
+    fn angle_right() {
+        let angle = Angle::from_points(&Vec2::ZERO, &Vec2::new(0, -1), &Vec2::new(1, 0));
+        assert!(angle == Angle::Right);
+    }
+}
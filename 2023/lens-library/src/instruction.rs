@@ -5,7 +5,7 @@ pub enum Instruction<'a> {
 }
 
 impl<'a> Instruction<'a> {
-    pub fn parse(s: &'a str) -> Result<Self, &'static str> {
+    pub fn parse(s: &'a str) -> Result<Self, aoc::ParseError> {
         s.split_once("=")
             .and_then(|(label, num)| {
                 num.parse::<u8>()
@@ -19,7 +19,7 @@ impl<'a> Instruction<'a> {
                 s.strip_suffix("-")
                     .map(|label| Instruction::Remove { label })
             })
-            .ok_or_else(|| "invalid instruction")
+            .ok_or_else(|| aoc::ParseError::invalid("instruction", s))
     }
 }
 
@@ -0,0 +1,62 @@
+use hash::hash;
+use hashmap::HashMap;
+use instruction::Instruction;
+use std::fmt::Display;
+
+mod hash;
+mod hashmap;
+mod instruction;
+
+pub const DAY: aoc::Day = aoc::day!(2023, 15, Solution);
+
+pub struct Solution;
+
+impl aoc::Solver for Solution {
+    type Input = Vec<String>;
+    type Error = aoc::ParseError;
+
+    fn parse(input: &str) -> Result<Vec<String>, aoc::ParseError> {
+        Ok(input.trim().split(",").map(str::to_string).collect())
+    }
+
+    fn part1(steps: &Vec<String>) -> Result<impl Display, aoc::ParseError> {
+        Ok(steps.iter().map(|string| hash(string) as u64).sum::<u64>())
+    }
+
+    fn part2(steps: &Vec<String>) -> Result<impl Display, aoc::ParseError> {
+        try_part2(steps)
+    }
+}
+
+fn try_part2(steps: &[String]) -> Result<impl Display, aoc::ParseError> {
+    let mut map = HashMap::default();
+    for instruction_string in steps {
+        let instruction = Instruction::parse(instruction_string)?;
+        match instruction {
+            Instruction::Insert {
+                label,
+                focal_length,
+            } => map.insert(label, focal_length),
+            Instruction::Remove { label } => map.remove(label),
+        }
+    }
+    Ok(map.focusing_power())
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::Solution;
+    use aoc::Part;
+
+    #[test]
+    fn example_part1() {
+        let string = "rn=1,cm-,qp=3,cm=2,qp-,pc=4,ot=9,ab=5,pc-,pc=6,ot=7";
+        assert_eq!(aoc::solve::<Solution>(string, Part::One).unwrap(), "1320");
+    }
+
+    #[test]
+    fn example_part2() {
+        let string = "rn=1,cm-,qp=3,cm=2,qp-,pc=4,ot=9,ab=5,pc-,pc=6,ot=7";
+        assert_eq!(aoc::solve::<Solution>(string, Part::Two).unwrap(), "145");
+    }
+}
@@ -1,3 +1,4 @@
+use aoc::ParseError;
 use std::str::FromStr;
 
 pub struct History {
@@ -44,12 +45,15 @@ impl History {
 }
 
 impl FromStr for History {
-    type Err = &'static str;
+    type Err = ParseError;
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
         let values = s
             .split_whitespace()
-            .map(|str| str.parse::<i32>().map_err(|_| "invalid value"))
+            .map(|str| {
+                str.parse::<i32>()
+                    .map_err(|err| ParseError::invalid("value", str).with_cause(err))
+            })
             .collect::<Result<Vec<_>, _>>()?;
         Ok(History { values })
     }
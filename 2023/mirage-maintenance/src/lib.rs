@@ -0,0 +1,26 @@
+use reading::Reading;
+use std::fmt::Display;
+
+mod history;
+mod reading;
+
+pub const DAY: aoc::Day = aoc::day!(2023, 9, Solution);
+
+pub struct Solution;
+
+impl aoc::Solver for Solution {
+    type Input = Reading;
+    type Error = &'static str;
+
+    fn parse(input: &str) -> Result<Reading, aoc::ParseError> {
+        input.parse()
+    }
+
+    fn part1(reading: &Reading) -> Result<impl Display, &'static str> {
+        Ok(reading.predict_next_sum())
+    }
+
+    fn part2(reading: &Reading) -> Result<impl Display, &'static str> {
+        Ok(reading.predict_previous_sum())
+    }
+}
@@ -19,7 +19,7 @@ impl Reading {
 }
 
 impl FromStr for Reading {
-    type Err = &'static str;
+    type Err = aoc::ParseError;
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
         let histories = s
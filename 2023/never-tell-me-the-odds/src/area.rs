@@ -1,10 +1,10 @@
 pub struct Area {
-    pub min: f64,
-    pub max: f64,
+    pub min: i128,
+    pub max: i128,
 }
 
 impl Area {
-    pub fn new(min: f64, max: f64) -> Area {
+    pub fn new(min: i128, max: i128) -> Area {
         Area { min, max }
     }
 }
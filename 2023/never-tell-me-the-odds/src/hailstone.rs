@@ -1,59 +1,56 @@
-use crate::{
-    area::Area,
-    vec::{Vec2f, Vec3i},
+use crate::area::Area;
+use aoc::{
+    geom::Vec3,
+    linalg::{Matrix, Rational},
+    ParseError,
 };
-use core::f64;
 use std::str::FromStr;
 
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct Hailstone {
-    pub position: Vec3i,
-    pub velocity: Vec3i,
+    pub position: Vec3<i64>,
+    pub velocity: Vec3<i64>,
 }
 
 impl Hailstone {
     pub fn xy_intersect_in(&self, other: &Hailstone, area: &Area) -> bool {
+        let (min, max) = (Rational::from(area.min), Rational::from(area.max));
         self.xy_intersect(other)
-            .map(|intersection| {
-                area.min <= intersection.x
-                    && area.max >= intersection.x
-                    && area.min <= intersection.y
-                    && area.max >= intersection.y
-            })
+            .map(|(x, y)| min <= x && max >= x && min <= y && max >= y)
             .unwrap_or(false)
     }
 
-    fn xy_intersect(&self, other: &Hailstone) -> Option<Vec2f> {
-        let p1 = self.position.xy();
-        let p2 = other.position.xy();
-        let v1 = self.velocity.xy();
-        let v2 = other.velocity.xy();
-        let l1_num = (p1.x - p2.x) * v2.y + (p2.y - p1.y) * v2.x;
-        let l1_den = v2.x * v1.y - v1.x * v2.y;
-        let l2_num = (p2.x - p1.x) * v1.y - (p2.y - p1.y) * v1.x;
-        let l2_den = v1.x * v2.y - v2.x * v1.y;
-        if l1_den == 0
-            || l2_den == 0
-            || l1_num.signum() * l1_den.signum() < 0
-            || l2_num.signum() * l2_den.signum() < 0
-        {
-            None
-        } else {
-            let l1 = l1_num as f64 / l1_den as f64;
-            let x = p1.x as f64 + v1.x as f64 * l1;
-            let y = p1.y as f64 + v1.y as f64 * l1;
-            Some(Vec2f { x, y })
+    fn xy_intersect(&self, other: &Hailstone) -> Option<(Rational, Rational)> {
+        let (p1, v1) = (self.position.map(i128::from), self.velocity.map(i128::from));
+        let (p2, v2) = (
+            other.position.map(i128::from),
+            other.velocity.map(i128::from),
+        );
+        let system = Matrix::from_rows([[v1.x, -v2.x], [v1.y, -v2.y]]);
+        let times = system.solve(&[(p2.x - p1.x).into(), (p2.y - p1.y).into()])?;
+        if times.iter().any(|time| *time < Rational::ZERO) {
+            return None;
         }
+        let at = |position: i128, velocity: i128| {
+            Rational::from(position) + Rational::from(velocity) * times[0]
+        };
+        Some((at(p1.x, v1.x), at(p1.y, v1.y)))
     }
 }
 
 impl FromStr for Hailstone {
-    type Err = &'static str;
+    type Err = ParseError;
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
-        let (position, velocity) = s.split_once(" @ ").ok_or("missing component")?;
-        let position = position.parse().map_err(|_| "missing position")?;
-        let velocity = velocity.parse().map_err(|_| "missing velocity")?;
+        let (position, velocity) = s
+            .split_once(" @ ")
+            .ok_or_else(|| ParseError::missing("velocity separator").span(s))?;
+        let position = position
+            .parse()
+            .map_err(|err: ParseError| err.context("position"))?;
+        let velocity = velocity
+            .parse()
+            .map_err(|err: ParseError| err.context("velocity"))?;
         Ok(Hailstone { position, velocity })
     }
 }
@@ -61,7 +58,6 @@ impl FromStr for Hailstone {
 #[cfg(test)]
 mod tests {
     use super::*;
-    const RELATIVE_ERROR: f64 = 1e-2;
 
     #[test]
     fn parse_hailstone() {
@@ -105,9 +101,7 @@ mod tests {
             velocity: (-1, -1, -2).into(),
         };
         let intersection = a.xy_intersect(&b).unwrap();
-        let expected: Vec2f = (14.33333, 15.33333).into();
-        relative_error(expected.x, intersection.x);
-        relative_error(expected.y, intersection.y);
+        assert_eq!(intersection, (Rational::new(43, 3), Rational::new(46, 3)));
     }
 
     #[test]
@@ -146,14 +140,7 @@ mod tests {
             position: (12, 31, 28).into(),
             velocity: (-1, -2, -1).into(),
         };
-        let area = Area {
-            min: 7.0,
-            max: 27.0,
-        };
+        let area = Area { min: 7, max: 27 };
         assert!(!a.xy_intersect_in(&b, &area));
     }
-
-    fn relative_error(expected: f64, actual: f64) {
-        assert!(((expected - actual) / expected).abs() < RELATIVE_ERROR);
-    }
 }
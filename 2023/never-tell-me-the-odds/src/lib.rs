@@ -0,0 +1,27 @@
+use puzzle::Puzzle;
+use std::fmt::Display;
+
+mod area;
+mod hailstone;
+mod puzzle;
+
+pub const DAY: aoc::Day = aoc::day!(2023, 24, Solution);
+
+pub struct Solution;
+
+impl aoc::Solver for Solution {
+    type Input = Puzzle;
+    type Error = &'static str;
+
+    fn parse(input: &str) -> Result<Puzzle, aoc::ParseError> {
+        input.parse()
+    }
+
+    fn part1(puzzle: &Puzzle) -> Result<impl Display, &'static str> {
+        Ok(puzzle.part1())
+    }
+
+    fn part2(puzzle: &Puzzle) -> Result<impl Display, &'static str> {
+        puzzle.part2()
+    }
+}
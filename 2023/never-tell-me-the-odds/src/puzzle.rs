@@ -1,4 +1,5 @@
 use crate::{area::Area, hailstone::Hailstone};
+use aoc::geom::Vec3;
 use std::str::FromStr;
 
 pub struct Puzzle {
@@ -6,12 +7,12 @@ pub struct Puzzle {
 }
 
 impl Puzzle {
-    pub fn part1(self) -> usize {
-        let area = Area::new(200000000000000.0, 400000000000000.0);
+    pub fn part1(&self) -> usize {
+        let area = Area::new(200000000000000, 400000000000000);
         self.part1_with_area(&area)
     }
 
-    fn part1_with_area(self, area: &Area) -> usize {
+    fn part1_with_area(&self, area: &Area) -> usize {
         self.hailstones
             .iter()
             .enumerate()
@@ -24,26 +25,42 @@ impl Puzzle {
             .sum()
     }
 
-    pub fn part2(self) -> usize {
-        let h0 = self.hailstones.get(0).unwrap();
-        let h1 = self.hailstones.get(1).unwrap();
-        let h2 = self.hailstones.get(2).unwrap();
-        let p1 = (h1.position - h0.position).widen();
-        let v1 = (h1.velocity - h0.velocity).widen();
-        let p2 = (h2.position - h0.position).widen();
-        let v2 = (h2.velocity - h0.velocity).widen();
-        let t1 = -p1.cross(p2).dot(v2) / v1.cross(p2).dot(v2);
-        let t2 = -p1.cross(p2).dot(v1) / p1.cross(v2).dot(v1);
-        let c1 = h1.position.widen() + (h1.velocity.widen() * t1);
-        let c2 = h2.position.widen() + h2.velocity.widen() * t2;
-        let v = (c2 - c1) / (t2 - t1);
+    pub fn part2(&self) -> Result<i128, &'static str> {
+        let [h0, h1, h2] = match self.hailstones.get(..3) {
+            Some([h0, h1, h2]) => [h0, h1, h2].map(|hailstone| {
+                (
+                    hailstone.position.map(i128::from),
+                    hailstone.velocity.map(i128::from),
+                )
+            }),
+            _ => return Err("need at least three hailstones"),
+        };
+        let (p1, v1) = (h1.0 - h0.0, h1.1 - h0.1);
+        let (p2, v2) = (h2.0 - h0.0, h2.1 - h0.1);
+        let t1 = exact_div(-p1.cross(p2).dot(v2), v1.cross(p2).dot(v2))?;
+        let t2 = exact_div(-p1.cross(p2).dot(v1), p1.cross(v2).dot(v1))?;
+        let c1 = h1.0 + h1.1 * t1;
+        let c2 = h2.0 + h2.1 * t2;
+        let d = c2 - c1;
+        let v = Vec3::new(
+            exact_div(d.x, t2 - t1)?,
+            exact_div(d.y, t2 - t1)?,
+            exact_div(d.z, t2 - t1)?,
+        );
         let p = c1 - v * t1;
-        (p.x + p.y + p.z) as usize
+        Ok(p.x + p.y + p.z)
+    }
+}
+
+fn exact_div(numer: i128, denom: i128) -> Result<i128, &'static str> {
+    match numer.checked_rem(denom) {
+        Some(0) => Ok(numer / denom),
+        _ => Err("no throw hits the hailstones at integer times"),
     }
 }
 
 impl FromStr for Puzzle {
-    type Err = &'static str;
+    type Err = aoc::ParseError;
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
         s.lines()
@@ -61,7 +78,7 @@ mod tests {
     fn example_part1() {
         let s = include_str!("../assets/example.txt");
         let puzzle: Puzzle = s.parse().unwrap();
-        let area = Area::new(7.0, 27.0);
+        let area = Area::new(7, 27);
         assert_eq!(puzzle.part1_with_area(&area), 2);
     }
 
@@ -69,6 +86,22 @@ mod tests {
     fn example_part2() {
         let s = include_str!("../assets/example.txt");
         let puzzle: Puzzle = s.parse().unwrap();
-        assert_eq!(puzzle.part2(), 47);
+        assert_eq!(puzzle.part2(), Ok(47));
+    }
+
+    #[test]
+    fn part2_needs_three_hailstones() {
+        let puzzle: Puzzle = "19, 13, 30 @ -2,  1, -2\n18, 19, 22 @ -1, -1, -2"
+            .parse()
+            .unwrap();
+        assert_eq!(puzzle.part2(), Err("need at least three hailstones"));
+    }
+
+    #[test]
+    fn part2_parallel_hailstones() {
+        let puzzle: Puzzle = "0, 0, 0 @ 1, 0, 0\n0, 1, 0 @ 1, 0, 0\n0, 2, 0 @ 1, 0, 0"
+            .parse()
+            .unwrap();
+        assert!(puzzle.part2().is_err());
     }
 }
@@ -0,0 +1,25 @@
+use platform::Platform;
+use std::fmt::Display;
+
+mod platform;
+
+pub const DAY: aoc::Day = aoc::day!(2023, 14, Solution);
+
+pub struct Solution;
+
+impl aoc::Solver for Solution {
+    type Input = Platform;
+    type Error = &'static str;
+
+    fn parse(input: &str) -> Result<Platform, aoc::ParseError> {
+        input.parse()
+    }
+
+    fn part1(platform: &Platform) -> Result<impl Display, &'static str> {
+        Ok(platform.clone().north_load())
+    }
+
+    fn part2(platform: &Platform) -> Result<impl Display, &'static str> {
+        Ok(platform.clone().cycled_north_load())
+    }
+}
@@ -1,8 +1,9 @@
-use std::{collections::HashMap, str::FromStr};
+use aoc::geom::Dir4;
+use std::str::FromStr;
 
 const TARGET: usize = 1000000000;
 
-#[derive(PartialEq, Eq)]
+#[derive(Clone, PartialEq, Eq, Hash)]
 pub struct Platform {
     tiles: Vec<Tile>,
     rows: usize,
@@ -11,66 +12,52 @@ pub struct Platform {
 
 impl Platform {
     pub fn north_load(self) -> usize {
-        let north = self.fall(North);
+        let north = self.fall(Dir4::North);
         north.top_load()
     }
 
-    pub fn cycled_north_load(mut self) -> usize {
-        let mut cache = HashMap::new();
-        let mut idx = 0;
-        loop {
-            if let Some(cached) = cache.get(&self.tiles) {
-                let period = idx - cached;
-                if (idx + (TARGET - idx) / period * period) % TARGET == 0 {
-                    break;
-                }
-            }
-            cache.insert(self.tiles.clone(), idx);
-            self = self.cycle();
-            idx += 1;
-        }
-        self.top_load()
+    pub fn cycled_north_load(self) -> usize {
+        aoc::cycle::nth(self, |platform| platform.clone().cycle(), TARGET).top_load()
     }
 
     fn cycle(mut self) -> Self {
-        self = self.fall(North);
-        self = self.fall(West);
-        self = self.fall(South);
-        self = self.fall(East);
+        self = self.fall(Dir4::North);
+        self = self.fall(Dir4::West);
+        self = self.fall(Dir4::South);
+        self = self.fall(Dir4::East);
         self
     }
 
-    fn fall(mut self, direction: impl Direction) -> Self {
-        for fixed_idx in 0..direction.get_fixed_max(self.rows, self.columns) {
-            let fall_ranges = self.fall_ranges(fixed_idx, &direction);
-            for (start, end) in fall_ranges {
-                let mut round_count = (start..end)
-                    .into_iter()
-                    .filter(|&variable_idx| {
-                        direction.get(&self, fixed_idx, variable_idx) == Tile::Round
-                    })
+    fn fall(mut self, direction: Dir4) -> Self {
+        for lane in 0..self.lane_count(direction) {
+            for (start, end) in self.fall_ranges(lane, direction) {
+                let round_count = (start..end)
+                    .filter(|&offset| self.lane_get(direction, lane, offset) == Tile::Round)
                     .count();
-                for variable_idx in direction.get_range_iter(start, end) {
-                    let new_tile = if round_count > 0 {
-                        round_count -= 1;
+                for step in 0..end - start {
+                    let offset = match direction {
+                        Dir4::North | Dir4::West => start + step,
+                        Dir4::South | Dir4::East => end - 1 - step,
+                    };
+                    let (row, column) = self.lane_position(direction, lane, offset);
+                    *self.get_mut(row, column) = if step < round_count {
                         Tile::Round
                     } else {
                         Tile::Empty
                     };
-                    *direction.get_mut(&mut self, fixed_idx, variable_idx) = new_tile;
                 }
             }
         }
         self
     }
 
-    fn fall_ranges(&self, fixed_idx: usize, direction: &impl Direction) -> Vec<(usize, usize)> {
-        let variable_max = direction.get_variable_max(self.rows, self.columns);
-        let square_positions: Vec<_> = (0..variable_max)
-            .filter(|&variable_idx| direction.get(self, fixed_idx, variable_idx) == Tile::Square)
+    fn fall_ranges(&self, lane: usize, direction: Dir4) -> Vec<(usize, usize)> {
+        let lane_length = self.lane_length(direction);
+        let square_positions: Vec<_> = (0..lane_length)
+            .filter(|&offset| self.lane_get(direction, lane, offset) == Tile::Square)
             .collect();
         let mut fall_ranges = Vec::new();
-        let first_square = square_positions.first().copied().unwrap_or(variable_max);
+        let first_square = square_positions.first().copied().unwrap_or(lane_length);
         if first_square > 0 {
             fall_ranges.push((0, first_square));
         }
@@ -79,7 +66,7 @@ impl Platform {
             let range_end = square_positions
                 .get(idx + 1)
                 .copied()
-                .unwrap_or(variable_max);
+                .unwrap_or(lane_length);
             if range_start + 1 < range_end {
                 fall_ranges.push((range_start, range_end));
             }
@@ -87,6 +74,35 @@ impl Platform {
         fall_ranges
     }
 
+    fn lane_count(&self, direction: Dir4) -> usize {
+        if direction.is_vertical() {
+            self.columns
+        } else {
+            self.rows
+        }
+    }
+
+    fn lane_length(&self, direction: Dir4) -> usize {
+        if direction.is_vertical() {
+            self.rows
+        } else {
+            self.columns
+        }
+    }
+
+    fn lane_position(&self, direction: Dir4, lane: usize, offset: usize) -> (usize, usize) {
+        if direction.is_vertical() {
+            (offset, lane)
+        } else {
+            (lane, offset)
+        }
+    }
+
+    fn lane_get(&self, direction: Dir4, lane: usize, offset: usize) -> Tile {
+        let (row, column) = self.lane_position(direction, lane, offset);
+        self.get(row, column)
+    }
+
     fn top_load(&self) -> usize {
         let mut total = 0;
         for column in 0..self.columns {
@@ -126,7 +142,7 @@ impl std::fmt::Debug for Platform {
 }
 
 impl FromStr for Platform {
-    type Err = &'static str;
+    type Err = aoc::ParseError;
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
         let mut rows = 0;
@@ -134,12 +150,15 @@ impl FromStr for Platform {
         let mut tiles = Vec::new();
         for line in s.lines() {
             columns = 0;
-            for character in line.chars() {
+            for (idx, character) in line.char_indices() {
                 let tile = match character {
                     'O' => Ok(Tile::Round),
                     '#' => Ok(Tile::Square),
                     '.' => Ok(Tile::Empty),
-                    _ => Err("invalid tile"),
+                    _ => Err(aoc::ParseError::invalid(
+                        "tile",
+                        &line[idx..idx + character.len_utf8()],
+                    )),
                 }?;
                 tiles.push(tile);
                 columns += 1;
@@ -154,120 +173,6 @@ impl FromStr for Platform {
     }
 }
 
-trait Axis {
-    fn get(platform: &Platform, fixed: usize, variable: usize) -> Tile;
-    fn get_mut<'a>(platform: &'a mut Platform, fixed: usize, variable: usize) -> &'a mut Tile;
-    fn get_fixed_max(rows: usize, columns: usize) -> usize;
-    fn get_variable_max(rows: usize, columns: usize) -> usize;
-}
-
-trait Direction {
-    type Item: Axis;
-
-    fn get_range_iter(&self, start: usize, end: usize) -> impl Iterator<Item = usize>;
-
-    fn get(&self, platform: &Platform, fixed: usize, variable: usize) -> Tile {
-        Self::Item::get(platform, fixed, variable)
-    }
-
-    fn get_mut<'a>(
-        &self,
-        platform: &'a mut Platform,
-        fixed: usize,
-        variable: usize,
-    ) -> &'a mut Tile {
-        Self::Item::get_mut(platform, fixed, variable)
-    }
-
-    fn get_fixed_max(&self, rows: usize, columns: usize) -> usize {
-        Self::Item::get_fixed_max(rows, columns)
-    }
-
-    fn get_variable_max(&self, rows: usize, columns: usize) -> usize {
-        Self::Item::get_variable_max(rows, columns)
-    }
-}
-
-struct VerticalAxis;
-
-impl Axis for VerticalAxis {
-    fn get(platform: &Platform, fixed: usize, variable: usize) -> Tile {
-        platform.get(variable, fixed)
-    }
-
-    fn get_mut<'a>(platform: &'a mut Platform, fixed: usize, variable: usize) -> &'a mut Tile {
-        platform.get_mut(variable, fixed)
-    }
-
-    fn get_fixed_max(_rows: usize, columns: usize) -> usize {
-        columns
-    }
-
-    fn get_variable_max(rows: usize, _columns: usize) -> usize {
-        rows
-    }
-}
-
-struct North;
-
-impl Direction for North {
-    type Item = VerticalAxis;
-
-    fn get_range_iter(&self, start: usize, end: usize) -> impl Iterator<Item = usize> {
-        start..end
-    }
-}
-
-struct South;
-
-impl Direction for South {
-    type Item = VerticalAxis;
-
-    fn get_range_iter(&self, start: usize, end: usize) -> impl Iterator<Item = usize> {
-        (start..end).rev()
-    }
-}
-
-struct HorizontalAxis;
-
-impl Axis for HorizontalAxis {
-    fn get(platform: &Platform, fixed: usize, variable: usize) -> Tile {
-        platform.get(fixed, variable)
-    }
-
-    fn get_mut<'a>(platform: &'a mut Platform, fixed: usize, variable: usize) -> &'a mut Tile {
-        platform.get_mut(fixed, variable)
-    }
-
-    fn get_fixed_max(rows: usize, _columns: usize) -> usize {
-        rows
-    }
-
-    fn get_variable_max(_rows: usize, columns: usize) -> usize {
-        columns
-    }
-}
-
-struct East;
-
-impl Direction for East {
-    type Item = HorizontalAxis;
-
-    fn get_range_iter(&self, start: usize, end: usize) -> impl Iterator<Item = usize> {
-        (start..end).rev()
-    }
-}
-
-struct West;
-
-impl Direction for West {
-    type Item = HorizontalAxis;
-
-    fn get_range_iter(&self, start: usize, end: usize) -> impl Iterator<Item = usize> {
-        start..end
-    }
-}
-
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 enum Tile {
     Round,
@@ -277,9 +182,8 @@ enum Tile {
 
 #[cfg(test)]
 mod tests {
-    use crate::platform::{North, South};
-
-    use super::{Platform, West};
+    use super::Platform;
+    use aoc::geom::Dir4;
 
     const EXAMPLE: &str = include_str!("../assets/example.txt");
     const FALLEN: &str = include_str!("../assets/fallen.txt");
@@ -299,15 +203,15 @@ mod tests {
     #[test]
     fn fall() {
         let platform: Platform = EXAMPLE.parse().unwrap();
-        assert_eq!(platform.fall(North), FALLEN.parse().unwrap());
+        assert_eq!(platform.fall(Dir4::North), FALLEN.parse().unwrap());
     }
 
     #[test]
     fn opposite_fall_identity() {
         let mut platform: Platform = EXAMPLE.parse().unwrap();
-        platform = platform.fall(North);
-        platform = platform.fall(South);
-        assert_eq!(platform.fall(North), FALLEN.parse().unwrap());
+        platform = platform.fall(Dir4::North);
+        platform = platform.fall(Dir4::South);
+        assert_eq!(platform.fall(Dir4::North), FALLEN.parse().unwrap());
     }
 
     #[test]
@@ -322,8 +226,8 @@ mod tests {
     #[test]
     fn two_step() {
         let mut platform: Platform = EXAMPLE.parse().unwrap();
-        platform = platform.fall(North);
-        platform = platform.fall(West);
+        platform = platform.fall(Dir4::North);
+        platform = platform.fall(Dir4::West);
         assert_eq!(
             platform,
             include_str!("../assets/west.txt").parse().unwrap()
@@ -1,40 +0,0 @@
-use crate::vec2::Vec2;
-
-#[derive(Clone, Copy, PartialEq, Eq)]
-pub enum Direction {
-    North,
-    South,
-    East,
-    West,
-}
-
-impl Direction {
-    pub const fn opposite(&self) -> Direction {
-        match self {
-            Direction::North => Direction::South,
-            Direction::South => Direction::North,
-            Direction::East => Direction::West,
-            Direction::West => Direction::East,
-        }
-    }
-
-    pub const fn all() -> [Direction; 4] {
-        [
-            Direction::North,
-            Direction::South,
-            Direction::East,
-            Direction::West,
-        ]
-    }
-}
-
-impl From<Direction> for Vec2 {
-    fn from(value: Direction) -> Self {
-        match value {
-            Direction::North => Vec2::north(),
-            Direction::South => Vec2::south(),
-            Direction::East => Vec2::east(),
-            Direction::West => Vec2::west(),
-        }
-    }
-}
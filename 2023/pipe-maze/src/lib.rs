@@ -0,0 +1,26 @@
+use pipes::Pipes;
+use std::fmt::Display;
+
+mod pipe_type;
+mod pipes;
+
+pub const DAY: aoc::Day = aoc::day!(2023, 10, Solution);
+
+pub struct Solution;
+
+impl aoc::Solver for Solution {
+    type Input = Pipes;
+    type Error = &'static str;
+
+    fn parse(input: &str) -> Result<Pipes, aoc::ParseError> {
+        input.parse()
+    }
+
+    fn part1(pipes: &Pipes) -> Result<impl Display, &'static str> {
+        Ok(pipes.clone().farthest_distance())
+    }
+
+    fn part2(pipes: &Pipes) -> Result<impl Display, &'static str> {
+        Ok(pipes.clone().inner_tiles())
+    }
+}
@@ -1,4 +1,4 @@
-use crate::direction::Direction;
+use aoc::geom::Dir4;
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum PipeType {
@@ -12,26 +12,26 @@ pub enum PipeType {
 }
 
 impl PipeType {
-    pub fn connections(&self) -> Option<[Direction; 2]> {
+    pub fn connections(&self) -> Option<[Dir4; 2]> {
         match self {
             PipeType::Start => None,
-            PipeType::NorthSouth => Some([Direction::North, Direction::South]),
-            PipeType::EastWest => Some([Direction::East, Direction::West]),
-            PipeType::NorthEast => Some([Direction::North, Direction::East]),
-            PipeType::NorthWest => Some([Direction::North, Direction::West]),
-            PipeType::SouthWest => Some([Direction::South, Direction::West]),
-            PipeType::SouthEast => Some([Direction::South, Direction::East]),
+            PipeType::NorthSouth => Some([Dir4::North, Dir4::South]),
+            PipeType::EastWest => Some([Dir4::East, Dir4::West]),
+            PipeType::NorthEast => Some([Dir4::North, Dir4::East]),
+            PipeType::NorthWest => Some([Dir4::North, Dir4::West]),
+            PipeType::SouthWest => Some([Dir4::South, Dir4::West]),
+            PipeType::SouthEast => Some([Dir4::South, Dir4::East]),
         }
     }
 
-    pub fn connects(&self, direction: &Direction) -> bool {
+    pub fn connects(&self, direction: &Dir4) -> bool {
         let connection = direction.opposite();
         self.connections()
             .map(|connections| connections.contains(&connection))
             .unwrap_or(true)
     }
 
-    pub fn next(&self, entry: &Direction) -> Option<Direction> {
+    pub fn next(&self, entry: &Dir4) -> Option<Dir4> {
         let opposite = entry.opposite();
         self.connections()?
             .into_iter()
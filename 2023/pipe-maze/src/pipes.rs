@@ -1,11 +1,13 @@
-use crate::{direction::Direction, pipe_type::PipeType, vec2::Vec2};
+use crate::pipe_type::PipeType;
+use aoc::geom::{Dir4, Vec2};
 use std::{
     collections::{HashMap, HashSet},
     str::FromStr,
 };
 
+#[derive(Clone)]
 pub struct Pipes {
-    pipes: HashMap<Vec2, PipeType>,
+    pipes: HashMap<Vec2<i64>, PipeType>,
 }
 
 impl Pipes {
@@ -29,7 +31,7 @@ impl Pipes {
             .count()
     }
 
-    fn is_inner(&self, vec: &Vec2, start_replacement: PipeType) -> bool {
+    fn is_inner(&self, vec: &Vec2<i64>, start_replacement: PipeType) -> bool {
         let mut left_tiles: Vec<_> = self
             .pipes
             .iter()
@@ -80,10 +82,10 @@ impl Pipes {
     fn get_start_replacement(&self) -> PipeType {
         let start = self.start().0.clone();
         match (
-            self.connected(start, Direction::North),
-            self.connected(start, Direction::South),
-            self.connected(start, Direction::East),
-            self.connected(start, Direction::West),
+            self.connected(start, Dir4::North),
+            self.connected(start, Dir4::South),
+            self.connected(start, Dir4::East),
+            self.connected(start, Dir4::West),
         ) {
             (true, true, false, false) => PipeType::NorthSouth,
             (true, false, true, false) => PipeType::NorthEast,
@@ -95,15 +97,15 @@ impl Pipes {
         }
     }
 
-    fn connected(&self, vec: Vec2, direction: Direction) -> bool {
-        let destination = vec + direction.into();
+    fn connected(&self, vec: Vec2<i64>, direction: Dir4) -> bool {
+        let destination = vec + direction.delta();
         self.pipes
             .get(&destination)
             .map(|pipe| pipe.connects(&direction))
             .unwrap_or(false)
     }
 
-    fn start(&self) -> (&Vec2, &PipeType) {
+    fn start(&self) -> (&Vec2<i64>, &PipeType) {
         self.pipes
             .iter()
             .find(|(_, &pipe)| pipe == PipeType::Start)
@@ -111,14 +113,14 @@ impl Pipes {
     }
 
     fn simplify(&mut self) {
-        let mut loop_positions: HashSet<Vec2> = HashSet::new();
+        let mut loop_positions: HashSet<Vec2<i64>> = HashSet::new();
         let start = self.start();
         loop_positions.insert(*start.0);
-        let (mut position, mut dir) = Direction::all()
+        let (mut position, mut dir) = Dir4::ALL
             .into_iter()
             .find_map(|dir| {
                 if self.connected(*start.0, dir) {
-                    let destination = *start.0 + dir.into();
+                    let destination = *start.0 + dir.delta();
                     self.pipes
                         .get(&destination)
                         .map(|pipe| (destination, pipe.next(&dir).unwrap()))
@@ -129,7 +131,7 @@ impl Pipes {
             .unwrap();
         loop_positions.insert(position);
         loop {
-            let destination = position + dir.into();
+            let destination = position + dir.delta();
             loop_positions.insert(destination);
             let pipe = self.pipes.get(&destination).unwrap();
             if let Some(new_dir) = pipe.next(&dir) {
@@ -150,7 +152,7 @@ impl Pipes {
 }
 
 impl FromStr for Pipes {
-    type Err = &'static str;
+    type Err = aoc::ParseError;
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
         let pipes = s
@@ -168,7 +170,7 @@ impl FromStr for Pipes {
                         'S' => Some(PipeType::Start),
                         _ => None,
                     }
-                    .map(|pipe| (Vec2::new(x as i32, y as i32), pipe))
+                    .map(|pipe| (Vec2::new(x as i64, y as i64), pipe))
                 })
             })
             .collect();
@@ -179,7 +181,8 @@ impl FromStr for Pipes {
 #[cfg(test)]
 mod tests {
     use super::Pipes;
-    use crate::{pipe_type::PipeType, vec2::Vec2};
+    use crate::pipe_type::PipeType;
+    use aoc::geom::Vec2;
 
     const EXAMPLE1: &str = include_str!("../assets/example1.txt");
     const EXAMPLE2: &str = include_str!("../assets/example2.txt");
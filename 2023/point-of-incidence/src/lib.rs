@@ -0,0 +1,26 @@
+use patterns::Patterns;
+use std::fmt::Display;
+
+mod pattern;
+mod patterns;
+
+pub const DAY: aoc::Day = aoc::day!(2023, 13, Solution);
+
+pub struct Solution;
+
+impl aoc::Solver for Solution {
+    type Input = Patterns;
+    type Error = &'static str;
+
+    fn parse(input: &str) -> Result<Patterns, aoc::ParseError> {
+        input.parse()
+    }
+
+    fn part1(patterns: &Patterns) -> Result<impl Display, &'static str> {
+        patterns.summarize()
+    }
+
+    fn part2(patterns: &Patterns) -> Result<impl Display, &'static str> {
+        patterns.one_off_summarize()
+    }
+}
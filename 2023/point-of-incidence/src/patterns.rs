@@ -23,7 +23,7 @@ impl Patterns {
 }
 
 impl FromStr for Patterns {
-    type Err = &'static str;
+    type Err = aoc::ParseError;
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
         let mut patterns = Vec::new();
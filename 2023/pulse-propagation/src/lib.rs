@@ -0,0 +1,30 @@
+use std::fmt::Display;
+
+use puzzle::Puzzle;
+
+mod module;
+mod module_line;
+mod module_type;
+mod puzzle;
+mod signal;
+
+pub const DAY: aoc::Day = aoc::day!(2023, 20, Solution);
+
+pub struct Solution;
+
+impl aoc::Solver for Solution {
+    type Input = Puzzle;
+    type Error = &'static str;
+
+    fn parse(input: &str) -> Result<Puzzle, aoc::ParseError> {
+        input.parse()
+    }
+
+    fn part1(puzzle: &Puzzle) -> Result<impl Display, &'static str> {
+        Ok(puzzle.clone().part1())
+    }
+
+    fn part2(puzzle: &Puzzle) -> Result<impl Display, &'static str> {
+        puzzle.clone().part2().ok_or("cycle length overflows")
+    }
+}
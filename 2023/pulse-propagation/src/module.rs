@@ -1,5 +1,6 @@
 use crate::signal::Signal;
 
+#[derive(Clone)]
 pub enum Module {
     Broadcast(Vec<String>),
     FlipFlop {
@@ -55,6 +56,7 @@ impl Module {
     }
 }
 
+#[derive(Clone)]
 pub enum State {
     On,
     Off,
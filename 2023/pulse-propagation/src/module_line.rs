@@ -12,10 +12,12 @@ impl ModuleLine {
 }
 
 impl std::str::FromStr for ModuleLine {
-    type Err = &'static str;
+    type Err = aoc::ParseError;
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
-        let (name, output) = s.split_once(" -> ").ok_or("missing module separator")?;
+        let (name, output) = s
+            .split_once(" -> ")
+            .ok_or_else(|| aoc::ParseError::missing("module separator").span(s))?;
         let (name, module_type) = match name.strip_prefix('%') {
             Some(name) => (
                 name.to_string(),
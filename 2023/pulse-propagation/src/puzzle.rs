@@ -6,6 +6,7 @@ use crate::{
 };
 use std::collections::{HashMap, VecDeque};
 
+#[derive(Clone)]
 pub struct Puzzle {
     modules: HashMap<String, Module>,
 }
@@ -41,7 +42,7 @@ impl Puzzle {
         low_signal * high_signal
     }
 
-    pub fn part2(mut self) -> usize {
+    pub fn part2(mut self) -> Option<usize> {
         let mut messages = VecDeque::new();
         let mut presses = 0;
         let conjuction = self
@@ -101,15 +102,12 @@ impl Puzzle {
                 }
             }
         }
-        cycle_sizes
-            .into_iter()
-            .map(|(_, size)| size)
-            .fold(1, |a, b| a * b)
+        aoc::math::lcm_all(cycle_sizes.into_values())
     }
 }
 
 impl std::str::FromStr for Puzzle {
-    type Err = &'static str;
+    type Err = aoc::ParseError;
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
         let mut modules_lines = Vec::new();
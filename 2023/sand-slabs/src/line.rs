@@ -1,11 +1,10 @@
+use aoc::geom::Vec3;
 use std::str::FromStr;
 
-use crate::vec::Vec3i;
-
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct Line {
-    pub start: Vec3i,
-    pub end: Vec3i,
+    pub start: Vec3<i64>,
+    pub end: Vec3<i64>,
 }
 
 impl Line {
@@ -31,10 +30,12 @@ impl Line {
 }
 
 impl FromStr for Line {
-    type Err = &'static str;
+    type Err = aoc::ParseError;
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
-        let (start, end) = s.split_once('~').ok_or("no separator")?;
+        let (start, end) = s
+            .split_once('~')
+            .ok_or_else(|| aoc::ParseError::missing("separator").span(s))?;
         let start = start.parse()?;
         let end = end.parse()?;
         Ok(Line { start, end })
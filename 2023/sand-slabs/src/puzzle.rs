@@ -5,6 +5,7 @@ use std::{
     str::FromStr,
 };
 
+#[derive(Clone)]
 pub struct Puzzle {
     lines: Vec<Line>,
 }
@@ -87,7 +88,7 @@ impl Puzzle {
 }
 
 impl FromStr for Puzzle {
-    type Err = &'static str;
+    type Err = aoc::ParseError;
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
         let lines = s
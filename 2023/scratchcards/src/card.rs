@@ -1,3 +1,4 @@
+use aoc::ParseError;
 use std::str::FromStr;
 
 pub struct Card {
@@ -23,27 +24,24 @@ impl Card {
 }
 
 impl FromStr for Card {
-    type Err = &'static str;
+    type Err = ParseError;
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
-        let (left, right) = s.split_once(": ").ok_or("missing description separator")?;
-        let id = left
+        let (left, right) = s
+            .split_once(": ")
+            .ok_or_else(|| ParseError::missing("description separator").span(s))?;
+        let id_s = left
             .strip_prefix("Card ")
-            .ok_or("missing prefix")?
-            .trim()
+            .ok_or_else(|| ParseError::missing("card prefix").span(left))?
+            .trim();
+        let id = id_s
             .parse()
-            .map_err(|_| "invalid id")?;
-        let (winning_s, contained_s) = right.split_once(" | ").ok_or("missing values separator")?;
-        let winning = winning_s
-            .split_whitespace()
-            .map(|num_s| num_s.parse())
-            .collect::<Result<_, _>>()
-            .map_err(|_| "invalid win number")?;
-        let contained = contained_s
-            .split_whitespace()
-            .map(|num_s| num_s.parse())
-            .collect::<Result<_, _>>()
-            .map_err(|_| "invalid contained number")?;
+            .map_err(|err| ParseError::invalid("id", id_s).with_cause(err))?;
+        let (winning_s, contained_s) = right
+            .split_once(" | ")
+            .ok_or_else(|| ParseError::missing("values separator").span(right))?;
+        let winning = parse_numbers(winning_s, "win number")?;
+        let contained = parse_numbers(contained_s, "contained number")?;
         Ok(Card {
             _id: id,
             winning,
@@ -52,6 +50,16 @@ impl FromStr for Card {
     }
 }
 
+fn parse_numbers(s: &str, what: &str) -> Result<Vec<u32>, ParseError> {
+    s.split_whitespace()
+        .map(|num_s| {
+            num_s
+                .parse()
+                .map_err(|err| ParseError::invalid(what, num_s).with_cause(err))
+        })
+        .collect()
+}
+
 #[cfg(test)]
 mod tests {
     use super::Card;
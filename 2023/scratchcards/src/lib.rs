@@ -0,0 +1,60 @@
+use card::Card;
+use std::fmt::Display;
+
+mod card;
+
+pub const DAY: aoc::Day = aoc::day!(2023, 4, Solution);
+
+pub struct Solution;
+
+impl aoc::Solver for Solution {
+    type Input = Vec<Card>;
+    type Error = &'static str;
+
+    fn parse(input: &str) -> Result<Vec<Card>, aoc::ParseError> {
+        input.lines().map(|line| line.parse()).collect()
+    }
+
+    fn part1(cards: &Vec<Card>) -> Result<impl Display, &'static str> {
+        Ok(cards.iter().map(|card| card.points()).sum::<u32>())
+    }
+
+    fn part2(cards: &Vec<Card>) -> Result<impl Display, &'static str> {
+        Ok(compute_part2(
+            cards.iter().map(|card| card.count()).collect(),
+        ))
+    }
+}
+
+fn compute_part2(card_matches: Vec<usize>) -> usize {
+    let mut counts: Vec<_> = card_matches.iter().map(|_| 1usize).collect();
+    for (idx, matches) in card_matches.iter().enumerate() {
+        let start = idx + 1;
+        let end = start + matches;
+        let count = *counts.get(idx).unwrap();
+        if *matches > 0 {
+            for value in counts[start..end].iter_mut() {
+                *value += count;
+            }
+        }
+    }
+    counts.into_iter().sum()
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::Solution;
+    use aoc::Part;
+
+    #[test]
+    fn example_part1() {
+        let s = include_str!("../assets/example.txt");
+        assert_eq!(aoc::solve::<Solution>(s, Part::One).unwrap(), "13");
+    }
+
+    #[test]
+    fn example_part2() {
+        let s = include_str!("../assets/example.txt");
+        assert_eq!(aoc::solve::<Solution>(s, Part::Two).unwrap(), "30")
+    }
+}
@@ -0,0 +1,25 @@
+use puzzle::Puzzle;
+use std::fmt::Display;
+
+mod puzzle;
+
+pub const DAY: aoc::Day = aoc::day!(2023, 25, Solution);
+
+pub struct Solution;
+
+impl aoc::Solver for Solution {
+    type Input = Puzzle;
+    type Error = &'static str;
+
+    fn parse(input: &str) -> Result<Puzzle, aoc::ParseError> {
+        input.parse()
+    }
+
+    fn part1(puzzle: &Puzzle) -> Result<impl Display, &'static str> {
+        puzzle.solve()
+    }
+
+    fn part2(_puzzle: &Puzzle) -> Result<impl Display, &'static str> {
+        Ok("Done")
+    }
+}
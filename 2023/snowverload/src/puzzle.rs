@@ -1,15 +1,17 @@
+use aoc::rand::Rng;
 use std::collections::HashMap;
-use std::fmt::Display;
 use std::str::FromStr;
 use std::usize;
 
+const MAX_ATTEMPTS: usize = 10_000;
+
 pub struct Puzzle {
     components: Vec<String>,
     connections: HashMap<usize, Vec<usize>>,
 }
 
 impl Puzzle {
-    pub fn solve(self) -> impl Display {
+    pub fn solve(&self) -> Result<usize, &'static str> {
         let edges: Vec<_> = self
             .connections
             .iter()
@@ -23,17 +25,24 @@ impl Puzzle {
     }
 }
 
-fn find_partition(edges: &[Edge], nodes: usize) -> usize {
-    let mut rng = Random::default();
+fn find_partition(edges: &[Edge], nodes: usize) -> Result<usize, &'static str> {
+    let mut rng = Rng::seeded();
     std::iter::repeat_with(|| edges.to_vec())
-        .find_map(|edges| try_partition(&mut rng, edges, nodes))
-        .unwrap()
+        .take(MAX_ATTEMPTS)
+        .find_map(|edges| try_partition(&mut rng, edges, nodes).transpose())
+        .unwrap_or(Err("no cut of three wires found"))
 }
 
-fn try_partition(rng: &mut Random, mut edges: Vec<Edge>, nodes: usize) -> Option<usize> {
+fn try_partition(
+    rng: &mut Rng,
+    mut edges: Vec<Edge>,
+    nodes: usize,
+) -> Result<Option<usize>, &'static str> {
     let mut nodes: Vec<_> = std::iter::repeat(1).take(nodes).collect();
     while nodes.iter().filter(|&weight| *weight != 0).count() > 2 {
-        let removed_edge = rng.sample(&edges);
+        let removed_edge = *rng
+            .sample(&edges)
+            .ok_or("components split into more than two groups")?;
         let (left, right) = removed_edge.unpack();
         *nodes.get_mut(left).unwrap() += *nodes.get(right).unwrap();
         *nodes.get_mut(right).unwrap() = 0;
@@ -43,25 +52,27 @@ fn try_partition(rng: &mut Random, mut edges: Vec<Edge>, nodes: usize) -> Option
         }
     }
     if edges.len() == 3 {
-        Some(
+        Ok(Some(
             nodes
                 .iter()
                 .filter(|&weight| *weight != 0)
                 .fold(1, |a, b| a * b),
-        )
+        ))
     } else {
-        None
+        Ok(None)
     }
 }
 
 impl FromStr for Puzzle {
-    type Err = &'static str;
+    type Err = aoc::ParseError;
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
         let mut components = Vec::new();
         let mut connections = HashMap::new();
         for line in s.lines() {
-            let (left, rights) = line.split_once(": ").ok_or("colon separator not found")?;
+            let (left, rights) = line
+                .split_once(": ")
+                .ok_or_else(|| aoc::ParseError::missing("colon separator").span(line))?;
             let left_idx =
                 if let Some(idx) = components.iter().position(|component| component == left) {
                     idx
@@ -112,50 +123,6 @@ impl Edge {
     }
 }
 
-struct Random {
-    state: u32,
-}
-
-impl Default for Random {
-    fn default() -> Self {
-        Random { state: 123456789 }
-    }
-}
-
-impl Random {
-    fn next(&mut self) -> u32 {
-        self.state = self.state.wrapping_mul(1103515245).wrapping_add(12345);
-        self.state
-    }
-
-    fn sample<T>(&mut self, l: &[T]) -> T
-    where
-        T: Copy,
-    {
-        let length = u32::try_from(l.len()).unwrap();
-        let idx = usize::try_from(self.next_in_range(length)).unwrap();
-        *l.get(idx).unwrap()
-    }
-
-    fn next_in_range(&mut self, range: u32) -> u32 {
-        let mut modulo = None;
-        loop {
-            let new = modulo.map(|v| v >> 1).unwrap_or(1 << 30);
-            if new < range {
-                break;
-            }
-            modulo = Some(new);
-        }
-        loop {
-            let value = self.next();
-            let value = modulo.map(|modulo| value % modulo).unwrap_or(value);
-            if value < range {
-                return value;
-            }
-        }
-    }
-}
-
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -164,6 +131,21 @@ mod tests {
     fn example() {
         let string = include_str!("../assets/example.txt");
         let puzzle: Puzzle = string.parse().unwrap();
-        assert_eq!(puzzle.solve().to_string(), "54");
+        assert_eq!(puzzle.solve(), Ok(54));
+    }
+
+    #[test]
+    fn disconnected() {
+        let puzzle: Puzzle = "a: b\nc: d\ne: f".parse().unwrap();
+        assert_eq!(
+            puzzle.solve(),
+            Err("components split into more than two groups")
+        );
+    }
+
+    #[test]
+    fn no_three_wire_cut() {
+        let puzzle: Puzzle = "a: b c\nb: c".parse().unwrap();
+        assert_eq!(puzzle.solve(), Err("no cut of three wires found"));
     }
 }
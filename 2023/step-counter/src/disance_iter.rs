@@ -1,4 +1,4 @@
-use crate::vec::Vec2i;
+use aoc::geom::Vec2;
 
 pub struct DistanceIter {
     index: i64,
@@ -11,13 +11,13 @@ impl From<i64> for DistanceIter {
         DistanceIter {
             index: 0,
             max: value,
-            secondary: TileIterator::new((0, value).into()),
+            secondary: TileIterator::new(Vec2::new(value, 0)),
         }
     }
 }
 
 impl Iterator for DistanceIter {
-    type Item = Vec2i;
+    type Item = Vec2<i64>;
 
     fn next(&mut self) -> Option<Self::Item> {
         if let Some(next) = self.secondary.next() {
@@ -25,8 +25,8 @@ impl Iterator for DistanceIter {
         } else {
             self.index += 1;
             if self.index <= self.max {
-                let base = (self.index, self.max - self.index);
-                self.secondary = TileIterator::new(base.into());
+                let base = Vec2::new(self.max - self.index, self.index);
+                self.secondary = TileIterator::new(base);
                 self.secondary.next()
             } else {
                 None
@@ -38,15 +38,15 @@ impl Iterator for DistanceIter {
 struct TileIterator {
     index: usize,
     size: usize,
-    tiles: [Vec2i; 4],
+    tiles: [Vec2<i64>; 4],
 }
 
 impl TileIterator {
-    fn new(base: Vec2i) -> Self {
-        let mut tiles = [(0, 0).into(); 4];
+    fn new(base: Vec2<i64>) -> Self {
+        let mut tiles = [Vec2::ZERO; 4];
         let mut size = 0;
         for multiplier in [(1, 1), (-1, 1), (1, -1), (-1, -1)] {
-            let position = (base.row * multiplier.0, base.column * multiplier.1).into();
+            let position = Vec2::new(base.x * multiplier.1, base.y * multiplier.0);
             if !tiles[0..size].contains(&position) {
                 tiles[size] = position;
                 size += 1;
@@ -61,7 +61,7 @@ impl TileIterator {
 }
 
 impl Iterator for TileIterator {
-    type Item = Vec2i;
+    type Item = Vec2<i64>;
 
     fn next(&mut self) -> Option<Self::Item> {
         if self.index < self.size {
@@ -77,43 +77,44 @@ impl Iterator for TileIterator {
 #[cfg(test)]
 mod tests {
     use super::{DistanceIter, TileIterator};
+    use aoc::geom::Vec2;
 
     #[test]
     fn return_single_element_on_full_symmetry() {
-        let mut iter = TileIterator::new((0, 0).into());
-        assert_eq!(iter.next().unwrap(), (0, 0).into());
+        let mut iter = TileIterator::new(Vec2::new(0, 0));
+        assert_eq!(iter.next().unwrap(), Vec2::new(0, 0));
         assert!(iter.next().is_none())
     }
 
     #[test]
     fn return_two_on_single_axis_symmetry() {
-        let mut iter = TileIterator::new((3, 0).into());
-        assert_eq!(iter.next().unwrap(), (3, 0).into());
-        assert_eq!(iter.next().unwrap(), (-3, 0).into());
+        let mut iter = TileIterator::new(Vec2::new(0, 3));
+        assert_eq!(iter.next().unwrap(), Vec2::new(0, 3));
+        assert_eq!(iter.next().unwrap(), Vec2::new(0, -3));
         assert!(iter.next().is_none())
     }
 
     #[test]
     fn return_all_on_no_symmetry() {
-        let mut iter = TileIterator::new((3, 1).into());
-        assert_eq!(iter.next().unwrap(), (3, 1).into());
-        assert_eq!(iter.next().unwrap(), (-3, 1).into());
-        assert_eq!(iter.next().unwrap(), (3, -1).into());
-        assert_eq!(iter.next().unwrap(), (-3, -1).into());
+        let mut iter = TileIterator::new(Vec2::new(1, 3));
+        assert_eq!(iter.next().unwrap(), Vec2::new(1, 3));
+        assert_eq!(iter.next().unwrap(), Vec2::new(1, -3));
+        assert_eq!(iter.next().unwrap(), Vec2::new(-1, 3));
+        assert_eq!(iter.next().unwrap(), Vec2::new(-1, -3));
         assert!(iter.next().is_none())
     }
 
     #[test]
     fn example_distance_iter() {
         let mut iter = DistanceIter::from(2);
-        assert_eq!(iter.next().unwrap(), (0, 2).into());
-        assert_eq!(iter.next().unwrap(), (0, -2).into());
-        assert_eq!(iter.next().unwrap(), (1, 1).into());
-        assert_eq!(iter.next().unwrap(), (-1, 1).into());
-        assert_eq!(iter.next().unwrap(), (1, -1).into());
-        assert_eq!(iter.next().unwrap(), (-1, -1).into());
-        assert_eq!(iter.next().unwrap(), (2, 0).into());
-        assert_eq!(iter.next().unwrap(), (-2, 0).into());
+        assert_eq!(iter.next().unwrap(), Vec2::new(2, 0));
+        assert_eq!(iter.next().unwrap(), Vec2::new(-2, 0));
+        assert_eq!(iter.next().unwrap(), Vec2::new(1, 1));
+        assert_eq!(iter.next().unwrap(), Vec2::new(1, -1));
+        assert_eq!(iter.next().unwrap(), Vec2::new(-1, 1));
+        assert_eq!(iter.next().unwrap(), Vec2::new(-1, -1));
+        assert_eq!(iter.next().unwrap(), Vec2::new(0, 2));
+        assert_eq!(iter.next().unwrap(), Vec2::new(0, -2));
         assert!(iter.next().is_none())
     }
 }
@@ -1,12 +1,10 @@
-use crate::{disance_iter::DistanceIter, vec::Vec2i};
-use std::{
-    collections::{HashMap, HashSet, VecDeque},
-    i64, usize,
-};
+use crate::disance_iter::DistanceIter;
+use aoc::geom::{Dir4, Vec2};
+use std::collections::{HashMap, HashSet};
 
 pub struct Puzzle {
-    start: Vec2i,
-    garden: HashSet<Vec2i>,
+    start: Vec2<i64>,
+    garden: HashSet<Vec2<i64>>,
     rows: i64,
     columns: i64,
 }
@@ -15,7 +13,7 @@ const PART1_STEPS: usize = 64;
 const PART2_STEPS: usize = 26501365;
 
 impl Puzzle {
-    fn new(start: Vec2i, rows: i64, columns: i64, garden: HashSet<Vec2i>) -> Puzzle {
+    fn new(start: Vec2<i64>, rows: i64, columns: i64, garden: HashSet<Vec2<i64>>) -> Puzzle {
         Puzzle {
             start,
             garden,
@@ -24,28 +22,27 @@ impl Puzzle {
         }
     }
 
-    pub fn part1(self) -> usize {
+    pub fn part1(&self) -> usize {
         self.reachable(PART1_STEPS)
     }
 
-    pub fn part2(self) -> usize {
+    pub fn part2(&self) -> usize {
         self.fast_reachable(PART2_STEPS)
     }
 
-    fn reachable(self, steps: usize) -> usize {
+    fn reachable(&self, steps: usize) -> usize {
         self.filtered_reachable(steps, |p| self.garden.contains(p))
     }
 
     fn fast_reachable(&self, steps: usize) -> usize {
         let unstable_area = 4;
-        let distances = self.distances(usize::MAX, |position| {
+        let distances = self.distances(|position| {
             let chunks = self.chunk(position);
-            chunks.row.abs() + chunks.column.abs() <= unstable_area
-                && self.contains_wrapped(position)
+            chunks.x.abs() + chunks.y.abs() <= unstable_area && self.contains_wrapped(position)
         });
         let chunks = self.chunks(&distances);
         let edge = (1..unstable_area)
-            .map(|idx| chunks.get(&Vec2i::new(idx, unstable_area - idx)).unwrap())
+            .map(|idx| chunks.get(&Vec2::new(unstable_area - idx, idx)).unwrap())
             .collect::<Vec<_>>();
         for (f, s) in edge[..(edge.len() - 1)].iter().zip(edge[1..].iter()) {
             assert_eq!(f, s)
@@ -53,29 +50,29 @@ impl Puzzle {
         let offset = (
             self.offset(
                 &chunks
-                    .get(&(unstable_area - 1, 0).into())
+                    .get(&Vec2::new(0, unstable_area - 1))
                     .unwrap()
                     .distances,
-                &chunks.get(&(unstable_area, 0).into()).unwrap().distances,
+                &chunks.get(&Vec2::new(0, unstable_area)).unwrap().distances,
             ),
             self.offset(
                 &chunks
-                    .get(&(0, unstable_area - 1).into())
+                    .get(&Vec2::new(unstable_area - 1, 0))
                     .unwrap()
                     .distances,
-                &chunks.get(&(0, unstable_area).into()).unwrap().distances,
+                &chunks.get(&Vec2::new(unstable_area, 0)).unwrap().distances,
             ),
         );
         assert_eq!(offset.0, offset.1);
         let offset = offset.0;
         let even_count = chunks
-            .get(&(0, 0).into())
+            .get(&Vec2::new(0, 0))
             .unwrap()
             .distances
             .values()
             .filter(|value| *value % 2 == steps % 2)
             .count();
-        let odd_count = chunks.get(&(0, 0).into()).unwrap().distances.len() - even_count;
+        let odd_count = chunks.get(&Vec2::new(0, 0)).unwrap().distances.len() - even_count;
         let max = chunks.values().map(|chunk| chunk.max).max().unwrap();
         let (mut distance, mut count) = steps
             .checked_sub(max)
@@ -129,10 +126,10 @@ impl Puzzle {
                 } else {
                     // Square corners
                     count += [
-                        Vec2i::new(0, unstable_area),
-                        Vec2i::new(unstable_area, 0),
-                        Vec2i::new(0, -unstable_area),
-                        Vec2i::new(-unstable_area, 0),
+                        Vec2::new(unstable_area, 0),
+                        Vec2::new(0, unstable_area),
+                        Vec2::new(-unstable_area, 0),
+                        Vec2::new(0, -unstable_area),
                     ]
                     .map(|chunk| chunks.get(&chunk).unwrap())
                     .map(|chunk| {
@@ -148,10 +145,10 @@ impl Puzzle {
                     .sum::<usize>();
                     // edges
                     count += [
-                        Vec2i::new(1, unstable_area - 1),
-                        Vec2i::new(-1, unstable_area - 1),
-                        Vec2i::new(1, -(unstable_area - 1)),
-                        Vec2i::new(-1, -(unstable_area - 1)),
+                        Vec2::new(unstable_area - 1, 1),
+                        Vec2::new(unstable_area - 1, -1),
+                        Vec2::new(-(unstable_area - 1), 1),
+                        Vec2::new(-(unstable_area - 1), -1),
                     ]
                     .into_iter()
                     .map(|chunk| {
@@ -177,12 +174,12 @@ impl Puzzle {
         count
     }
 
-    fn offset(&self, near: &HashMap<Vec2i, usize>, far: &HashMap<Vec2i, usize>) -> usize {
+    fn offset(&self, near: &HashMap<Vec2<i64>, usize>, far: &HashMap<Vec2<i64>, usize>) -> usize {
         let offsets: HashSet<_> = (0..self.rows)
-            .flat_map(|row| (0..self.columns).map(move |column| (row, column)))
+            .flat_map(|row| (0..self.columns).map(move |column| Vec2::new(column, row)))
             .filter_map(|position| {
-                let near = near.get(&position.into());
-                let far = far.get(&position.into());
+                let near = near.get(&position);
+                let far = far.get(&position);
                 match (far, near) {
                     (Some(far), Some(near)) => Some(far - near),
                     _ => None,
@@ -193,12 +190,12 @@ impl Puzzle {
         offsets.into_iter().next().unwrap()
     }
 
-    fn chunks(&self, distances: &HashMap<Vec2i, usize>) -> HashMap<Vec2i, Chunk> {
+    fn chunks(&self, distances: &HashMap<Vec2<i64>, usize>) -> HashMap<Vec2<i64>, Chunk> {
         distances
             .iter()
             .fold(HashMap::new(), |mut chunks, (vec, distance)| {
                 let chunk = self.chunk(vec);
-                let chunk_position = vec.rem_euclid(self.rows, self.columns);
+                let chunk_position = vec.rem_euclid(self.size());
                 chunks
                     .entry(chunk)
                     .or_default()
@@ -207,58 +204,46 @@ impl Puzzle {
             })
     }
 
-    fn chunk(&self, position: &Vec2i) -> Vec2i {
-        position.div_euclid(self.rows, self.columns)
+    fn chunk(&self, position: &Vec2<i64>) -> Vec2<i64> {
+        position.div_euclid(self.size())
     }
 
     fn filtered_reachable<F>(&self, steps: usize, func: F) -> usize
     where
-        F: Fn(&Vec2i) -> bool,
+        F: Fn(&Vec2<i64>) -> bool,
     {
-        let distances = self.distances(steps, &func);
+        let distances = self.distances(&func);
         let parity = steps % 2;
         distances
             .values()
-            .into_iter()
+            .filter(|&&distance| distance <= steps)
             .filter(|&distance| distance % 2 == parity)
             .count()
     }
 
-    fn distances<F>(&self, steps: usize, func: F) -> HashMap<Vec2i, usize>
+    fn distances<F>(&self, func: F) -> HashMap<Vec2<i64>, usize>
     where
-        F: Fn(&Vec2i) -> bool,
+        F: Fn(&Vec2<i64>) -> bool,
     {
-        let mut queue = VecDeque::new();
-        queue.push_back(self.start.clone());
-        let mut distances = HashMap::new();
-        distances.insert(self.start.clone(), 0);
-        while let Some(position) = queue.pop_front() {
-            let new_distance = distances.get(&position).unwrap() + 1;
-            if new_distance <= steps {
-                for adjacent in position.adjacent().into_iter().filter(&func) {
-                    if distances
-                        .get(&adjacent)
-                        .map(|&old_distance| new_distance < old_distance)
-                        .unwrap_or(true)
-                    {
-                        distances.insert(adjacent, new_distance);
-                        queue.push_back(adjacent)
-                    }
-                }
-            }
-        }
-        distances
+        aoc::search::distances([self.start], |position| {
+            Dir4::ALL
+                .map(|dir| *position + dir.delta())
+                .into_iter()
+                .filter(&func)
+        })
+    }
+
+    fn size(&self) -> Vec2<i64> {
+        Vec2::new(self.columns, self.rows)
     }
 
-    fn contains_wrapped(&self, position: &Vec2i) -> bool {
-        let row = position.row.rem_euclid(self.rows);
-        let column = position.column.rem_euclid(self.columns);
-        self.garden.contains(&Vec2i::new(row, column))
+    fn contains_wrapped(&self, position: &Vec2<i64>) -> bool {
+        self.garden.contains(&position.rem_euclid(self.size()))
     }
 }
 
 impl std::str::FromStr for Puzzle {
-    type Err = &'static str;
+    type Err = aoc::ParseError;
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
         let mut start = None;
@@ -267,31 +252,35 @@ impl std::str::FromStr for Puzzle {
             for (column, symbol) in line.chars().enumerate() {
                 match symbol {
                     '.' => {
-                        garden.insert((row as i64, column as i64).into());
+                        garden.insert(Vec2::new(column as i64, row as i64));
                     }
                     'S' => {
-                        garden.insert((row as i64, column as i64).into());
-                        start = Some((row as i64, column as i64).into());
+                        garden.insert(Vec2::new(column as i64, row as i64));
+                        start = Some(Vec2::new(column as i64, row as i64));
                     }
                     _ => (),
                 }
             }
         }
-        let start = start.ok_or("start not found")?;
+        let start = start.ok_or_else(|| aoc::ParseError::missing("start"))?;
         let rows = s.lines().count() as i64;
-        let columns = s.lines().map(|line| line.len()).max().ok_or("no rows")? as i64;
+        let columns = s
+            .lines()
+            .map(|line| line.len())
+            .max()
+            .ok_or_else(|| aoc::ParseError::missing("rows"))? as i64;
         Ok(Puzzle::new(start, rows, columns, garden))
     }
 }
 
 #[derive(Debug, Default, PartialEq)]
 struct Chunk {
-    distances: HashMap<Vec2i, usize>,
+    distances: HashMap<Vec2<i64>, usize>,
     max: usize,
 }
 
 impl Chunk {
-    fn update(&mut self, vec: Vec2i, distance: usize) {
+    fn update(&mut self, vec: Vec2<i64>, distance: usize) {
         if distance > self.max {
             self.max = distance
         }
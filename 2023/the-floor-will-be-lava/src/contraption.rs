@@ -1,3 +1,4 @@
+use aoc::{geom::Dir4, ParseError};
 use std::{collections::HashSet, str::FromStr};
 
 pub struct Contraption {
@@ -7,22 +8,26 @@ pub struct Contraption {
 
 impl Contraption {
     pub fn energized(&self) -> usize {
-        self.energized_from((0, 0), Direction::East)
+        self.energized_from((0, 0), Dir4::East)
     }
 
     pub fn max_energized(&self) -> usize {
         let row_count = self.tiles.len() / self.columns;
-        (0..row_count)
-            .map(|row| ((row, 0), Direction::East))
-            .chain((0..row_count).map(|row| ((row, self.columns - 1), Direction::West)))
-            .chain((0..self.columns).map(|column| ((0, column), Direction::South)))
-            .chain((0..self.columns).map(|column| ((row_count - 1, column), Direction::North)))
-            .map(|(position, direction)| self.energized_from(position, direction))
-            .max()
-            .unwrap()
+        let starts: Vec<_> = (0..row_count)
+            .map(|row| ((row, 0), Dir4::East))
+            .chain((0..row_count).map(|row| ((row, self.columns - 1), Dir4::West)))
+            .chain((0..self.columns).map(|column| ((0, column), Dir4::South)))
+            .chain((0..self.columns).map(|column| ((row_count - 1, column), Dir4::North)))
+            .collect();
+        aoc::par::map_reduce(
+            &starts,
+            |&(position, direction)| self.energized_from(position, direction),
+            usize::max,
+        )
+        .unwrap()
     }
 
-    fn energized_from(&self, position: (usize, usize), direction: Direction) -> usize {
+    fn energized_from(&self, position: (usize, usize), direction: Dir4) -> usize {
         let mut visited = HashSet::new();
         let mut stack = Vec::new();
         stack.push((position, direction));
@@ -31,14 +36,14 @@ impl Contraption {
             let index = position.0 * self.columns + position.1;
             let current_tile = self.tiles[index];
             let (next_direction, alternative) = current_tile.next_direction(direction);
-            if let Some(in_position) = self.fit_in(next_direction.apply(position)) {
+            if let Some(in_position) = self.fit_in(step(position, next_direction)) {
                 let new = (in_position, next_direction);
                 if !visited.contains(&new) {
                     stack.push(new)
                 }
             }
             if let Some(alternative) = alternative {
-                if let Some(in_position) = self.fit_in(alternative.apply(position)) {
+                if let Some(in_position) = self.fit_in(step(position, alternative)) {
                     let new = (in_position, alternative);
                     if !visited.contains(&new) {
                         stack.push(new)
@@ -60,15 +65,17 @@ impl Contraption {
 }
 
 impl FromStr for Contraption {
-    type Err = &'static str;
+    type Err = ParseError;
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
         let mut columns = 0;
         let mut tiles = Vec::new();
         for line in s.lines() {
             columns = line.len();
-            for character in line.chars() {
-                tiles.push(Tile::try_from(character)?);
+            for (idx, character) in line.char_indices() {
+                let tile = Tile::try_from(character)
+                    .map_err(|err| err.span(&line[idx..idx + character.len_utf8()]))?;
+                tiles.push(tile);
             }
         }
         Ok(Self { tiles, columns })
@@ -85,35 +92,21 @@ enum Tile {
 }
 
 impl Tile {
-    fn next_direction(&self, direction: Direction) -> (Direction, Option<Direction>) {
+    fn next_direction(&self, direction: Dir4) -> (Dir4, Option<Dir4>) {
         match self {
-            Tile::EmptySpace => (direction, None),
-            Tile::DiagonalWest => match direction {
-                Direction::North => (Direction::West, None),
-                Direction::South => (Direction::East, None),
-                Direction::West => (Direction::North, None),
-                Direction::East => (Direction::South, None),
-            },
-            Tile::DiagonalEast => match direction {
-                Direction::North => (Direction::East, None),
-                Direction::South => (Direction::West, None),
-                Direction::West => (Direction::South, None),
-                Direction::East => (Direction::North, None),
-            },
-            Tile::Horizontal => match direction {
-                Direction::North | Direction::South => (Direction::West, Some(Direction::East)),
-                other => (other, None),
-            },
-            Tile::Vertical => match direction {
-                Direction::West | Direction::East => (Direction::North, Some(Direction::South)),
-                other => (other, None),
-            },
+            Tile::DiagonalWest if direction.is_vertical() => (direction.turn_left(), None),
+            Tile::DiagonalWest => (direction.turn_right(), None),
+            Tile::DiagonalEast if direction.is_vertical() => (direction.turn_right(), None),
+            Tile::DiagonalEast => (direction.turn_left(), None),
+            Tile::Horizontal if direction.is_vertical() => (Dir4::West, Some(Dir4::East)),
+            Tile::Vertical if !direction.is_vertical() => (Dir4::North, Some(Dir4::South)),
+            _ => (direction, None),
         }
     }
 }
 
 impl TryFrom<char> for Tile {
-    type Error = &'static str;
+    type Error = ParseError;
 
     fn try_from(value: char) -> Result<Self, Self::Error> {
         match value {
@@ -122,28 +115,14 @@ impl TryFrom<char> for Tile {
             '|' => Ok(Self::Vertical),
             '/' => Ok(Self::DiagonalEast),
             '-' => Ok(Self::Horizontal),
-            _ => Err("invalid character for tile"),
+            _ => Err(ParseError::new(format!("invalid tile '{value}'"))),
         }
     }
 }
 
-#[derive(Clone, Copy, PartialEq, Eq, Hash)]
-enum Direction {
-    North,
-    South,
-    West,
-    East,
-}
-impl Direction {
-    fn apply(&self, position: (usize, usize)) -> (i64, i64) {
-        let position = (position.0 as i64, position.1 as i64);
-        match self {
-            Direction::North => (position.0 - 1, position.1),
-            Direction::South => (position.0 + 1, position.1),
-            Direction::West => (position.0, position.1 - 1),
-            Direction::East => (position.0, position.1 + 1),
-        }
-    }
+fn step(position: (usize, usize), direction: Dir4) -> (i64, i64) {
+    let delta = direction.delta();
+    (position.0 as i64 + delta.y, position.1 as i64 + delta.x)
 }
 
 #[cfg(test)]
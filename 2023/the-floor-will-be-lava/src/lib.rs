@@ -0,0 +1,25 @@
+use contraption::Contraption;
+use std::fmt::Display;
+
+mod contraption;
+
+pub const DAY: aoc::Day = aoc::day!(2023, 16, Solution);
+
+pub struct Solution;
+
+impl aoc::Solver for Solution {
+    type Input = Contraption;
+    type Error = &'static str;
+
+    fn parse(input: &str) -> Result<Contraption, aoc::ParseError> {
+        input.parse()
+    }
+
+    fn part1(contraption: &Contraption) -> Result<impl Display, &'static str> {
+        Ok(contraption.energized())
+    }
+
+    fn part2(contraption: &Contraption) -> Result<impl Display, &'static str> {
+        Ok(contraption.max_energized())
+    }
+}
@@ -1,4 +1,5 @@
 use crate::{competition::Competition, scoreboard::Scoreboard};
+use aoc::ParseError;
 use std::str::FromStr;
 
 pub struct JoinScoreboard {
@@ -12,35 +13,33 @@ impl Scoreboard for JoinScoreboard {
 }
 
 impl FromStr for JoinScoreboard {
-    type Err = &'static str;
+    type Err = ParseError;
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
         let mut lines = s.lines();
-        let time_string: String = lines
-            .next()
-            .ok_or("missing time line")?
-            .strip_prefix("Time:")
-            .ok_or("missing time line prefix")?
-            .chars()
-            .filter(|char| !char.is_whitespace())
-            .collect();
-        let time = time_string.parse().map_err(|_| "failed to parse time")?;
-        let distance_string: String = lines
-            .next()
-            .ok_or("missing distance line")?
-            .strip_prefix("Distance:")
-            .ok_or("missing distance line prefix")?
-            .chars()
-            .filter(|char| !char.is_whitespace())
-            .collect();
-        let distance = distance_string
-            .parse()
-            .map_err(|_| "failed to parse distance")?;
+        let time = parse_joined(lines.next(), "Time:", "time")?;
+        let distance = parse_joined(lines.next(), "Distance:", "distance")?;
         let competition = [Competition::new(time, distance)];
         Ok(JoinScoreboard { competition })
     }
 }
 
+fn parse_joined(line: Option<&str>, prefix: &str, what: &str) -> Result<u64, ParseError> {
+    let line = line.ok_or_else(|| ParseError::missing(&format!("{what} line")))?;
+    let values = line
+        .strip_prefix(prefix)
+        .ok_or_else(|| ParseError::missing(&format!("{what} line prefix")).span(line))?;
+    let joined: String = values
+        .chars()
+        .filter(|char| !char.is_whitespace())
+        .collect();
+    joined.parse().map_err(|err| {
+        ParseError::invalid(what, &joined)
+            .span(values)
+            .with_cause(err)
+    })
+}
+
 #[cfg(test)]
 mod tests {
     use super::JoinScoreboard;
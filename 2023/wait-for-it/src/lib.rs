@@ -0,0 +1,34 @@
+use join_scoreboard::JoinScoreboard;
+use scoreboard::Scoreboard;
+use separate_scoreboard::SeparateScoreboard;
+use std::fmt::Display;
+
+mod competition;
+mod join_scoreboard;
+mod scoreboard;
+mod separate_scoreboard;
+
+pub const DAY: aoc::Day = aoc::day!(2023, 6, Solution);
+
+pub struct Solution;
+
+impl aoc::Solver for Solution {
+    type Input = (SeparateScoreboard, JoinScoreboard);
+    type Error = &'static str;
+
+    fn parse(input: &str) -> Result<(SeparateScoreboard, JoinScoreboard), aoc::ParseError> {
+        Ok((input.parse()?, input.parse()?))
+    }
+
+    fn part1(
+        scoreboards: &(SeparateScoreboard, JoinScoreboard),
+    ) -> Result<impl Display, &'static str> {
+        Ok(scoreboards.0.wining_move_count())
+    }
+
+    fn part2(
+        scoreboards: &(SeparateScoreboard, JoinScoreboard),
+    ) -> Result<impl Display, &'static str> {
+        Ok(scoreboards.1.wining_move_count())
+    }
+}
@@ -1,4 +1,5 @@
 use crate::{competition::Competition, scoreboard::Scoreboard};
+use aoc::ParseError;
 use std::str::FromStr;
 
 pub struct SeparateScoreboard {
@@ -12,30 +13,12 @@ impl Scoreboard for SeparateScoreboard {
 }
 
 impl FromStr for SeparateScoreboard {
-    type Err = &'static str;
+    type Err = ParseError;
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
         let mut lines = s.lines();
-        let times = lines
-            .next()
-            .ok_or("missing time line")?
-            .strip_prefix("Time:")
-            .ok_or("missing time line prefix")?
-            .split_whitespace()
-            .map(|time_string| time_string.parse::<u64>().map_err(|_| "invalid time value"))
-            .collect::<Result<Vec<_>, _>>()?;
-        let distances = lines
-            .next()
-            .ok_or("missing distance line")?
-            .strip_prefix("Distance:")
-            .ok_or("missing distance line prefix")?
-            .split_whitespace()
-            .map(|time_string| {
-                time_string
-                    .parse::<u64>()
-                    .map_err(|_| "invalid distance value")
-            })
-            .collect::<Result<Vec<_>, _>>()?;
+        let times = parse_separate(lines.next(), "Time:", "time")?;
+        let distances = parse_separate(lines.next(), "Distance:", "distance")?;
         if times.len() == distances.len() {
             let competitions = times
                 .into_iter()
@@ -44,11 +27,24 @@ impl FromStr for SeparateScoreboard {
                 .collect();
             Ok(SeparateScoreboard { competitions })
         } else {
-            Err("scoreboard lines must have equal length")
+            Err(ParseError::new("scoreboard lines must have equal length"))
         }
     }
 }
 
+fn parse_separate(line: Option<&str>, prefix: &str, what: &str) -> Result<Vec<u64>, ParseError> {
+    let line = line.ok_or_else(|| ParseError::missing(&format!("{what} line")))?;
+    line.strip_prefix(prefix)
+        .ok_or_else(|| ParseError::missing(&format!("{what} line prefix")).span(line))?
+        .split_whitespace()
+        .map(|value| {
+            value
+                .parse::<u64>()
+                .map_err(|err| ParseError::invalid(what, value).with_cause(err))
+        })
+        .collect()
+}
+
 #[cfg(test)]
 mod tests {
     use super::SeparateScoreboard;
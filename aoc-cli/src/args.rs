@@ -0,0 +1,365 @@
+use aoc::Part;
+use std::{error::Error, fmt::Display, num::NonZeroUsize, str::FromStr};
+
+#[derive(Debug, PartialEq, Eq)]
+pub enum Command {
+    List {
+        day: Option<(u16, u8)>,
+    },
+    Run {
+        year: u16,
+        day: u8,
+        part: Option<Part>,
+        input: Option<String>,
+        bench: Option<NonZeroUsize>,
+        format: Format,
+        seed: Option<u64>,
+    },
+    Verify {
+        day: Option<(u16, u8)>,
+        format: Format,
+        seed: Option<u64>,
+    },
+    New {
+        year: u16,
+        day: u8,
+        slug: String,
+    },
+}
+
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum Format {
+    #[default]
+    Text,
+    Json,
+}
+
+impl FromStr for Format {
+    type Err = ();
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "text" => Ok(Format::Text),
+            "json" => Ok(Format::Json),
+            _ => Err(()),
+        }
+    }
+}
+
+impl Command {
+    pub fn parse<I>(args: I) -> Result<Command, ArgsError>
+    where
+        I: IntoIterator<Item = String>,
+    {
+        let mut args = args.into_iter();
+        let command = args.next().ok_or(ArgsError::MissingCommand)?;
+        match command.as_str() {
+            "list" => parse_list(args),
+            "run" => parse_run(args),
+            "verify" => parse_verify(args),
+            "new" => parse_new(args),
+            _ => Err(ArgsError::UnknownCommand(command)),
+        }
+    }
+}
+
+fn parse_list(mut args: impl Iterator<Item = String>) -> Result<Command, ArgsError> {
+    let day = match args.next() {
+        Some(year) => Some((
+            parse_value(Some(year), "year")?,
+            parse_value(args.next(), "day")?,
+        )),
+        None => None,
+    };
+    match args.next() {
+        Some(arg) => Err(ArgsError::UnexpectedArgument(arg)),
+        None => Ok(Command::List { day }),
+    }
+}
+
+fn parse_run(mut args: impl Iterator<Item = String>) -> Result<Command, ArgsError> {
+    let year = parse_value(args.next(), "year")?;
+    let day = parse_value(args.next(), "day")?;
+    let mut part = None;
+    let mut input = None;
+    let mut bench = None;
+    let mut format = Format::default();
+    let mut seed = None;
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--part" => {
+                part = Some(parse_value(args.next(), "part")?);
+            }
+            "--input" => {
+                input = Some(args.next().ok_or(ArgsError::MissingValue("input"))?);
+            }
+            "--bench" => {
+                bench = Some(parse_value(args.next(), "bench")?);
+            }
+            "--format" => {
+                format = parse_value(args.next(), "format")?;
+            }
+            "--seed" => {
+                seed = Some(parse_value(args.next(), "seed")?);
+            }
+            _ => return Err(ArgsError::UnexpectedArgument(arg)),
+        }
+    }
+    Ok(Command::Run {
+        year,
+        day,
+        part,
+        input,
+        bench,
+        format,
+        seed,
+    })
+}
+
+fn parse_verify(mut args: impl Iterator<Item = String>) -> Result<Command, ArgsError> {
+    let day = match args.next() {
+        Some(arg) if arg == "--all" => None,
+        year => Some((parse_value(year, "year")?, parse_value(args.next(), "day")?)),
+    };
+    let mut format = Format::default();
+    let mut seed = None;
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--format" => {
+                format = parse_value(args.next(), "format")?;
+            }
+            "--seed" => {
+                seed = Some(parse_value(args.next(), "seed")?);
+            }
+            _ => return Err(ArgsError::UnexpectedArgument(arg)),
+        }
+    }
+    Ok(Command::Verify { day, format, seed })
+}
+
+fn parse_new(mut args: impl Iterator<Item = String>) -> Result<Command, ArgsError> {
+    let year = parse_value(args.next(), "year")?;
+    let day = parse_value(args.next(), "day")?;
+    let slug = args.next().ok_or(ArgsError::MissingValue("slug"))?;
+    match args.next() {
+        Some(arg) => Err(ArgsError::UnexpectedArgument(arg)),
+        None => Ok(Command::New { year, day, slug }),
+    }
+}
+
+fn parse_value<T: std::str::FromStr>(
+    arg: Option<String>,
+    name: &'static str,
+) -> Result<T, ArgsError> {
+    let arg = arg.ok_or(ArgsError::MissingValue(name))?;
+    arg.parse().map_err(|_| ArgsError::InvalidValue(name, arg))
+}
+
+#[derive(Debug, PartialEq, Eq)]
+pub enum ArgsError {
+    MissingCommand,
+    UnknownCommand(String),
+    UnexpectedArgument(String),
+    MissingValue(&'static str),
+    InvalidValue(&'static str, String),
+}
+
+impl Display for ArgsError {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self {
+            ArgsError::MissingCommand => {
+                write!(
+                    f,
+                    "missing command, expected `run`, `verify`, `list` or `new`"
+                )
+            }
+            ArgsError::UnknownCommand(command) => write!(f, "unknown command `{command}`"),
+            ArgsError::UnexpectedArgument(arg) => write!(f, "unexpected argument `{arg}`"),
+            ArgsError::MissingValue(name) => write!(f, "missing value for {name}"),
+            ArgsError::InvalidValue(name, value) => write!(f, "invalid {name} `{value}`"),
+        }
+    }
+}
+
+impl Error for ArgsError {}
+
+#[cfg(test)]
+mod tests {
+    use super::{ArgsError, Command, Format};
+    use aoc::Part;
+    use std::num::NonZeroUsize;
+
+    fn parse(args: &str) -> Result<Command, ArgsError> {
+        Command::parse(args.split_whitespace().map(str::to_string))
+    }
+
+    #[test]
+    fn parse_list() {
+        assert_eq!(parse("list"), Ok(Command::List { day: None }));
+        assert_eq!(
+            parse("list 2022 5"),
+            Ok(Command::List {
+                day: Some((2022, 5))
+            })
+        );
+    }
+
+    #[test]
+    fn parse_run() {
+        assert_eq!(
+            parse("run 2023 17"),
+            Ok(Command::Run {
+                year: 2023,
+                day: 17,
+                part: None,
+                input: None,
+                bench: None,
+                format: Format::Text,
+                seed: None
+            })
+        );
+    }
+
+    #[test]
+    fn parse_run_with_options() {
+        assert_eq!(
+            parse("run 2023 17 --part 2 --input path.txt"),
+            Ok(Command::Run {
+                year: 2023,
+                day: 17,
+                part: Some(Part::Two),
+                input: Some("path.txt".to_string()),
+                bench: None,
+                format: Format::Text,
+                seed: None
+            })
+        );
+    }
+
+    #[test]
+    fn parse_run_bench() {
+        assert_eq!(
+            parse("run 2023 21 --bench 10"),
+            Ok(Command::Run {
+                year: 2023,
+                day: 21,
+                part: None,
+                input: None,
+                bench: NonZeroUsize::new(10),
+                format: Format::Text,
+                seed: None
+            })
+        );
+    }
+
+    #[test]
+    fn parse_verify() {
+        assert_eq!(
+            parse("verify --all"),
+            Ok(Command::Verify {
+                day: None,
+                format: Format::Text,
+                seed: None
+            })
+        );
+        assert_eq!(
+            parse("verify 2015 6"),
+            Ok(Command::Verify {
+                day: Some((2015, 6)),
+                format: Format::Text,
+                seed: None
+            })
+        );
+    }
+
+    #[test]
+    fn parse_json_format() {
+        assert_eq!(
+            parse("run 2023 17 --format json"),
+            Ok(Command::Run {
+                year: 2023,
+                day: 17,
+                part: None,
+                input: None,
+                bench: None,
+                format: Format::Json,
+                seed: None
+            })
+        );
+        assert_eq!(
+            parse("verify --all --format json"),
+            Ok(Command::Verify {
+                day: None,
+                format: Format::Json,
+                seed: None
+            })
+        );
+        assert_eq!(
+            parse("verify --all --format yaml"),
+            Err(ArgsError::InvalidValue("format", "yaml".to_string()))
+        );
+    }
+
+    #[test]
+    fn parse_seed() {
+        assert_eq!(
+            parse("verify 2023 25 --seed 42"),
+            Ok(Command::Verify {
+                day: Some((2023, 25)),
+                format: Format::Text,
+                seed: Some(42)
+            })
+        );
+        assert_eq!(
+            parse("run 2023 25 --seed x"),
+            Err(ArgsError::InvalidValue("seed", "x".to_string()))
+        );
+    }
+
+    #[test]
+    fn parse_new() {
+        assert_eq!(
+            parse("new 2023 6 wait-for-it"),
+            Ok(Command::New {
+                year: 2023,
+                day: 6,
+                slug: "wait-for-it".to_string()
+            })
+        );
+        assert_eq!(parse("new 2023 6"), Err(ArgsError::MissingValue("slug")));
+    }
+
+    #[test]
+    fn fail_on_missing_verify_target() {
+        assert_eq!(parse("verify"), Err(ArgsError::MissingValue("year")));
+    }
+
+    #[test]
+    fn fail_on_invalid_part() {
+        assert_eq!(
+            parse("run 2023 17 --part 3"),
+            Err(ArgsError::InvalidValue("part", "3".to_string()))
+        );
+    }
+
+    #[test]
+    fn fail_on_empty_bench() {
+        assert_eq!(
+            parse("run 2023 21 --bench 0"),
+            Err(ArgsError::InvalidValue("bench", "0".to_string()))
+        );
+    }
+
+    #[test]
+    fn fail_on_missing_day() {
+        assert_eq!(parse("run 2023"), Err(ArgsError::MissingValue("day")));
+    }
+
+    #[test]
+    fn fail_on_unknown_command() {
+        assert_eq!(
+            parse("walk"),
+            Err(ArgsError::UnknownCommand("walk".to_string()))
+        );
+    }
+}
@@ -0,0 +1,57 @@
+use std::{fmt::Display, time::Duration};
+
+pub struct Stats {
+    min: Duration,
+    median: Duration,
+    mean: Duration,
+}
+
+impl Stats {
+    pub fn new(mut samples: Vec<Duration>) -> Option<Stats> {
+        samples.sort();
+        let min = *samples.first()?;
+        let median = match samples.len() % 2 {
+            0 => (samples[samples.len() / 2 - 1] + samples[samples.len() / 2]) / 2,
+            _ => samples[samples.len() / 2],
+        };
+        let mean = samples.iter().sum::<Duration>() / u32::try_from(samples.len()).ok()?;
+        Some(Stats { min, median, mean })
+    }
+}
+
+impl Display for Stats {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        write!(
+            f,
+            "min {:?}, median {:?}, mean {:?}",
+            self.min, self.median, self.mean
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Stats;
+    use std::time::Duration;
+
+    #[test]
+    fn odd_samples() {
+        let samples = [3, 1, 8].map(Duration::from_millis).to_vec();
+        let stats = Stats::new(samples).unwrap();
+        assert_eq!(stats.min, Duration::from_millis(1));
+        assert_eq!(stats.median, Duration::from_millis(3));
+        assert_eq!(stats.mean, Duration::from_millis(4));
+    }
+
+    #[test]
+    fn even_samples() {
+        let samples = [4, 1, 2, 9].map(Duration::from_millis).to_vec();
+        let stats = Stats::new(samples).unwrap();
+        assert_eq!(stats.median, Duration::from_millis(3));
+    }
+
+    #[test]
+    fn no_samples() {
+        assert!(Stats::new(Vec::new()).is_none());
+    }
+}
@@ -0,0 +1,132 @@
+use aoc::{Answer, Day, Expected, Status};
+use std::{fmt::Display, time::Duration};
+
+pub struct Record<'a> {
+    pub day: &'a Day,
+    pub input: &'a str,
+    pub parse: Duration,
+    pub answer: &'a Answer,
+    pub expected: &'a Expected,
+}
+
+impl Record<'_> {
+    fn status(&self) -> &'static str {
+        if self.answer.result.is_err() {
+            return "error";
+        }
+        match self.expected.check(self.answer) {
+            Status::Pass => "pass",
+            Status::Fail(_) => "fail",
+            Status::Unknown => "unknown",
+        }
+    }
+}
+
+impl Display for Record<'_> {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        write!(
+            f,
+            "{{\"year\":{},\"day\":{},\"input\":{},\"part\":{},",
+            self.day.year,
+            self.day.day,
+            string(self.input),
+            self.answer.part
+        )?;
+        match &self.answer.result {
+            Ok(answer) => write!(f, "\"answer\":{},", string(answer))?,
+            Err(err) => write!(f, "\"answer\":null,\"error\":{},", string(&err.to_string()))?,
+        }
+        write!(
+            f,
+            "\"parse_ms\":{},\"solve_ms\":{},\"status\":\"{}\"}}",
+            millis(self.parse),
+            millis(self.answer.elapsed),
+            self.status()
+        )
+    }
+}
+
+fn millis(duration: Duration) -> String {
+    format!("{:.3}", duration.as_secs_f64() * 1000.0)
+}
+
+fn string(s: &str) -> String {
+    let mut out = String::with_capacity(s.len() + 2);
+    out.push('"');
+    for c in s.chars() {
+        match c {
+            '"' => out.push_str("\\\""),
+            '\\' => out.push_str("\\\\"),
+            '\n' => out.push_str("\\n"),
+            '\r' => out.push_str("\\r"),
+            '\t' => out.push_str("\\t"),
+            c if u32::from(c) < 0x20 => out.push_str(&format!("\\u{:04x}", u32::from(c))),
+            c => out.push(c),
+        }
+    }
+    out.push('"');
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{string, Record};
+    use aoc::{Answer, Answers, Day, Failure, Part, Report};
+    use std::time::Duration;
+
+    fn run(_input: &str, _parts: &[Part]) -> Report {
+        unreachable!()
+    }
+
+    const DAY: Day = Day {
+        year: 2022,
+        day: 10,
+        name: "cathode-ray_tube",
+        assets: "assets",
+        run,
+    };
+
+    fn record(answer: &Answer, answers: &str) -> String {
+        let answers: Answers = answers.parse().unwrap();
+        Record {
+            day: &DAY,
+            input: "input",
+            parse: Duration::from_micros(1500),
+            answer,
+            expected: answers.get("input").unwrap(),
+        }
+        .to_string()
+    }
+
+    #[test]
+    fn escape_strings() {
+        assert_eq!(string("a\"b\\c"), r#""a\"b\\c""#);
+        assert_eq!(string("\n#.\u{1}"), r#""\n#.\u0001""#);
+    }
+
+    #[test]
+    fn passing_record() {
+        let answer = Answer {
+            part: Part::One,
+            result: Ok("13140".to_string()),
+            elapsed: Duration::from_micros(250),
+        };
+        assert_eq!(
+            record(&answer, "[input]\npart1 = 13140"),
+            r#"{"year":2022,"day":10,"input":"input","part":1,"answer":"13140","parse_ms":1.500,"solve_ms":0.250,"status":"pass"}"#
+        );
+    }
+
+    #[test]
+    fn failed_record() {
+        let answer = Answer {
+            part: Part::Two,
+            result: Err(Failure::Solve("no path".to_string())),
+            elapsed: Duration::ZERO,
+        };
+        assert_eq!(
+            record(&answer, "[input]"),
+            r#"{"year":2022,"day":10,"input":"input","part":2,"answer":null,"error":"failed to solve: no path","parse_ms":1.500,"solve_ms":0.000,"status":"error"}"#
+        );
+    }
+}
@@ -0,0 +1,225 @@
+use aoc::{is_stdin, read_input, Day, Expected, Part, Report, Status};
+use args::{Command, Format};
+use bench::Stats;
+use json::Record;
+use scaffold::Scaffold;
+use std::{io::Write, num::NonZeroUsize, path::Path, process::ExitCode};
+
+const DEFAULT_INPUT: &str = "input";
+
+mod args;
+mod bench;
+mod json;
+mod registry;
+mod scaffold;
+
+fn main() -> ExitCode {
+    match run() {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(err) => {
+            eprintln!("error: {err}");
+            ExitCode::FAILURE
+        }
+    }
+}
+
+fn run() -> Result<(), String> {
+    let command = Command::parse(std::env::args().skip(1)).map_err(|err| err.to_string())?;
+    match command {
+        Command::List { day: None } => {
+            for day in registry::DAYS {
+                println!("{} {:>2} {}", day.year, day.day, day.name);
+            }
+        }
+        Command::List {
+            day: Some((year, day)),
+        } => {
+            let day = find(year, day)?;
+            for name in day.inputs()? {
+                if Expected::load(&day.input(&name))? == Expected::default() {
+                    println!("{name}");
+                } else {
+                    println!("{name} (expected answers)");
+                }
+            }
+        }
+        Command::Run {
+            year,
+            day,
+            part,
+            input,
+            bench,
+            format,
+            seed,
+        } => {
+            set_seed(seed)?;
+            let day = find(year, day)?;
+            let name = input.as_deref().unwrap_or(DEFAULT_INPUT);
+            let path = day.input(name);
+            let input = read_input(&path)?;
+            let parts = part.map(|part| vec![part]).unwrap_or(Part::ALL.to_vec());
+            match bench {
+                Some(_) if format == Format::Json => {
+                    return Err("`--format json` is not supported with `--bench`".to_string())
+                }
+                Some(runs) => run_bench(day, &input, &parts, runs)?,
+                None => {
+                    let expected = if is_stdin(&path) {
+                        Expected::default()
+                    } else {
+                        Expected::load(&path)?
+                    };
+                    let report = (day.run)(&input, &parts);
+                    match format {
+                        Format::Text => {
+                            println!("Parse: {:?}", report.parse);
+                            for answer in report.answers.iter() {
+                                match answer.result {
+                                    Ok(_) => println!("{answer} ({:?})", answer.elapsed),
+                                    Err(_) => println!("{answer}"),
+                                }
+                            }
+                            if expected != Expected::default() {
+                                println!("Verify: {}", describe(&report, &expected));
+                            }
+                        }
+                        Format::Json => print_records(day, name, &report, &expected),
+                    }
+                    if !passed(&report, &expected) {
+                        return Err(format!("{} day {} failed", day.year, day.day));
+                    }
+                }
+            }
+        }
+        Command::Verify { day, format, seed } => {
+            set_seed(seed)?;
+            let days = match day {
+                Some((year, day)) => vec![find(year, day)?],
+                None => registry::DAYS.iter().collect(),
+            };
+            let mut failed = 0;
+            for day in days {
+                if !verify(day, format)? {
+                    failed += 1;
+                }
+            }
+            if failed > 0 {
+                return Err(format!("{failed} puzzles failed verification"));
+            }
+        }
+        Command::New { year, day, slug } => {
+            let root = Path::new(env!("CARGO_MANIFEST_DIR"))
+                .parent()
+                .ok_or("could not locate the workspace root")?;
+            let dir = Scaffold::new(year, day, &slug)?.create(root, registry::DAYS)?;
+            println!("Created {}", dir.display());
+            println!(
+                "Add the puzzle input to assets/input.txt and the example to assets/example.txt"
+            );
+        }
+    }
+    Ok(())
+}
+
+fn set_seed(seed: Option<u64>) -> Result<(), String> {
+    match seed {
+        Some(seed) if !aoc::rand::set_seed(seed) => Err(format!(
+            "could not set the random seed to {seed}, it is already set"
+        )),
+        _ => Ok(()),
+    }
+}
+
+fn find(year: u16, day: u8) -> Result<&'static Day, String> {
+    registry::find(year, day).ok_or_else(|| format!("no puzzle registered for {year} day {day}"))
+}
+
+fn verify(day: &Day, format: Format) -> Result<bool, String> {
+    let inputs = day.inputs()?;
+    if format == Format::Text && !inputs.iter().any(|name| name == DEFAULT_INPUT) {
+        println!("{} {:>2} {}: SKIP (no input)", day.year, day.day, day.name);
+    }
+    let mut ok = true;
+    for name in inputs {
+        let path = day.input(&name);
+        let expected = Expected::load(&path)?;
+        if name != DEFAULT_INPUT && expected == Expected::default() {
+            continue;
+        }
+        if format == Format::Json {
+            let report = (day.run)(&read_input(&path)?, &Part::ALL);
+            print_records(day, &name, &report, &expected);
+            ok &= passed(&report, &expected);
+            continue;
+        }
+        if name == DEFAULT_INPUT {
+            print!("{} {:>2} {}: ", day.year, day.day, day.name);
+        } else {
+            print!("{} {:>2} {} ({name}): ", day.year, day.day, day.name);
+        }
+        std::io::stdout().flush().map_err(|err| err.to_string())?;
+        let report = (day.run)(&read_input(&path)?, &Part::ALL);
+        println!("{}", describe(&report, &expected));
+        ok &= passed(&report, &expected);
+    }
+    Ok(ok)
+}
+
+fn print_records(day: &Day, input: &str, report: &Report, expected: &Expected) {
+    for answer in report.answers.iter() {
+        let record = Record {
+            day,
+            input,
+            parse: report.parse,
+            answer,
+            expected,
+        };
+        println!("{record}");
+    }
+}
+
+fn describe(report: &Report, expected: &Expected) -> String {
+    report
+        .answers
+        .iter()
+        .map(|answer| match (&answer.result, expected.check(answer)) {
+            (Err(err), Status::Unknown) => format!("part {} {err}", answer.part),
+            (_, status) => format!("part {} {status}", answer.part),
+        })
+        .collect::<Vec<_>>()
+        .join(", ")
+}
+
+fn passed(report: &Report, expected: &Expected) -> bool {
+    report.is_ok()
+        && report
+            .answers
+            .iter()
+            .all(|answer| !matches!(expected.check(answer), Status::Fail(_)))
+}
+
+fn run_bench(day: &Day, input: &str, parts: &[Part], runs: NonZeroUsize) -> Result<(), String> {
+    let mut reports = Vec::new();
+    for _ in 0..runs.get() {
+        let report = (day.run)(input, parts);
+        if !report.is_ok() {
+            println!("{report}");
+            return Err(format!("{} day {} failed", day.year, day.day));
+        }
+        reports.push(report);
+    }
+    let stats = |samples: Vec<_>| Stats::new(samples).ok_or("bench needs at least one run");
+    println!("{} {:>2} {} ({runs} runs)", day.year, day.day, day.name);
+    println!(
+        "Parse: {}",
+        stats(reports.iter().map(|report| report.parse).collect())?
+    );
+    for (idx, part) in parts.iter().enumerate() {
+        let samples = reports
+            .iter()
+            .map(|report: &Report| report.answers[idx].elapsed)
+            .collect();
+        println!("Part {part}: {}", stats(samples)?);
+    }
+    Ok(())
+}
@@ -0,0 +1,83 @@
+use aoc::Day;
+
+pub const DAYS: &[Day] = &[
+    not_quite_lisp::DAY,
+    i_was_told_there_would_be_no_math::DAY,
+    perfectly_spherical_houses_in_a_vacuum::DAY,
+    the_ideal_stocking_stuffer::DAY,
+    doesnt_he_have_intern_elves_for_this::DAY,
+    problably_a_fire_hazard::DAY,
+    some_assembly_required::DAY,
+    matchsticks::DAY,
+    all_in_a_single_night::DAY,
+    elves_look_elves_say::DAY,
+    corporate_policy::DAY,
+    jsabacusframework_io::DAY,
+    knights_of_the_dinner_table::DAY,
+    reindeer_olympics::DAY,
+    science_for_hungry_people::DAY,
+    aunt_sue::DAY,
+    no_such_thing_as_too_much::DAY,
+    like_a_gif_for_your_yard::DAY,
+    medicine_for_rudolph::DAY,
+    infinite_elves_and_infinite_houses::DAY,
+    rpg_simulator_20xx::DAY,
+    wizard_simulator_20xx::DAY,
+    opening_the_turing_lock::DAY,
+    it_hangs_in_the_balance::DAY,
+    let_it_snow::DAY,
+    calorie_counting::DAY,
+    rock_paper_scissors::DAY,
+    rucksack_reorganization::DAY,
+    camp_cleanup::DAY,
+    suply_stacks::DAY,
+    no_space_left_on_device::DAY,
+    treetop_tree_house::DAY,
+    rope_bridge::DAY,
+    cathode_ray_tube::DAY,
+    monkey_in_the_middle::DAY,
+    hill_climbing_algorithm::DAY,
+    distress_signal::DAY,
+    regolith_reservoir::DAY,
+    beacon_exclusion_zone::DAY,
+    proboscidea_volcanium::DAY,
+    pytoclastic_flow::DAY,
+    boiling_boulder::DAY,
+    not_enough_minerals::DAY,
+    grove_positioning_system::DAY,
+    monkey_math::DAY,
+    monkey_map::DAY,
+    unstable_diffusion::DAY,
+    blizzard_basin::DAY,
+    full_of_hot_air::DAY,
+    trebuchet::DAY,
+    cube_conundrum::DAY,
+    gear_ratios::DAY,
+    scratchcards::DAY,
+    if_you_give_a_seed_a_fertilizer::DAY,
+    wait_for_it::DAY,
+    camel_cards::DAY,
+    haunted_wasteland::DAY,
+    mirage_maintenance::DAY,
+    pipe_maze::DAY,
+    cosmic_expansion::DAY,
+    hot_springs::DAY,
+    point_of_incidence::DAY,
+    parabolic_reflector_dish::DAY,
+    lens_library::DAY,
+    the_floor_will_be_lava::DAY,
+    clumsy_crucible::DAY,
+    lavaduct_lagoon::DAY,
+    aplenty::DAY,
+    pulse_propagation::DAY,
+    step_counter::DAY,
+    sand_slabs::DAY,
+    a_long_walk::DAY,
+    never_tell_me_the_odds::DAY,
+    snowverload::DAY,
+];
+
+pub fn find(year: u16, day: u8) -> Option<&'static Day> {
+    DAYS.iter()
+        .find(|entry| entry.year == year && entry.day == day)
+}
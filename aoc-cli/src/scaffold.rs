@@ -0,0 +1,271 @@
+use aoc::Day;
+use std::path::{Path, PathBuf};
+
+const TEMPLATES: [(&str, &str); 7] = [
+    ("Cargo.toml", include_str!("../templates/Cargo.toml.tmpl")),
+    ("README.md", include_str!("../templates/README.md.tmpl")),
+    (
+        "assets/answers.toml",
+        include_str!("../templates/answers.toml.tmpl"),
+    ),
+    ("assets/example.txt", ""),
+    ("src/main.rs", include_str!("../templates/main.rs.tmpl")),
+    ("src/lib.rs", include_str!("../templates/lib.rs.tmpl")),
+    ("src/puzzle.rs", include_str!("../templates/puzzle.rs.tmpl")),
+];
+
+#[derive(Debug)]
+pub struct Scaffold {
+    year: u16,
+    day: u8,
+    slug: String,
+}
+
+impl Scaffold {
+    pub fn new(year: u16, day: u8, slug: &str) -> Result<Scaffold, String> {
+        if !(1..=25).contains(&day) {
+            return Err(format!("invalid day {day}, expected 1 to 25"));
+        }
+        let valid = slug.starts_with(|c: char| c.is_ascii_lowercase())
+            && slug
+                .chars()
+                .all(|c| c.is_ascii_lowercase() || c.is_ascii_digit() || c == '-');
+        if !valid {
+            return Err(format!(
+                "invalid slug `{slug}`, expected lowercase words separated by '-'"
+            ));
+        }
+        Ok(Scaffold {
+            year,
+            day,
+            slug: slug.to_string(),
+        })
+    }
+
+    pub fn member(&self) -> String {
+        format!("{}/{}", self.year, self.slug)
+    }
+
+    fn lib(&self) -> String {
+        self.slug.replace('-', "_")
+    }
+
+    fn title(&self) -> String {
+        self.slug
+            .split('-')
+            .map(|word| {
+                let mut chars = word.chars();
+                chars
+                    .next()
+                    .map(|first| first.to_ascii_uppercase().to_string() + chars.as_str())
+                    .unwrap_or_default()
+            })
+            .collect::<Vec<_>>()
+            .join(" ")
+    }
+
+    fn render(&self, template: &str) -> String {
+        template
+            .replace("{{year}}", &self.year.to_string())
+            .replace("{{day}}", &self.day.to_string())
+            .replace("{{crate}}", &self.slug)
+            .replace("{{lib}}", &self.lib())
+            .replace("{{title}}", &self.title())
+    }
+
+    pub fn files(&self) -> Vec<(PathBuf, String)> {
+        TEMPLATES
+            .iter()
+            .map(|(path, template)| (PathBuf::from(path), self.render(template)))
+            .collect()
+    }
+
+    pub fn create(&self, root: &Path, days: &[Day]) -> Result<PathBuf, String> {
+        if days
+            .iter()
+            .any(|day| day.year == self.year && day.day == self.day)
+        {
+            return Err(format!(
+                "{} day {} is already registered",
+                self.year, self.day
+            ));
+        }
+        if days.iter().any(|day| day.name == self.slug) {
+            return Err(format!("a crate named `{}` already exists", self.slug));
+        }
+        let dir = root.join(self.member());
+        if dir.exists() {
+            return Err(format!("`{}` already exists", dir.display()));
+        }
+
+        let members = root.join("Cargo.toml");
+        let dependencies = root.join("aoc-cli/Cargo.toml");
+        let registry = root.join("aoc-cli/src/registry.rs");
+        let (members_text, dependencies_text, registry_text) =
+            (read(&members)?, read(&dependencies)?, read(&registry)?);
+        let previous = days
+            .iter()
+            .filter(|day| (day.year, day.day) < (self.year, self.day))
+            .max_by_key(|day| (day.year, day.day));
+        let (member_anchor, dependency_anchor, registry_anchor) = match previous {
+            Some(previous) => {
+                let dependency = format!("{} = ", previous.name);
+                let member = member_of(&dependencies_text, &dependency)?;
+                (
+                    format!("\"{member}\","),
+                    dependency,
+                    format!("{}::DAY,", previous.name.replace('-', "_")),
+                )
+            }
+            None => (
+                "\"aoc-cli\",".to_string(),
+                "aoc = ".to_string(),
+                "pub const DAYS".to_string(),
+            ),
+        };
+        let members_text = insert_after(
+            &members_text,
+            &member_anchor,
+            &format!("  \"{}\",", self.member()),
+        )?;
+        let dependencies_text = insert_after(
+            &dependencies_text,
+            &dependency_anchor,
+            &format!("{} = {{ path = \"../{}\" }}", self.slug, self.member()),
+        )?;
+        let registry_text = insert_after(
+            &registry_text,
+            &registry_anchor,
+            &format!("    {}::DAY,", self.lib()),
+        )?;
+
+        for (path, content) in self.files() {
+            let path = dir.join(path);
+            if let Some(parent) = path.parent() {
+                std::fs::create_dir_all(parent)
+                    .map_err(|err| format!("could not create `{}`: {err}", parent.display()))?;
+            }
+            write(&path, &content)?;
+        }
+        write(&members, &members_text)?;
+        write(&dependencies, &dependencies_text)?;
+        write(&registry, &registry_text)?;
+        Ok(dir)
+    }
+}
+
+fn read(path: &Path) -> Result<String, String> {
+    std::fs::read_to_string(path)
+        .map_err(|err| format!("could not read `{}`: {err}", path.display()))
+}
+
+fn write(path: &Path, content: &str) -> Result<(), String> {
+    std::fs::write(path, content)
+        .map_err(|err| format!("could not write `{}`: {err}", path.display()))
+}
+
+fn member_of(dependencies: &str, dependency: &str) -> Result<String, String> {
+    dependencies
+        .lines()
+        .find(|line| line.starts_with(dependency))
+        .and_then(|line| line.split_once("path = \"../"))
+        .and_then(|(_, path)| path.split_once('"'))
+        .map(|(path, _)| path.to_string())
+        .ok_or_else(|| {
+            format!(
+                "could not find `{}` in aoc-cli/Cargo.toml",
+                dependency.trim_end_matches(" = ")
+            )
+        })
+}
+
+fn insert_after(text: &str, anchor: &str, line: &str) -> Result<String, String> {
+    let mut lines: Vec<&str> = text.lines().collect();
+    let idx = lines
+        .iter()
+        .position(|existing| existing.trim_start().starts_with(anchor))
+        .ok_or_else(|| format!("could not find `{anchor}`"))?;
+    lines.insert(idx + 1, line);
+    let mut text = lines.join("\n");
+    text.push('\n');
+    Ok(text)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{insert_after, member_of, write, Scaffold};
+    use std::path::Path;
+    use std::process::Command;
+
+    #[test]
+    fn reject_invalid_slug() {
+        assert!(Scaffold::new(2023, 1, "Trebuchet").is_err());
+        assert!(Scaffold::new(2023, 1, "1-trebuchet").is_err());
+        assert!(Scaffold::new(2023, 1, "trebuchet?!").is_err());
+        assert!(Scaffold::new(2023, 26, "trebuchet").is_err());
+    }
+
+    #[test]
+    fn render_templates() {
+        let scaffold = Scaffold::new(2023, 6, "wait-for-it").unwrap();
+        let files = scaffold.files();
+        let file = |path: &str| {
+            files
+                .iter()
+                .find(|(file, _)| file == Path::new(path))
+                .map(|(_, content)| content.as_str())
+                .unwrap()
+        };
+        assert_eq!(file("README.md"), "# Day 6: Wait For It\n");
+        assert!(file("Cargo.toml").contains("name = \"wait-for-it\""));
+        assert!(file("src/main.rs").contains("aoc::aoc_main::<wait_for_it::Solution>"));
+        assert!(file("src/lib.rs").contains("aoc::day!(2023, 6, Solution)"));
+    }
+
+    #[test]
+    fn generated_crate_passes_its_tests() {
+        let scaffold = Scaffold::new(2023, 6, "wait-for-it").unwrap();
+        let root = std::env::temp_dir().join(format!("aoc-scaffold-{}", std::process::id()));
+        let aoc = Path::new(env!("CARGO_MANIFEST_DIR")).join("../aoc");
+        let manifest = format!(
+            "[workspace]\nresolver = \"2\"\nmembers = [\"{}\"]\n\n[workspace.dependencies]\naoc = {{ path = {:?} }}\n",
+            scaffold.member(),
+            aoc.display().to_string()
+        );
+        std::fs::create_dir_all(&root).unwrap();
+        write(&root.join("Cargo.toml"), &manifest).unwrap();
+        for (path, content) in scaffold.files() {
+            let path = root.join(scaffold.member()).join(path);
+            std::fs::create_dir_all(path.parent().unwrap()).unwrap();
+            write(&path, &content).unwrap();
+        }
+
+        let status = Command::new(std::env::var_os("CARGO").unwrap_or("cargo".into()))
+            .args(["test", "--offline", "--quiet"])
+            .current_dir(&root)
+            .env("CARGO_TARGET_DIR", root.join("target"))
+            .status();
+        std::fs::remove_dir_all(&root).unwrap();
+        assert!(status.unwrap().success());
+    }
+
+    #[test]
+    fn insert_lines() {
+        let text = "members = [\n  \"aoc\",\n  \"2023/trebuchet\",\n]\n";
+        assert_eq!(
+            insert_after(text, "\"aoc\",", "  \"2015/not-quite-lisp\",").unwrap(),
+            "members = [\n  \"aoc\",\n  \"2015/not-quite-lisp\",\n  \"2023/trebuchet\",\n]\n"
+        );
+        assert!(insert_after(text, "\"aoc-cli\",", "").is_err());
+    }
+
+    #[test]
+    fn find_member() {
+        let dependencies =
+            "aoc = { workspace = true }\ncalorie_counting = { path = \"../2022/01\" }\n";
+        assert_eq!(
+            member_of(dependencies, "calorie_counting = "),
+            Ok("2022/01".to_string())
+        );
+    }
+}
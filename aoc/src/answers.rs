@@ -0,0 +1,188 @@
+use crate::{Answer, ParseError, Part};
+use std::{
+    collections::BTreeMap,
+    fmt::{Display, Formatter},
+    path::Path,
+    str::FromStr,
+};
+
+#[derive(Debug, Default, PartialEq, Eq)]
+pub struct Answers {
+    inputs: BTreeMap<String, Expected>,
+}
+
+impl Answers {
+    pub fn get(&self, input: &str) -> Option<&Expected> {
+        self.inputs.get(input)
+    }
+}
+
+impl FromStr for Answers {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut inputs: BTreeMap<String, Expected> = BTreeMap::new();
+        let mut section = None;
+        for line in s.lines() {
+            let line = line.trim();
+            if line.is_empty() || line.starts_with('#') {
+                continue;
+            }
+            if let Some(name) = line.strip_prefix('[') {
+                let name = name
+                    .strip_suffix(']')
+                    .ok_or_else(|| ParseError::missing("closing bracket").span(line))?
+                    .trim();
+                inputs.entry(name.to_string()).or_default();
+                section = Some(name.to_string());
+                continue;
+            }
+            let name = section
+                .as_ref()
+                .ok_or_else(|| ParseError::new("answer outside of an input section").span(line))?;
+            let (key, value) = line
+                .split_once('=')
+                .ok_or_else(|| ParseError::missing("'='").span(line))?;
+            let (key, value) = (key.trim(), parse_value(value.trim())?);
+            let expected = inputs.get_mut(name).unwrap();
+            match key {
+                "part1" => expected.part1 = Some(value),
+                "part2" => expected.part2 = Some(value),
+                _ => return Err(ParseError::invalid("key", key)),
+            }
+        }
+        Ok(Answers { inputs })
+    }
+}
+
+fn parse_value(value: &str) -> Result<String, ParseError> {
+    match value.strip_prefix('"') {
+        Some(quoted) => quoted
+            .strip_suffix('"')
+            .map(str::to_string)
+            .ok_or_else(|| ParseError::missing("closing quote").span(value)),
+        None if !value.is_empty() && value.chars().all(|c| c.is_ascii_digit() || c == '-') => {
+            Ok(value.to_string())
+        }
+        None => Err(ParseError::invalid("value", value)),
+    }
+}
+
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct Expected {
+    part1: Option<String>,
+    part2: Option<String>,
+}
+
+impl Expected {
+    pub fn load(input: &Path) -> Result<Expected, String> {
+        let path = input.with_file_name("answers.toml");
+        let s = match std::fs::read_to_string(&path) {
+            Ok(s) => s,
+            Err(err) if err.kind() == std::io::ErrorKind::NotFound => {
+                return Ok(Expected::default())
+            }
+            Err(err) => return Err(format!("could not read `{}`: {err}", path.display())),
+        };
+        let answers = s
+            .parse::<Answers>()
+            .map_err(|err| format!("invalid `{}`: {}", path.display(), err.locate(&s)))?;
+        let name = input
+            .file_stem()
+            .and_then(|stem| stem.to_str())
+            .unwrap_or_default();
+        Ok(answers.get(name).cloned().unwrap_or_default())
+    }
+
+    pub fn get(&self, part: Part) -> Option<&str> {
+        match part {
+            Part::One => self.part1.as_deref(),
+            Part::Two => self.part2.as_deref(),
+        }
+    }
+
+    pub fn check(&self, answer: &Answer) -> Status {
+        match (self.get(answer.part), &answer.result) {
+            (None, _) => Status::Unknown,
+            (Some(expected), Ok(result)) if expected == result => Status::Pass,
+            (Some(expected), _) => Status::Fail(expected.to_string()),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Status {
+    Pass,
+    Fail(String),
+    Unknown,
+}
+
+impl Display for Status {
+    fn fmt(&self, f: &mut Formatter) -> std::fmt::Result {
+        match self {
+            Status::Pass => write!(f, "PASS"),
+            Status::Fail(expected) => write!(f, "FAIL (expected {expected})"),
+            Status::Unknown => write!(f, "UNKNOWN"),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Answers, Status};
+    use crate::{Answer, Failure, Part};
+    use std::time::Duration;
+
+    const ANSWERS: &str =
+        "# answers\n[input]\npart1 = \"280\"\npart2 = 1797\n\n[example]\npart1 = \"-3\"\n";
+
+    fn answer(part: Part, result: Result<&str, Failure>) -> Answer {
+        Answer {
+            part,
+            result: result.map(str::to_string),
+            elapsed: Duration::ZERO,
+        }
+    }
+
+    #[test]
+    fn parse_sections() {
+        let answers: Answers = ANSWERS.parse().unwrap();
+        let input = answers.get("input").unwrap();
+        assert_eq!(input.get(Part::One), Some("280"));
+        assert_eq!(input.get(Part::Two), Some("1797"));
+        let example = answers.get("example").unwrap();
+        assert_eq!(example.get(Part::One), Some("-3"));
+        assert_eq!(example.get(Part::Two), None);
+        assert!(answers.get("example2").is_none());
+    }
+
+    #[test]
+    fn fail_on_unknown_key() {
+        let err = "[input]\npart3 = 1".parse::<Answers>().unwrap_err();
+        assert_eq!(err.to_string(), "invalid key 'part3'");
+    }
+
+    #[test]
+    fn fail_outside_section() {
+        assert!("part1 = 1".parse::<Answers>().is_err());
+    }
+
+    #[test]
+    fn check_answers() {
+        let answers: Answers = ANSWERS.parse().unwrap();
+        let expected = answers.get("example").unwrap();
+        assert_eq!(expected.check(&answer(Part::One, Ok("-3"))), Status::Pass);
+        assert_eq!(
+            expected.check(&answer(Part::One, Ok("3"))),
+            Status::Fail("-3".to_string())
+        );
+        assert_eq!(
+            expected.check(&answer(
+                Part::One,
+                Err(Failure::Solve("no path".to_string()))
+            )),
+            Status::Fail("-3".to_string())
+        );
+        assert_eq!(expected.check(&answer(Part::Two, Ok("3"))), Status::Unknown);
+    }
+}
@@ -0,0 +1,196 @@
+use std::{collections::HashMap, hash::Hash};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Cycle {
+    pub start: usize,
+    pub length: usize,
+}
+
+impl Cycle {
+    pub fn index(&self, n: usize) -> usize {
+        if n < self.start {
+            n
+        } else {
+            self.start + (n - self.start) % self.length
+        }
+    }
+
+    pub fn extrapolate(&self, n: usize, mut value: impl FnMut(usize) -> i64) -> i64 {
+        if n < self.start + self.length {
+            return value(n);
+        }
+        let cycles = ((n - self.start) / self.length) as i64;
+        let gain = value(self.start + self.length) - value(self.start);
+        value(self.index(n)) + cycles * gain
+    }
+}
+
+pub fn floyd<S: PartialEq>(initial: S, mut f: impl FnMut(&S) -> S) -> Cycle {
+    let mut tortoise = f(&initial);
+    let mut hare = f(&tortoise);
+    while tortoise != hare {
+        tortoise = f(&tortoise);
+        let next = f(&hare);
+        hare = f(&next);
+    }
+    let mut start = 0;
+    tortoise = initial;
+    while tortoise != hare {
+        tortoise = f(&tortoise);
+        hare = f(&hare);
+        start += 1;
+    }
+    let mut length = 1;
+    hare = f(&tortoise);
+    while tortoise != hare {
+        hare = f(&hare);
+        length += 1;
+    }
+    Cycle { start, length }
+}
+
+pub fn brent<S: Clone + PartialEq>(initial: S, mut f: impl FnMut(&S) -> S) -> Cycle {
+    let (mut power, mut length) = (1, 1);
+    let mut tortoise = initial.clone();
+    let mut hare = f(&initial);
+    while tortoise != hare {
+        if power == length {
+            tortoise = hare.clone();
+            power *= 2;
+            length = 0;
+        }
+        hare = f(&hare);
+        length += 1;
+    }
+    tortoise = initial.clone();
+    hare = initial;
+    for _ in 0..length {
+        hare = f(&hare);
+    }
+    let mut start = 0;
+    while tortoise != hare {
+        tortoise = f(&tortoise);
+        hare = f(&hare);
+        start += 1;
+    }
+    Cycle { start, length }
+}
+
+pub fn detect<S: Eq + Hash>(states: impl IntoIterator<Item = S>) -> Option<Cycle> {
+    let mut seen = HashMap::new();
+    for (idx, state) in states.into_iter().enumerate() {
+        if let Some(start) = seen.insert(state, idx) {
+            return Some(Cycle {
+                start,
+                length: idx - start,
+            });
+        }
+    }
+    None
+}
+
+pub fn detect_by_key<S, K: Eq + Hash>(
+    states: impl IntoIterator<Item = S>,
+    mut key: impl FnMut(&S) -> K,
+) -> Option<Cycle> {
+    detect(states.into_iter().map(|state| key(&state)))
+}
+
+pub fn nth<S: Clone + Eq + Hash>(initial: S, mut f: impl FnMut(&S) -> S, n: usize) -> S {
+    let mut seen = HashMap::new();
+    let mut states = vec![initial];
+    while states.len() <= n {
+        let state = states.last().unwrap();
+        if let Some(start) = seen.insert(state.clone(), states.len() - 1) {
+            let cycle = Cycle {
+                start,
+                length: states.len() - 1 - start,
+            };
+            return states.swap_remove(cycle.index(n));
+        }
+        let next = f(state);
+        states.push(next);
+    }
+    states.swap_remove(n)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{brent, detect, detect_by_key, floyd, nth, Cycle};
+
+    fn step(&x: &u64) -> u64 {
+        (x * x + 1) % 255
+    }
+
+    fn naive(initial: u64) -> Cycle {
+        let mut states = vec![initial];
+        loop {
+            let next = step(states.last().unwrap());
+            if let Some(start) = states.iter().position(|&state| state == next) {
+                return Cycle {
+                    start,
+                    length: states.len() - start,
+                };
+            }
+            states.push(next);
+        }
+    }
+
+    #[test]
+    fn algorithms_agree() {
+        for initial in 0..50 {
+            let expected = naive(initial);
+            assert_eq!(floyd(initial, step), expected, "floyd from {initial}");
+            assert_eq!(brent(initial, step), expected, "brent from {initial}");
+            let states = std::iter::successors(Some(initial), |x| Some(step(x)));
+            assert_eq!(detect(states), Some(expected), "detect from {initial}");
+        }
+    }
+
+    #[test]
+    fn cycle_index() {
+        let cycle = Cycle {
+            start: 3,
+            length: 4,
+        };
+        assert_eq!(cycle.index(2), 2);
+        assert_eq!(cycle.index(3), 3);
+        assert_eq!(cycle.index(7), 3);
+        assert_eq!(cycle.index(1_000_000_002), 3 + 999_999_999 % 4);
+    }
+
+    #[test]
+    fn extrapolate_values() {
+        let heights: [i64; 9] = [0, 2, 5, 6, 8, 11, 12, 14, 17];
+        let cycle = detect_by_key(heights.windows(2), |pair| pair[1] - pair[0]).unwrap();
+        assert_eq!(
+            cycle,
+            Cycle {
+                start: 0,
+                length: 3
+            }
+        );
+        let height = |n: usize| heights[n];
+        assert_eq!(cycle.extrapolate(7, height), 14);
+        assert_eq!(
+            cycle.extrapolate(2022, height),
+            2022 / 3 * 6 + [0, 2, 5][2022 % 3]
+        );
+    }
+
+    #[test]
+    fn nth_state() {
+        let mut state = 7;
+        for _ in 0..1000 {
+            state = step(&state);
+        }
+        assert_eq!(nth(7, step, 1000), state);
+        assert_eq!(nth(7, step, 2), step(&step(&7)));
+        assert_eq!(nth(7, step, 0), 7);
+    }
+
+    #[test]
+    fn finite_iterator() {
+        assert_eq!(detect([1, 2, 3]), None);
+    }
+}
@@ -0,0 +1,85 @@
+use crate::{Part, Report, STDIN};
+use std::{
+    io::ErrorKind,
+    path::{Path, PathBuf},
+};
+
+pub struct Day {
+    pub year: u16,
+    pub day: u8,
+    pub name: &'static str,
+    pub assets: &'static str,
+    pub run: fn(&str, &[Part]) -> Report,
+}
+
+impl Day {
+    pub fn input(&self, name: &str) -> PathBuf {
+        if name == STDIN {
+            return PathBuf::from(STDIN);
+        }
+        let assets = Path::new(self.assets);
+        [assets.join(name), assets.join(format!("{name}.txt"))]
+            .into_iter()
+            .find(|path| path.is_file())
+            .unwrap_or_else(|| PathBuf::from(name))
+    }
+
+    pub fn inputs(&self) -> Result<Vec<String>, String> {
+        let entries = match std::fs::read_dir(self.assets) {
+            Ok(entries) => entries,
+            Err(err) if err.kind() == ErrorKind::NotFound => return Ok(Vec::new()),
+            Err(err) => return Err(format!("could not read `{}`: {err}", self.assets)),
+        };
+        let mut inputs = Vec::new();
+        for entry in entries {
+            let path = entry.map_err(|err| err.to_string())?.path();
+            if path.extension().is_some_and(|ext| ext == "txt") {
+                if let Some(stem) = path.file_stem().and_then(|stem| stem.to_str()) {
+                    inputs.push(stem.to_string());
+                }
+            }
+        }
+        inputs.sort();
+        Ok(inputs)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Day;
+    use crate::{Part, Report};
+    use std::{path::PathBuf, time::Duration};
+
+    fn run(_input: &str, _parts: &[Part]) -> Report {
+        Report {
+            parse: Duration::ZERO,
+            answers: Vec::new(),
+        }
+    }
+
+    const DAY: Day = Day {
+        year: 2023,
+        day: 1,
+        name: "aoc",
+        assets: concat!(env!("CARGO_MANIFEST_DIR"), "/src"),
+        run,
+    };
+
+    #[test]
+    fn resolve_named_input() {
+        let path = PathBuf::from(env!("CARGO_MANIFEST_DIR")).join("src/lib.rs");
+        assert_eq!(DAY.input("lib.rs"), path);
+    }
+
+    #[test]
+    fn resolve_path_input() {
+        assert_eq!(DAY.input("other/example2"), PathBuf::from("other/example2"));
+        assert_eq!(DAY.input("-"), PathBuf::from("-"));
+    }
+
+    #[test]
+    fn input_macro() {
+        assert!(crate::input!("example2").ends_with("/assets/example2.txt"));
+        assert!(crate::input!().ends_with("/assets/input.txt"));
+    }
+}
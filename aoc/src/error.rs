@@ -0,0 +1,186 @@
+use std::{
+    error::Error,
+    fmt::{Display, Formatter},
+};
+
+#[derive(Debug)]
+pub struct ParseError(Box<Inner>);
+
+#[derive(Debug)]
+struct Inner {
+    message: String,
+    snippet: Option<String>,
+    context: Option<&'static str>,
+    line: Option<usize>,
+    column: Option<usize>,
+    address: Option<usize>,
+    cause: Option<Box<dyn Error + Send + Sync>>,
+}
+
+impl ParseError {
+    pub fn new(message: impl Into<String>) -> ParseError {
+        ParseError(Box::new(Inner {
+            message: message.into(),
+            snippet: None,
+            context: None,
+            line: None,
+            column: None,
+            address: None,
+            cause: None,
+        }))
+    }
+
+    pub fn invalid(what: &str, snippet: &str) -> ParseError {
+        ParseError::new(format!("invalid {what} '{snippet}'")).span(snippet)
+    }
+
+    pub fn missing(what: &str) -> ParseError {
+        ParseError::new(format!("missing {what}"))
+    }
+
+    pub fn unexpected(snippet: &str) -> ParseError {
+        ParseError::new(format!("unexpected '{snippet}'")).span(snippet)
+    }
+
+    pub fn span(mut self, snippet: &str) -> ParseError {
+        if self.0.address.is_none() {
+            self.0.snippet = Some(snippet.to_string());
+            self.0.address = Some(snippet.as_ptr() as usize);
+        }
+        self
+    }
+
+    pub fn context(mut self, context: &'static str) -> ParseError {
+        self.0.context.get_or_insert(context);
+        self
+    }
+
+    pub fn at(mut self, line: usize, column: usize) -> ParseError {
+        if self.0.line.is_none() {
+            self.0.line = Some(line);
+            self.0.column = Some(column);
+        }
+        self
+    }
+
+    pub fn at_line(mut self, line: usize) -> ParseError {
+        self.0.line.get_or_insert(line);
+        self
+    }
+
+    pub fn locate(self, source: &str) -> ParseError {
+        let start = source.as_ptr() as usize;
+        let offset = match self.0.address {
+            Some(address) if self.0.line.is_none() && address >= start => address - start,
+            _ => return self,
+        };
+        match source.get(..offset) {
+            Some(before) => {
+                let line_start = before.rfind('\n').map(|idx| idx + 1).unwrap_or(0);
+                let line = before.matches('\n').count() + 1;
+                let column = before[line_start..].chars().count() + 1;
+                self.at(line, column)
+            }
+            None => self,
+        }
+    }
+
+    pub fn with_cause(mut self, cause: impl Into<Box<dyn Error + Send + Sync>>) -> ParseError {
+        self.0.cause = Some(cause.into());
+        self
+    }
+
+    pub fn message(&self) -> &str {
+        &self.0.message
+    }
+
+    pub fn snippet(&self) -> Option<&str> {
+        self.0.snippet.as_deref()
+    }
+
+    pub fn line(&self) -> Option<usize> {
+        self.0.line
+    }
+
+    pub fn column(&self) -> Option<usize> {
+        self.0.column
+    }
+}
+
+impl Display for ParseError {
+    fn fmt(&self, f: &mut Formatter) -> std::fmt::Result {
+        match (self.0.line, self.0.column) {
+            (Some(line), Some(column)) => write!(f, "line {line}, col {column}: ")?,
+            (Some(line), None) => write!(f, "line {line}: ")?,
+            _ => {}
+        }
+        write!(f, "{}", self.0.message)?;
+        if let Some(context) = self.0.context {
+            write!(f, " in {context}")?;
+        }
+        Ok(())
+    }
+}
+
+impl Error for ParseError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        self.0
+            .cause
+            .as_deref()
+            .map(|cause| cause as &(dyn Error + 'static))
+    }
+}
+
+impl From<&str> for ParseError {
+    fn from(message: &str) -> Self {
+        ParseError::new(message)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::ParseError;
+    use std::error::Error;
+
+    #[test]
+    fn display_message() {
+        let err = ParseError::missing("separator");
+        assert_eq!(err.to_string(), "missing separator");
+    }
+
+    #[test]
+    fn display_location_and_context() {
+        let source = "px{a<2006:qkq}\nqs{a<bbb:R}";
+        let snippet = &source[20..23];
+        let err = ParseError::invalid("constant", snippet)
+            .context("condition")
+            .context("workflow")
+            .locate(source);
+        assert_eq!(
+            err.to_string(),
+            "line 2, col 6: invalid constant 'bbb' in condition"
+        );
+        assert_eq!(err.snippet(), Some("bbb"));
+    }
+
+    #[test]
+    fn innermost_location_wins() {
+        let err = ParseError::new("nan").at(3, 4).at_line(7);
+        assert_eq!(err.line(), Some(3));
+        assert_eq!(err.column(), Some(4));
+    }
+
+    #[test]
+    fn ignore_foreign_snippet() {
+        let snippet = String::from("bbb");
+        let err = ParseError::invalid("constant", &snippet).locate("a<bbb");
+        assert_eq!(err.line(), None);
+    }
+
+    #[test]
+    fn chain_cause() {
+        let cause = "bbb".parse::<u64>().unwrap_err();
+        let err = ParseError::invalid("constant", "bbb").with_cause(cause.clone());
+        assert_eq!(err.source().unwrap().to_string(), cause.to_string());
+    }
+}
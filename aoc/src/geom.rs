@@ -0,0 +1,7 @@
+mod dir;
+mod scalar;
+mod vec;
+
+pub use dir::{Dir4, Dir8, Turn};
+pub use scalar::Scalar;
+pub use vec::{Vec2, Vec3};
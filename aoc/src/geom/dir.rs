@@ -0,0 +1,233 @@
+use super::Vec2;
+use crate::ParseError;
+use std::str::FromStr;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Dir4 {
+    North,
+    East,
+    South,
+    West,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Dir8 {
+    North,
+    NorthEast,
+    East,
+    SouthEast,
+    South,
+    SouthWest,
+    West,
+    NorthWest,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Turn {
+    Left,
+    Right,
+}
+
+impl Dir4 {
+    pub const ALL: [Dir4; 4] = [Dir4::North, Dir4::East, Dir4::South, Dir4::West];
+
+    pub fn delta(self) -> Vec2<i64> {
+        Dir8::from(self).delta()
+    }
+
+    pub fn turn_right(self) -> Dir4 {
+        Dir4::ALL[(self as usize + 1) % 4]
+    }
+
+    pub fn turn_left(self) -> Dir4 {
+        Dir4::ALL[(self as usize + 3) % 4]
+    }
+
+    pub fn turn(self, turn: Turn) -> Dir4 {
+        match turn {
+            Turn::Left => self.turn_left(),
+            Turn::Right => self.turn_right(),
+        }
+    }
+
+    pub fn opposite(self) -> Dir4 {
+        Dir4::ALL[(self as usize + 2) % 4]
+    }
+
+    pub fn is_vertical(self) -> bool {
+        matches!(self, Dir4::North | Dir4::South)
+    }
+}
+
+impl Dir8 {
+    pub const ALL: [Dir8; 8] = [
+        Dir8::North,
+        Dir8::NorthEast,
+        Dir8::East,
+        Dir8::SouthEast,
+        Dir8::South,
+        Dir8::SouthWest,
+        Dir8::West,
+        Dir8::NorthWest,
+    ];
+
+    pub fn delta(self) -> Vec2<i64> {
+        let (x, y) = match self {
+            Dir8::North => (0, -1),
+            Dir8::NorthEast => (1, -1),
+            Dir8::East => (1, 0),
+            Dir8::SouthEast => (1, 1),
+            Dir8::South => (0, 1),
+            Dir8::SouthWest => (-1, 1),
+            Dir8::West => (-1, 0),
+            Dir8::NorthWest => (-1, -1),
+        };
+        Vec2::new(x, y)
+    }
+
+    pub fn turn_right(self) -> Dir8 {
+        Dir8::ALL[(self as usize + 1) % 8]
+    }
+
+    pub fn turn_left(self) -> Dir8 {
+        Dir8::ALL[(self as usize + 7) % 8]
+    }
+
+    pub fn turn(self, turn: Turn) -> Dir8 {
+        match turn {
+            Turn::Left => self.turn_left(),
+            Turn::Right => self.turn_right(),
+        }
+    }
+
+    pub fn opposite(self) -> Dir8 {
+        Dir8::ALL[(self as usize + 4) % 8]
+    }
+}
+
+impl From<Dir4> for Dir8 {
+    fn from(value: Dir4) -> Self {
+        Dir8::ALL[value as usize * 2]
+    }
+}
+
+impl TryFrom<Dir8> for Dir4 {
+    type Error = Dir8;
+
+    fn try_from(value: Dir8) -> Result<Self, Self::Error> {
+        match value as usize % 2 {
+            0 => Ok(Dir4::ALL[value as usize / 2]),
+            _ => Err(value),
+        }
+    }
+}
+
+impl TryFrom<char> for Dir4 {
+    type Error = ParseError;
+
+    fn try_from(value: char) -> Result<Self, Self::Error> {
+        match value {
+            '^' | 'N' | 'U' | '3' => Ok(Dir4::North),
+            '>' | 'E' | 'R' | '0' => Ok(Dir4::East),
+            'v' | 'S' | 'D' | '1' => Ok(Dir4::South),
+            '<' | 'W' | 'L' | '2' => Ok(Dir4::West),
+            _ => Err(ParseError::new(format!("invalid direction '{value}'"))),
+        }
+    }
+}
+
+impl FromStr for Dir4 {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut chars = s.chars();
+        match (chars.next(), chars.next()) {
+            (Some(c), None) => Dir4::try_from(c).map_err(|err| err.span(s)),
+            _ => Err(ParseError::invalid("direction", s)),
+        }
+    }
+}
+
+impl FromStr for Dir8 {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "NE" => Ok(Dir8::NorthEast),
+            "SE" => Ok(Dir8::SouthEast),
+            "SW" => Ok(Dir8::SouthWest),
+            "NW" => Ok(Dir8::NorthWest),
+            _ => s.parse::<Dir4>().map(Dir8::from),
+        }
+    }
+}
+
+impl TryFrom<char> for Turn {
+    type Error = ParseError;
+
+    fn try_from(value: char) -> Result<Self, Self::Error> {
+        match value {
+            'L' => Ok(Turn::Left),
+            'R' => Ok(Turn::Right),
+            _ => Err(ParseError::new(format!("invalid turn '{value}'"))),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Dir4, Dir8, Turn};
+    use crate::geom::Vec2;
+
+    #[test]
+    fn turn_dir4() {
+        assert_eq!(Dir4::North.turn_right(), Dir4::East);
+        assert_eq!(Dir4::North.turn_left(), Dir4::West);
+        assert_eq!(Dir4::West.turn(Turn::Right), Dir4::North);
+        assert_eq!(Dir4::East.opposite(), Dir4::West);
+        for dir in Dir4::ALL {
+            assert_eq!(dir.turn_left().turn_right(), dir);
+            assert_eq!(dir.opposite().delta(), -dir.delta());
+        }
+    }
+
+    #[test]
+    fn turn_dir8() {
+        assert_eq!(Dir8::North.turn_right(), Dir8::NorthEast);
+        assert_eq!(Dir8::North.turn_left(), Dir8::NorthWest);
+        assert_eq!(Dir8::SouthWest.opposite(), Dir8::NorthEast);
+        let sum = Dir8::ALL
+            .iter()
+            .fold(Vec2::ZERO, |sum, dir| sum + dir.delta());
+        assert_eq!(sum, Vec2::ZERO);
+    }
+
+    #[test]
+    fn deltas() {
+        assert_eq!(Dir4::North.delta(), Vec2::new(0, -1));
+        assert_eq!(Dir4::East.delta(), Vec2::new(1, 0));
+        assert_eq!(Dir8::SouthWest.delta(), Vec2::new(-1, 1));
+    }
+
+    #[test]
+    fn convert() {
+        assert_eq!(Dir8::from(Dir4::South), Dir8::South);
+        assert_eq!(Dir4::try_from(Dir8::West), Ok(Dir4::West));
+        assert_eq!(Dir4::try_from(Dir8::NorthEast), Err(Dir8::NorthEast));
+    }
+
+    #[test]
+    fn parse_conventions() {
+        for s in ["^>v<", "NESW", "URDL"] {
+            let parsed: Vec<Dir4> = s.chars().map(|c| Dir4::try_from(c).unwrap()).collect();
+            assert_eq!(parsed, Dir4::ALL.to_vec());
+        }
+        let hex: Vec<Dir4> = "0123".chars().map(|c| Dir4::try_from(c).unwrap()).collect();
+        assert_eq!(hex, vec![Dir4::East, Dir4::South, Dir4::West, Dir4::North]);
+        assert_eq!("SW".parse::<Dir8>().ok(), Some(Dir8::SouthWest));
+        assert_eq!("E".parse::<Dir8>().ok(), Some(Dir8::East));
+        assert!("x".parse::<Dir4>().is_err());
+        assert!("NN".parse::<Dir4>().is_err());
+        assert_eq!(Turn::try_from('L').ok(), Some(Turn::Left));
+    }
+}
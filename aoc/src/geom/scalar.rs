@@ -0,0 +1,57 @@
+use std::{
+    error::Error,
+    ops::{Add, Div, Mul, Sub},
+    str::FromStr,
+};
+
+pub trait Scalar:
+    Copy
+    + PartialOrd
+    + Add<Output = Self>
+    + Sub<Output = Self>
+    + Mul<Output = Self>
+    + Div<Output = Self>
+    + FromStr<Err: Into<Box<dyn Error + Send + Sync>>>
+{
+    const ZERO: Self;
+
+    fn div_euclid(self, rhs: Self) -> Self;
+    fn rem_euclid(self, rhs: Self) -> Self;
+
+    fn abs_diff(self, rhs: Self) -> Self {
+        if self < rhs {
+            rhs - self
+        } else {
+            self - rhs
+        }
+    }
+
+    fn max_of(self, rhs: Self) -> Self {
+        if self < rhs {
+            rhs
+        } else {
+            self
+        }
+    }
+}
+
+macro_rules! impl_scalar {
+    ($zero:expr => $($ty:ty),*) => {
+        $(
+            impl Scalar for $ty {
+                const ZERO: Self = $zero;
+
+                fn div_euclid(self, rhs: Self) -> Self {
+                    <$ty>::div_euclid(self, rhs)
+                }
+
+                fn rem_euclid(self, rhs: Self) -> Self {
+                    <$ty>::rem_euclid(self, rhs)
+                }
+            }
+        )*
+    };
+}
+
+impl_scalar!(0 => i8, i16, i32, i64, i128, isize, u8, u16, u32, u64, u128, usize);
+impl_scalar!(0.0 => f32, f64);
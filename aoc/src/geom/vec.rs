@@ -0,0 +1,271 @@
+use super::Scalar;
+use crate::ParseError;
+use std::{
+    ops::{Add, AddAssign, Mul, Neg, Sub, SubAssign},
+    str::FromStr,
+};
+
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Vec2<T> {
+    pub x: T,
+    pub y: T,
+}
+
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Vec3<T> {
+    pub x: T,
+    pub y: T,
+    pub z: T,
+}
+
+impl<T> Vec2<T> {
+    pub const fn new(x: T, y: T) -> Vec2<T> {
+        Vec2 { x, y }
+    }
+
+    pub fn map<U>(self, mut f: impl FnMut(T) -> U) -> Vec2<U> {
+        Vec2::new(f(self.x), f(self.y))
+    }
+}
+
+impl<T: Scalar> Vec2<T> {
+    pub const ZERO: Vec2<T> = Vec2::new(T::ZERO, T::ZERO);
+
+    pub fn dot(self, rhs: Vec2<T>) -> T {
+        self.x * rhs.x + self.y * rhs.y
+    }
+
+    pub fn cross(self, rhs: Vec2<T>) -> T {
+        self.x * rhs.y - self.y * rhs.x
+    }
+
+    pub fn manhattan(self, rhs: Vec2<T>) -> T {
+        self.x.abs_diff(rhs.x) + self.y.abs_diff(rhs.y)
+    }
+
+    pub fn chebyshev(self, rhs: Vec2<T>) -> T {
+        self.x.abs_diff(rhs.x).max_of(self.y.abs_diff(rhs.y))
+    }
+
+    pub fn div_euclid(self, rhs: Vec2<T>) -> Vec2<T> {
+        Vec2::new(self.x.div_euclid(rhs.x), self.y.div_euclid(rhs.y))
+    }
+
+    pub fn rem_euclid(self, rhs: Vec2<T>) -> Vec2<T> {
+        Vec2::new(self.x.rem_euclid(rhs.x), self.y.rem_euclid(rhs.y))
+    }
+}
+
+impl<T> Vec3<T> {
+    pub const fn new(x: T, y: T, z: T) -> Vec3<T> {
+        Vec3 { x, y, z }
+    }
+
+    pub fn map<U>(self, mut f: impl FnMut(T) -> U) -> Vec3<U> {
+        Vec3::new(f(self.x), f(self.y), f(self.z))
+    }
+
+    pub fn xy(self) -> Vec2<T> {
+        Vec2::new(self.x, self.y)
+    }
+}
+
+impl<T: Scalar> Vec3<T> {
+    pub const ZERO: Vec3<T> = Vec3::new(T::ZERO, T::ZERO, T::ZERO);
+
+    pub fn dot(self, rhs: Vec3<T>) -> T {
+        self.x * rhs.x + self.y * rhs.y + self.z * rhs.z
+    }
+
+    pub fn cross(self, rhs: Vec3<T>) -> Vec3<T> {
+        Vec3::new(
+            self.y * rhs.z - self.z * rhs.y,
+            self.z * rhs.x - self.x * rhs.z,
+            self.x * rhs.y - self.y * rhs.x,
+        )
+    }
+
+    pub fn manhattan(self, rhs: Vec3<T>) -> T {
+        self.x.abs_diff(rhs.x) + self.y.abs_diff(rhs.y) + self.z.abs_diff(rhs.z)
+    }
+
+    pub fn chebyshev(self, rhs: Vec3<T>) -> T {
+        self.x
+            .abs_diff(rhs.x)
+            .max_of(self.y.abs_diff(rhs.y))
+            .max_of(self.z.abs_diff(rhs.z))
+    }
+
+    pub fn div_euclid(self, rhs: Vec3<T>) -> Vec3<T> {
+        Vec3::new(
+            self.x.div_euclid(rhs.x),
+            self.y.div_euclid(rhs.y),
+            self.z.div_euclid(rhs.z),
+        )
+    }
+
+    pub fn rem_euclid(self, rhs: Vec3<T>) -> Vec3<T> {
+        Vec3::new(
+            self.x.rem_euclid(rhs.x),
+            self.y.rem_euclid(rhs.y),
+            self.z.rem_euclid(rhs.z),
+        )
+    }
+}
+
+macro_rules! impl_ops {
+    ($vec:ident { $($field:ident),* }) => {
+        impl<T: Add<Output = T>> Add for $vec<T> {
+            type Output = $vec<T>;
+
+            fn add(self, rhs: Self) -> Self::Output {
+                $vec { $($field: self.$field + rhs.$field),* }
+            }
+        }
+
+        impl<T: Sub<Output = T>> Sub for $vec<T> {
+            type Output = $vec<T>;
+
+            fn sub(self, rhs: Self) -> Self::Output {
+                $vec { $($field: self.$field - rhs.$field),* }
+            }
+        }
+
+        impl<T: Mul<Output = T> + Copy> Mul<T> for $vec<T> {
+            type Output = $vec<T>;
+
+            fn mul(self, rhs: T) -> Self::Output {
+                $vec { $($field: self.$field * rhs),* }
+            }
+        }
+
+        impl<T: Neg<Output = T>> Neg for $vec<T> {
+            type Output = $vec<T>;
+
+            fn neg(self) -> Self::Output {
+                $vec { $($field: -self.$field),* }
+            }
+        }
+
+        impl<T: AddAssign> AddAssign for $vec<T> {
+            fn add_assign(&mut self, rhs: Self) {
+                $(self.$field += rhs.$field;)*
+            }
+        }
+
+        impl<T: SubAssign> SubAssign for $vec<T> {
+            fn sub_assign(&mut self, rhs: Self) {
+                $(self.$field -= rhs.$field;)*
+            }
+        }
+
+        impl<T: Scalar> FromStr for $vec<T> {
+            type Err = ParseError;
+
+            fn from_str(s: &str) -> Result<Self, Self::Err> {
+                let mut components = s.split(',');
+                let vec = $vec {
+                    $($field: parse_component(s, components.next(), stringify!($field))?),*
+                };
+                match components.next() {
+                    Some(extra) => Err(ParseError::unexpected(extra.trim())),
+                    None => Ok(vec),
+                }
+            }
+        }
+    };
+}
+
+impl_ops!(Vec2 { x, y });
+impl_ops!(Vec3 { x, y, z });
+
+impl<T> From<(T, T)> for Vec2<T> {
+    fn from((x, y): (T, T)) -> Self {
+        Vec2::new(x, y)
+    }
+}
+
+impl<T> From<(T, T, T)> for Vec3<T> {
+    fn from((x, y, z): (T, T, T)) -> Self {
+        Vec3::new(x, y, z)
+    }
+}
+
+fn parse_component<T: Scalar>(
+    s: &str,
+    component: Option<&str>,
+    name: &str,
+) -> Result<T, ParseError> {
+    let component = component
+        .ok_or_else(|| ParseError::missing(&format!("{name} component")).span(s))?
+        .trim();
+    component
+        .parse()
+        .map_err(|err| ParseError::invalid(&format!("{name} component"), component).with_cause(err))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Vec2, Vec3};
+
+    #[test]
+    fn arithmetic() {
+        let a = Vec2::new(1, -2);
+        let b = Vec2::new(3, 5);
+        assert_eq!(a + b, Vec2::new(4, 3));
+        assert_eq!(a - b, Vec2::new(-2, -7));
+        assert_eq!(a * 3, Vec2::new(3, -6));
+        assert_eq!(-a, Vec2::new(-1, 2));
+        let mut c = a;
+        c += b;
+        c -= Vec2::new(1, 1);
+        assert_eq!(c, Vec2::new(3, 2));
+    }
+
+    #[test]
+    fn products() {
+        assert_eq!(Vec2::new(1, 2).dot(Vec2::new(3, 4)), 11);
+        assert_eq!(Vec2::new(1, 0).cross(Vec2::new(0, 1)), 1);
+        let x = Vec3::new(1, 0, 0);
+        let y = Vec3::new(0, 1, 0);
+        assert_eq!(x.cross(y), Vec3::new(0, 0, 1));
+        assert_eq!(Vec3::new(1.5, 2.0, -1.0).dot(Vec3::new(2.0, 0.5, 3.0)), 1.0);
+    }
+
+    #[test]
+    fn distances() {
+        let a = Vec2::new(1, -2);
+        let b = Vec2::new(-3, 5);
+        assert_eq!(a.manhattan(b), 11);
+        assert_eq!(a.chebyshev(b), 7);
+        assert_eq!(Vec2::<u32>::new(2, 9).manhattan(Vec2::new(5, 1)), 11);
+        assert_eq!(Vec3::new(1, 2, 3).manhattan(Vec3::ZERO), 6);
+        assert_eq!(Vec3::new(1, -7, 3).chebyshev(Vec3::ZERO), 7);
+    }
+
+    #[test]
+    fn euclid() {
+        let size = Vec2::new(11, 11);
+        assert_eq!(Vec2::new(-1, 23).rem_euclid(size), Vec2::new(10, 1));
+        assert_eq!(Vec2::new(-1, 23).div_euclid(size), Vec2::new(-1, 2));
+    }
+
+    #[test]
+    fn parse() {
+        assert_eq!("19, 13, 30".parse().ok(), Some(Vec3::new(19, 13, 30)));
+        assert_eq!("1,-2".parse().ok(), Some(Vec2::new(1, -2)));
+        assert_eq!("0.5,2".parse().ok(), Some(Vec2::new(0.5, 2.0)));
+        let err = "1,2".parse::<Vec3<i64>>().unwrap_err();
+        assert_eq!(err.to_string(), "missing z component");
+        let err = "1,a".parse::<Vec2<i64>>().unwrap_err();
+        assert_eq!(err.to_string(), "invalid y component 'a'");
+        assert!("1,2,3".parse::<Vec2<i64>>().is_err());
+    }
+
+    #[test]
+    fn convert() {
+        let v = Vec3::new(1, 2, 3);
+        assert_eq!(v.xy(), Vec2::from((1, 2)));
+        assert_eq!(v.map(i128::from), Vec3::new(1i128, 2, 3));
+    }
+}
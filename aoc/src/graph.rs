@@ -0,0 +1,237 @@
+use crate::search::Path;
+use std::{collections::HashMap, hash::Hash};
+
+#[derive(Debug, Clone)]
+pub struct Graph<N> {
+    nodes: Vec<N>,
+    index: HashMap<N, usize>,
+    edges: Vec<Vec<(usize, usize)>>,
+}
+
+impl<N: Eq + Hash + Clone> Graph<N> {
+    pub fn new() -> Graph<N> {
+        Graph {
+            nodes: Vec::new(),
+            index: HashMap::new(),
+            edges: Vec::new(),
+        }
+    }
+
+    pub fn contract<I>(
+        required: impl IntoIterator<Item = N>,
+        mut neighbours: impl FnMut(&N) -> I,
+    ) -> Graph<N>
+    where
+        I: IntoIterator<Item = N>,
+    {
+        let mut graph = Graph::new();
+        let mut open: Vec<usize> = required
+            .into_iter()
+            .map(|node| graph.add_node(node))
+            .collect();
+        while let Some(from) = open.pop() {
+            let junction = graph.nodes[from].clone();
+            for first in neighbours(&junction) {
+                let (mut previous, mut current, mut distance) = (junction.clone(), first, 1);
+                loop {
+                    if let Some(&to) = graph.index.get(&current) {
+                        graph.add_edge(from, to, distance);
+                        break;
+                    }
+                    let mut next: Vec<N> = neighbours(&current)
+                        .into_iter()
+                        .filter(|next| *next != previous)
+                        .collect();
+                    match next.len() {
+                        0 => break,
+                        1 => {
+                            previous = std::mem::replace(&mut current, next.pop().unwrap());
+                            distance += 1;
+                        }
+                        _ => {
+                            let to = graph.add_node(current);
+                            graph.add_edge(from, to, distance);
+                            open.push(to);
+                            break;
+                        }
+                    }
+                }
+            }
+        }
+        graph
+    }
+
+    pub fn add_node(&mut self, node: N) -> usize {
+        if let Some(&idx) = self.index.get(&node) {
+            return idx;
+        }
+        self.index.insert(node.clone(), self.nodes.len());
+        self.nodes.push(node);
+        self.edges.push(Vec::new());
+        self.nodes.len() - 1
+    }
+
+    pub fn add_edge(&mut self, from: usize, to: usize, weight: usize) {
+        self.edges[from].push((to, weight));
+    }
+
+    pub fn len(&self) -> usize {
+        self.nodes.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.nodes.is_empty()
+    }
+
+    pub fn node(&self, idx: usize) -> &N {
+        &self.nodes[idx]
+    }
+
+    pub fn index_of(&self, node: &N) -> Option<usize> {
+        self.index.get(node).copied()
+    }
+
+    pub fn edges(&self, idx: usize) -> &[(usize, usize)] {
+        &self.edges[idx]
+    }
+
+    pub fn longest_path(&self, from: &N, to: &N) -> Option<Path<N, usize>> {
+        let (from, to) = (self.index_of(from)?, self.index_of(to)?);
+        let mut best_in = vec![0; self.len()];
+        let mut predecessors = vec![Vec::new(); self.len()];
+        for (source, edges) in self.edges.iter().enumerate() {
+            for &(target, weight) in edges {
+                best_in[target] = best_in[target].max(weight);
+                if !predecessors[target].contains(&source) {
+                    predecessors[target].push(source);
+                }
+            }
+        }
+        let gate = match predecessors[to][..] {
+            [gate] => Some(gate),
+            _ => None,
+        };
+        let remaining = best_in.iter().sum::<usize>() - best_in[from];
+        let mut dfs = LongestPath {
+            graph: self,
+            to,
+            gate,
+            best_in,
+            visited: vec![0; self.len().div_ceil(64)],
+            stack: vec![from],
+            best: None,
+        };
+        dfs.visit(from, 0, remaining);
+        dfs.best.map(|(cost, stack)| Path {
+            cost,
+            nodes: stack
+                .into_iter()
+                .map(|idx| self.nodes[idx].clone())
+                .collect(),
+        })
+    }
+}
+
+impl<N: Eq + Hash + Clone> Default for Graph<N> {
+    fn default() -> Self {
+        Graph::new()
+    }
+}
+
+struct LongestPath<'a, N> {
+    graph: &'a Graph<N>,
+    to: usize,
+    gate: Option<usize>,
+    best_in: Vec<usize>,
+    visited: Vec<u64>,
+    stack: Vec<usize>,
+    best: Option<(usize, Vec<usize>)>,
+}
+
+impl<N> LongestPath<'_, N> {
+    fn visit(&mut self, node: usize, distance: usize, remaining: usize) {
+        if node == self.to {
+            if self.best.as_ref().is_none_or(|(best, _)| distance > *best) {
+                self.best = Some((distance, self.stack.clone()));
+            }
+            return;
+        }
+        if self
+            .best
+            .as_ref()
+            .is_some_and(|(best, _)| distance + remaining <= *best)
+        {
+            return;
+        }
+        self.visited[node / 64] |= 1 << (node % 64);
+        for &(next, weight) in &self.graph.edges[node] {
+            if self.visited[next / 64] & (1 << (next % 64)) != 0 {
+                continue;
+            }
+            if self.gate == Some(node) && next != self.to {
+                continue;
+            }
+            self.stack.push(next);
+            self.visit(next, distance + weight, remaining - self.best_in[next]);
+            self.stack.pop();
+        }
+        self.visited[node / 64] &= !(1 << (node % 64));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Graph;
+    use crate::grid::Grid;
+
+    const MAZE: &str = "#.#####\n#.....#\n#.###.#\n#.....#\n###.###\n###...#\n#####.#";
+
+    fn open(grid: &Grid<char>) -> impl FnMut(&(usize, usize)) -> Vec<(usize, usize)> + '_ {
+        |&position| {
+            grid.neighbours4(position)
+                .filter(|&next| grid[next] != '#')
+                .collect()
+        }
+    }
+
+    #[test]
+    fn contract_corridors() {
+        let grid: Grid<char> = MAZE.parse().unwrap();
+        let (start, end) = ((0, 1), (6, 5));
+        let graph = Graph::contract([start, end], open(&grid));
+        assert_eq!(graph.len(), 4);
+        let junction = graph.index_of(&(1, 1)).unwrap();
+        let start = graph.index_of(&start).unwrap();
+        let centre = graph.index_of(&(3, 3)).unwrap();
+        let mut edges = graph.edges(junction).to_vec();
+        edges.sort();
+        let mut expected = vec![(start, 1), (centre, 4), (centre, 8)];
+        expected.sort();
+        assert_eq!(edges, expected);
+    }
+
+    #[test]
+    fn longest_path_in_maze() {
+        let grid: Grid<char> = MAZE.parse().unwrap();
+        let (start, end) = ((0, 1), (6, 5));
+        let graph = Graph::contract([start, end], open(&grid));
+        let path = graph.longest_path(&start, &end).unwrap();
+        assert_eq!(path.cost, 14);
+        assert_eq!(path.nodes, vec![(0, 1), (1, 1), (3, 3), (6, 5)]);
+    }
+
+    #[test]
+    fn directed_edges() {
+        let mut graph = Graph::new();
+        let [a, b, c, d] = ['a', 'b', 'c', 'd'].map(|node| graph.add_node(node));
+        graph.add_edge(a, b, 1);
+        graph.add_edge(b, c, 1);
+        graph.add_edge(a, c, 5);
+        graph.add_edge(c, d, 1);
+        graph.add_edge(d, b, 10);
+        let path = graph.longest_path(&'a', &'c').unwrap();
+        assert_eq!(path.cost, 5);
+        assert_eq!(path.nodes, vec!['a', 'c']);
+        assert!(graph.longest_path(&'d', &'a').is_none());
+    }
+}
@@ -0,0 +1,362 @@
+use crate::{
+    geom::{Dir4, Dir8},
+    ParseError,
+};
+use std::{
+    error::Error,
+    fmt::{Display, Formatter},
+    ops::{Index, IndexMut},
+    str::FromStr,
+};
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Grid<T> {
+    cells: Vec<T>,
+    rows: usize,
+    columns: usize,
+}
+
+impl<T> Grid<T> {
+    pub fn new(rows: usize, columns: usize, fill: T) -> Grid<T>
+    where
+        T: Clone,
+    {
+        Grid {
+            cells: vec![fill; rows * columns],
+            rows,
+            columns,
+        }
+    }
+
+    pub fn from_fn(rows: usize, columns: usize, mut f: impl FnMut((usize, usize)) -> T) -> Grid<T> {
+        let cells = (0..rows * columns)
+            .map(|idx| f((idx / columns, idx % columns)))
+            .collect();
+        Grid {
+            cells,
+            rows,
+            columns,
+        }
+    }
+
+    pub fn from_cells(rows: usize, columns: usize, cells: Vec<T>) -> Option<Grid<T>> {
+        (cells.len() == rows * columns).then_some(Grid {
+            cells,
+            rows,
+            columns,
+        })
+    }
+
+    pub fn rows(&self) -> usize {
+        self.rows
+    }
+
+    pub fn columns(&self) -> usize {
+        self.columns
+    }
+
+    pub fn contains(&self, (row, column): (usize, usize)) -> bool {
+        row < self.rows && column < self.columns
+    }
+
+    pub fn get(&self, position: (usize, usize)) -> Option<&T> {
+        self.contains(position)
+            .then(|| &self.cells[position.0 * self.columns + position.1])
+    }
+
+    pub fn get_mut(&mut self, position: (usize, usize)) -> Option<&mut T> {
+        self.contains(position)
+            .then(|| &mut self.cells[position.0 * self.columns + position.1])
+    }
+
+    pub fn row(&self, row: usize) -> Option<&[T]> {
+        (row < self.rows).then(|| &self.cells[row * self.columns..(row + 1) * self.columns])
+    }
+
+    pub fn column(&self, column: usize) -> Option<impl DoubleEndedIterator<Item = &T>> {
+        (column < self.columns).then(|| (0..self.rows).map(move |row| &self[(row, column)]))
+    }
+
+    pub fn iter_rows(&self) -> impl DoubleEndedIterator<Item = &[T]> {
+        (0..self.rows).map(|row| &self.cells[row * self.columns..(row + 1) * self.columns])
+    }
+
+    pub fn positions(&self) -> impl Iterator<Item = (usize, usize)> {
+        let columns = self.columns;
+        (0..self.rows * columns).map(move |idx| (idx / columns, idx % columns))
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = ((usize, usize), &T)> {
+        self.positions().zip(self.cells.iter())
+    }
+
+    pub fn position(&self, mut predicate: impl FnMut(&T) -> bool) -> Option<(usize, usize)> {
+        self.iter()
+            .find(|(_, cell)| predicate(cell))
+            .map(|(position, _)| position)
+    }
+
+    pub fn step(
+        &self,
+        (row, column): (usize, usize),
+        dir: impl Into<Dir8>,
+    ) -> Option<(usize, usize)> {
+        let delta = dir.into().delta();
+        let position = (
+            row.checked_add_signed(delta.y as isize)?,
+            column.checked_add_signed(delta.x as isize)?,
+        );
+        self.contains(position).then_some(position)
+    }
+
+    pub fn neighbours4(
+        &self,
+        position: (usize, usize),
+    ) -> impl Iterator<Item = (usize, usize)> + '_ {
+        Dir4::ALL
+            .into_iter()
+            .filter_map(move |dir| self.step(position, dir))
+    }
+
+    pub fn neighbours8(
+        &self,
+        position: (usize, usize),
+    ) -> impl Iterator<Item = (usize, usize)> + '_ {
+        Dir8::ALL
+            .into_iter()
+            .filter_map(move |dir| self.step(position, dir))
+    }
+
+    pub fn map<U>(&self, mut f: impl FnMut(&T) -> U) -> Grid<U> {
+        Grid {
+            cells: self.cells.iter().map(&mut f).collect(),
+            rows: self.rows,
+            columns: self.columns,
+        }
+    }
+
+    pub fn transpose(&self) -> Grid<T>
+    where
+        T: Clone,
+    {
+        Grid::from_fn(self.columns, self.rows, |(row, column)| {
+            self[(column, row)].clone()
+        })
+    }
+
+    pub fn rotate_clockwise(&self) -> Grid<T>
+    where
+        T: Clone,
+    {
+        Grid::from_fn(self.columns, self.rows, |(row, column)| {
+            self[(self.rows - 1 - column, row)].clone()
+        })
+    }
+
+    pub fn rotate_counterclockwise(&self) -> Grid<T>
+    where
+        T: Clone,
+    {
+        Grid::from_fn(self.columns, self.rows, |(row, column)| {
+            self[(column, self.columns - 1 - row)].clone()
+        })
+    }
+}
+
+impl<T> Index<(usize, usize)> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, position: (usize, usize)) -> &T {
+        let (rows, columns) = (self.rows, self.columns);
+        self.get(position).unwrap_or_else(|| {
+            panic!("position {position:?} out of bounds for {rows}x{columns} grid")
+        })
+    }
+}
+
+impl<T> IndexMut<(usize, usize)> for Grid<T> {
+    fn index_mut(&mut self, position: (usize, usize)) -> &mut T {
+        let (rows, columns) = (self.rows, self.columns);
+        self.get_mut(position).unwrap_or_else(|| {
+            panic!("position {position:?} out of bounds for {rows}x{columns} grid")
+        })
+    }
+}
+
+impl<T> FromStr for Grid<T>
+where
+    T: TryFrom<char>,
+    T::Error: Into<Box<dyn Error + Send + Sync>>,
+{
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut cells = Vec::new();
+        let mut rows = 0;
+        let mut columns = None;
+        for line in s.lines() {
+            let start = cells.len();
+            for (idx, c) in line.char_indices() {
+                let cell = T::try_from(c).map_err(|err| {
+                    ParseError::invalid("cell", &line[idx..idx + c.len_utf8()]).with_cause(err)
+                })?;
+                cells.push(cell);
+            }
+            let width = cells.len() - start;
+            if *columns.get_or_insert(width) != width {
+                return Err(ParseError::new("grid rows must have equal length").span(line));
+            }
+            rows += 1;
+        }
+        match columns {
+            Some(columns) if columns > 0 => Ok(Grid {
+                cells,
+                rows,
+                columns,
+            }),
+            _ => Err(ParseError::missing("grid")),
+        }
+    }
+}
+
+impl<T: Display> Display for Grid<T> {
+    fn fmt(&self, f: &mut Formatter) -> std::fmt::Result {
+        for (idx, row) in self.iter_rows().enumerate() {
+            if idx > 0 {
+                writeln!(f)?;
+            }
+            for cell in row {
+                write!(f, "{cell}")?;
+            }
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Grid;
+    use crate::{
+        geom::{Dir4, Dir8},
+        ParseError,
+    };
+    use std::fmt::Display;
+
+    #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+    enum Tile {
+        Empty,
+        Wall,
+    }
+
+    impl TryFrom<char> for Tile {
+        type Error = ParseError;
+
+        fn try_from(value: char) -> Result<Self, Self::Error> {
+            match value {
+                '.' => Ok(Tile::Empty),
+                '#' => Ok(Tile::Wall),
+                _ => Err(ParseError::new("unknown tile")),
+            }
+        }
+    }
+
+    impl Display for Tile {
+        fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+            match self {
+                Tile::Empty => write!(f, "."),
+                Tile::Wall => write!(f, "#"),
+            }
+        }
+    }
+
+    const MAZE: &str = "#..\n.#.\n..#\n##.";
+
+    #[test]
+    fn parse_and_display() {
+        let grid: Grid<Tile> = MAZE.parse().unwrap();
+        assert_eq!((grid.rows(), grid.columns()), (4, 3));
+        assert_eq!(grid[(1, 1)], Tile::Wall);
+        assert_eq!(grid[(1, 2)], Tile::Empty);
+        assert_eq!(grid.to_string(), MAZE);
+    }
+
+    #[test]
+    fn parse_errors() {
+        let source = "#..\n.x.";
+        let err = source.parse::<Grid<Tile>>().unwrap_err().locate(source);
+        assert_eq!(err.to_string(), "line 2, col 2: invalid cell 'x'");
+        let source = "#..\n.#";
+        let err = source.parse::<Grid<Tile>>().unwrap_err().locate(source);
+        assert_eq!(
+            err.to_string(),
+            "line 2, col 1: grid rows must have equal length"
+        );
+        assert!("".parse::<Grid<char>>().is_err());
+    }
+
+    #[test]
+    fn bounds_checked() {
+        let grid: Grid<char> = "ab\ncd".parse().unwrap();
+        assert_eq!(grid.get((0, 2)), None);
+        assert_eq!(grid.get((2, 0)), None);
+        assert_eq!(grid.get((1, 0)), Some(&'c'));
+        assert_eq!(grid.position(|&c| c == 'd'), Some((1, 1)));
+    }
+
+    #[test]
+    #[should_panic(expected = "out of bounds")]
+    fn index_out_of_bounds() {
+        let grid: Grid<char> = "ab\ncd".parse().unwrap();
+        let _ = grid[(0, 2)];
+    }
+
+    #[test]
+    fn neighbours() {
+        let grid = Grid::new(3, 3, 0);
+        assert_eq!(
+            grid.neighbours4((0, 0)).collect::<Vec<_>>(),
+            vec![(0, 1), (1, 0)]
+        );
+        assert_eq!(grid.neighbours4((1, 1)).count(), 4);
+        assert_eq!(
+            grid.neighbours8((0, 2)).collect::<Vec<_>>(),
+            vec![(1, 2), (1, 1), (0, 1)]
+        );
+        assert_eq!(grid.neighbours8((1, 1)).count(), 8);
+    }
+
+    #[test]
+    fn step_in_direction() {
+        let grid = Grid::new(2, 3, 0);
+        assert_eq!(grid.step((0, 0), Dir4::East), Some((0, 1)));
+        assert_eq!(grid.step((0, 0), Dir4::North), None);
+        assert_eq!(grid.step((0, 1), Dir8::SouthWest), Some((1, 0)));
+        assert_eq!(grid.step((1, 2), Dir8::SouthEast), None);
+    }
+
+    #[test]
+    fn rows_and_columns() {
+        let grid: Grid<char> = "abc\ndef".parse().unwrap();
+        assert_eq!(grid.row(1), Some(&['d', 'e', 'f'][..]));
+        assert_eq!(grid.row(2), None);
+        assert_eq!(
+            grid.column(2).unwrap().collect::<String>(),
+            "cf".to_string()
+        );
+        assert!(grid.column(3).is_none());
+        assert_eq!(grid.iter_rows().count(), 2);
+    }
+
+    #[test]
+    fn transform() {
+        let grid: Grid<char> = "abc\ndef".parse().unwrap();
+        assert_eq!(grid.transpose().to_string(), "ad\nbe\ncf");
+        assert_eq!(grid.rotate_clockwise().to_string(), "da\neb\nfc");
+        assert_eq!(grid.rotate_counterclockwise().to_string(), "cf\nbe\nad");
+        assert_eq!(
+            grid.rotate_clockwise().rotate_counterclockwise(),
+            grid.clone()
+        );
+        assert_eq!(grid.map(|c| c.to_ascii_uppercase()).to_string(), "ABC\nDEF");
+    }
+}
@@ -0,0 +1,48 @@
+use std::{
+    io::Read,
+    path::{Path, PathBuf},
+};
+
+pub const STDIN: &str = "-";
+
+pub fn is_stdin(path: &Path) -> bool {
+    path == Path::new(STDIN)
+}
+
+pub fn read_input(path: &Path) -> Result<String, String> {
+    if is_stdin(path) {
+        let mut input = String::new();
+        std::io::stdin()
+            .read_to_string(&mut input)
+            .map_err(|err| format!("could not read stdin: {err}"))?;
+        return Ok(input);
+    }
+    std::fs::read_to_string(path)
+        .map_err(|err| format!("could not read `{}`: {err}", path.display()))
+}
+
+pub fn input_path(default: &str) -> PathBuf {
+    std::env::args_os()
+        .nth(1)
+        .map(PathBuf::from)
+        .unwrap_or_else(|| PathBuf::from(default))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{is_stdin, read_input};
+    use std::path::Path;
+
+    #[test]
+    fn detect_stdin() {
+        assert!(is_stdin(Path::new("-")));
+        assert!(!is_stdin(Path::new("./-")));
+        assert!(!is_stdin(Path::new("input.txt")));
+    }
+
+    #[test]
+    fn fail_on_missing_file() {
+        let err = read_input(Path::new("no/such/input.txt")).unwrap_err();
+        assert!(err.starts_with("could not read `no/such/input.txt`: "));
+    }
+}
@@ -0,0 +1,372 @@
+use crate::math::Integer;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Interval<T> {
+    pub start: T,
+    pub end: T,
+}
+
+impl<T: Integer> Interval<T> {
+    pub const fn new(start: T, end: T) -> Interval<T> {
+        Interval { start, end }
+    }
+
+    pub fn inclusive(first: T, last: T) -> Interval<T> {
+        Interval::new(first, last + T::ONE)
+    }
+
+    pub fn with_len(start: T, len: T) -> Interval<T> {
+        Interval::new(start, start + len)
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.end <= self.start
+    }
+
+    pub fn len(&self) -> T {
+        if self.is_empty() {
+            T::ZERO
+        } else {
+            self.end - self.start
+        }
+    }
+
+    pub fn last(&self) -> Option<T> {
+        (!self.is_empty()).then(|| self.end - T::ONE)
+    }
+
+    pub fn contains(&self, value: T) -> bool {
+        self.start <= value && value < self.end
+    }
+
+    pub fn covers(&self, other: &Interval<T>) -> bool {
+        other.is_empty() || (self.start <= other.start && other.end <= self.end)
+    }
+
+    pub fn overlaps(&self, other: &Interval<T>) -> bool {
+        self.intersection(other).is_some()
+    }
+
+    pub fn intersection(&self, other: &Interval<T>) -> Option<Interval<T>> {
+        Interval::new(self.start.max(other.start), self.end.min(other.end)).non_empty()
+    }
+
+    pub fn union(&self, other: &Interval<T>) -> Option<Interval<T>> {
+        if self.is_empty() {
+            Some(*other)
+        } else if other.is_empty() {
+            Some(*self)
+        } else if self.start <= other.end && other.start <= self.end {
+            Some(Interval::new(
+                self.start.min(other.start),
+                self.end.max(other.end),
+            ))
+        } else {
+            None
+        }
+    }
+
+    pub fn difference(&self, other: &Interval<T>) -> (Option<Interval<T>>, Option<Interval<T>>) {
+        if other.is_empty() {
+            return (self.non_empty(), None);
+        }
+        (
+            Interval::new(self.start, self.end.min(other.start)).non_empty(),
+            Interval::new(self.start.max(other.end), self.end).non_empty(),
+        )
+    }
+
+    pub fn split_at(&self, point: T) -> (Option<Interval<T>>, Option<Interval<T>>) {
+        (
+            Interval::new(self.start, self.end.min(point)).non_empty(),
+            Interval::new(self.start.max(point), self.end).non_empty(),
+        )
+    }
+
+    fn non_empty(self) -> Option<Interval<T>> {
+        (!self.is_empty()).then_some(self)
+    }
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Eq, Hash)]
+pub struct IntervalSet<T> {
+    intervals: Vec<Interval<T>>,
+}
+
+impl<T: Integer> IntervalSet<T> {
+    pub fn new() -> IntervalSet<T> {
+        IntervalSet {
+            intervals: Vec::new(),
+        }
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.intervals.is_empty()
+    }
+
+    pub fn len(&self) -> T {
+        self.intervals
+            .iter()
+            .fold(T::ZERO, |len, interval| len + interval.len())
+    }
+
+    pub fn first(&self) -> Option<T> {
+        self.intervals.first().map(|interval| interval.start)
+    }
+
+    pub fn last(&self) -> Option<T> {
+        self.intervals.last().and_then(Interval::last)
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = &Interval<T>> {
+        self.intervals.iter()
+    }
+
+    pub fn contains(&self, value: T) -> bool {
+        let idx = self
+            .intervals
+            .partition_point(|interval| interval.end <= value);
+        self.intervals
+            .get(idx)
+            .is_some_and(|interval| interval.contains(value))
+    }
+
+    pub fn insert(&mut self, interval: Interval<T>) {
+        if interval.is_empty() {
+            return;
+        }
+        let lo = self
+            .intervals
+            .partition_point(|other| other.end < interval.start);
+        let hi = self
+            .intervals
+            .partition_point(|other| other.start <= interval.end);
+        let mut merged = interval;
+        if lo < hi {
+            merged.start = merged.start.min(self.intervals[lo].start);
+            merged.end = merged.end.max(self.intervals[hi - 1].end);
+        }
+        self.intervals.splice(lo..hi, [merged]);
+    }
+
+    pub fn remove(&mut self, interval: Interval<T>) {
+        if interval.is_empty() {
+            return;
+        }
+        let lo = self
+            .intervals
+            .partition_point(|other| other.end <= interval.start);
+        let hi = self
+            .intervals
+            .partition_point(|other| other.start < interval.end);
+        if lo == hi {
+            return;
+        }
+        let (first, last) = (self.intervals[lo], self.intervals[hi - 1]);
+        let left = first.difference(&interval).0;
+        let right = last.difference(&interval).1;
+        self.intervals.splice(lo..hi, left.into_iter().chain(right));
+    }
+
+    pub fn union(&self, other: &IntervalSet<T>) -> IntervalSet<T> {
+        let mut union = self.clone();
+        other.iter().for_each(|&interval| union.insert(interval));
+        union
+    }
+
+    pub fn difference(&self, other: &IntervalSet<T>) -> IntervalSet<T> {
+        let mut difference = self.clone();
+        other
+            .iter()
+            .for_each(|&interval| difference.remove(interval));
+        difference
+    }
+
+    pub fn intersection(&self, other: &IntervalSet<T>) -> IntervalSet<T> {
+        self.difference(&self.difference(other))
+    }
+
+    pub fn split_at(&self, point: T) -> (IntervalSet<T>, IntervalSet<T>) {
+        let (mut below, mut above) = (IntervalSet::new(), IntervalSet::new());
+        for interval in &self.intervals {
+            let (left, right) = interval.split_at(point);
+            below.intervals.extend(left);
+            above.intervals.extend(right);
+        }
+        (below, above)
+    }
+}
+
+impl<T: Integer> From<Interval<T>> for IntervalSet<T> {
+    fn from(interval: Interval<T>) -> Self {
+        IntervalSet::from_iter([interval])
+    }
+}
+
+impl<T: Integer> FromIterator<Interval<T>> for IntervalSet<T> {
+    fn from_iter<I: IntoIterator<Item = Interval<T>>>(iter: I) -> Self {
+        let mut set = IntervalSet::new();
+        iter.into_iter().for_each(|interval| set.insert(interval));
+        set
+    }
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Eq, Hash)]
+pub struct IntervalMap<T> {
+    offsets: Vec<(Interval<T>, T)>,
+}
+
+impl<T: Integer> IntervalMap<T> {
+    pub fn new() -> IntervalMap<T> {
+        IntervalMap {
+            offsets: Vec::new(),
+        }
+    }
+
+    pub fn len(&self) -> usize {
+        self.offsets.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.offsets.is_empty()
+    }
+
+    pub fn insert(&mut self, source: Interval<T>, destination: T) {
+        self.offsets.push((source, destination));
+    }
+
+    pub fn map(&self, value: T) -> T {
+        self.offsets
+            .iter()
+            .find(|(source, _)| source.contains(value))
+            .map_or(value, |&(source, destination)| {
+                destination + (value - source.start)
+            })
+    }
+
+    pub fn map_set(&self, set: &IntervalSet<T>) -> IntervalSet<T> {
+        let mut unmapped = set.clone();
+        let mut mapped = IntervalSet::new();
+        for &(source, destination) in &self.offsets {
+            let overlap = unmapped.intersection(&source.into());
+            for interval in overlap.iter() {
+                let start = destination + (interval.start - source.start);
+                mapped.insert(Interval::with_len(start, interval.len()));
+                unmapped.remove(*interval);
+            }
+        }
+        mapped.union(&unmapped)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Interval, IntervalMap, IntervalSet};
+
+    #[test]
+    fn construction() {
+        assert_eq!(Interval::inclusive(2, 4), Interval::new(2, 5));
+        assert_eq!(Interval::with_len(79, 14), Interval::new(79, 93));
+        assert_eq!(Interval::inclusive(3, 3).len(), 1);
+        assert_eq!(Interval::new(5, 3).len(), 0);
+        assert_eq!(Interval::new(2, 5).last(), Some(4));
+        assert!(Interval::new(4, 4).is_empty());
+    }
+
+    #[test]
+    fn interval_operations() {
+        let a = Interval::new(0, 10);
+        let b = Interval::new(5, 15);
+        assert_eq!(a.intersection(&b), Some(Interval::new(5, 10)));
+        assert_eq!(a.union(&b), Some(Interval::new(0, 15)));
+        assert_eq!(a.union(&Interval::new(10, 12)), Some(Interval::new(0, 12)));
+        assert_eq!(a.union(&Interval::new(11, 12)), None);
+        assert_eq!(
+            a.difference(&Interval::new(3, 6)),
+            (Some(Interval::new(0, 3)), Some(Interval::new(6, 10)))
+        );
+        assert_eq!(a.difference(&b), (Some(Interval::new(0, 5)), None));
+        assert_eq!(
+            a.split_at(4),
+            (Some(Interval::new(0, 4)), Some(Interval::new(4, 10)))
+        );
+        assert_eq!(a.split_at(12), (Some(a), None));
+        assert!(a.covers(&Interval::new(2, 8)));
+        assert!(!a.covers(&b));
+        assert!(!a.overlaps(&Interval::new(10, 20)));
+    }
+
+    #[test]
+    fn set_merges() {
+        let set: IntervalSet<i32> = [
+            Interval::new(5, 8),
+            Interval::new(0, 2),
+            Interval::new(2, 3),
+            Interval::new(7, 10),
+            Interval::new(20, 25),
+        ]
+        .into_iter()
+        .collect();
+        let intervals: Vec<_> = set.iter().copied().collect();
+        assert_eq!(
+            intervals,
+            vec![
+                Interval::new(0, 3),
+                Interval::new(5, 10),
+                Interval::new(20, 25)
+            ]
+        );
+        assert_eq!(set.len(), 13);
+        assert!(set.contains(9));
+        assert!(!set.contains(10));
+        assert!(!set.contains(-1));
+        assert_eq!(set.first(), Some(0));
+        assert_eq!(set.last(), Some(24));
+    }
+
+    #[test]
+    fn set_operations() {
+        let a: IntervalSet<i32> = [Interval::new(0, 10), Interval::new(20, 30)]
+            .into_iter()
+            .collect();
+        let b: IntervalSet<i32> = [Interval::new(5, 25)].into_iter().collect();
+        let intervals = |set: IntervalSet<i32>| set.iter().copied().collect::<Vec<_>>();
+        assert_eq!(intervals(a.union(&b)), vec![Interval::new(0, 30)]);
+        assert_eq!(
+            intervals(a.intersection(&b)),
+            vec![Interval::new(5, 10), Interval::new(20, 25)]
+        );
+        assert_eq!(
+            intervals(a.difference(&b)),
+            vec![Interval::new(0, 5), Interval::new(25, 30)]
+        );
+        let (below, above) = a.split_at(22);
+        assert_eq!(
+            intervals(below),
+            vec![Interval::new(0, 10), Interval::new(20, 22)]
+        );
+        assert_eq!(intervals(above), vec![Interval::new(22, 30)]);
+        let mut c = a.clone();
+        c.remove(Interval::new(3, 4));
+        assert_eq!(c.len(), 19);
+        assert_eq!(c.iter().count(), 3);
+    }
+
+    #[test]
+    fn offset_map() {
+        let mut map = IntervalMap::new();
+        map.insert(Interval::with_len(98u64, 2), 50);
+        map.insert(Interval::with_len(50, 48), 52);
+        assert_eq!(map.map(79), 81);
+        assert_eq!(map.map(99), 51);
+        assert_eq!(map.map(10), 10);
+        let seeds = IntervalSet::from(Interval::with_len(90, 20));
+        let mapped = map.map_set(&seeds);
+        let intervals: Vec<_> = mapped.iter().copied().collect();
+        assert_eq!(
+            intervals,
+            vec![Interval::new(50, 52), Interval::new(92, 110)]
+        );
+        assert_eq!(mapped.len(), 20);
+    }
+}
@@ -0,0 +1,85 @@
+pub struct TupleIter<I, T> {
+    inner: I,
+    previous: Option<T>,
+}
+
+impl<I, T> Iterator for TupleIter<I, T>
+where
+    I: Iterator<Item = T>,
+    T: Clone,
+{
+    type Item = (T, T);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let previous = self.previous.clone()?;
+        self.previous = Some(self.inner.next()?);
+        Some((previous, self.previous.clone()?))
+    }
+}
+
+pub struct TripletIter<I, T> {
+    inner: I,
+    previous: Option<(T, T)>,
+}
+
+impl<I, T> Iterator for TripletIter<I, T>
+where
+    I: Iterator<Item = T>,
+    T: Clone,
+{
+    type Item = (T, T, T);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let previous = self.previous.as_mut()?;
+        let new = self.inner.next()?;
+        let res = Some((previous.0.clone(), previous.1.clone(), new.clone()));
+        std::mem::swap(&mut previous.0, &mut previous.1);
+        previous.1 = new;
+        res
+    }
+}
+
+impl<I> ExtIterator for I where I: Iterator {}
+
+pub trait ExtIterator: Iterator + Sized {
+    fn tuples(mut self) -> TupleIter<Self, Self::Item> {
+        let previous = self.next();
+        TupleIter {
+            inner: self,
+            previous,
+        }
+    }
+
+    fn triplet(mut self) -> TripletIter<Self, Self::Item> {
+        let previous = get_init(&mut self);
+        TripletIter {
+            inner: self,
+            previous,
+        }
+    }
+}
+
+fn get_init<I: Iterator>(iter: &mut I) -> Option<(I::Item, I::Item)> {
+    let first = iter.next()?;
+    let second = iter.next()?;
+    Some((first, second))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::ExtIterator;
+
+    #[test]
+    fn tuples() {
+        let pairs: Vec<_> = "abcd".chars().tuples().collect();
+        assert_eq!(pairs, vec![('a', 'b'), ('b', 'c'), ('c', 'd')]);
+        assert_eq!("a".chars().tuples().count(), 0);
+    }
+
+    #[test]
+    fn triplets() {
+        let triplets: Vec<_> = (1..=4).triplet().collect();
+        assert_eq!(triplets, vec![(1, 2, 3), (2, 3, 4)]);
+        assert_eq!((1..=2).triplet().count(), 0);
+    }
+}
@@ -1,23 +1,58 @@
-use std::fmt::Display;
+mod answers;
+mod day;
+mod error;
+mod input;
+mod solver;
+pub use answers::{Answers, Expected, Status};
+pub use day::Day;
+pub use error::ParseError;
+pub use input::{is_stdin, read_input, STDIN};
+pub use solver::{run, solve, Answer, Failure, Part, Report, Solver};
 
-pub fn aoc_main<F1, F2, R1, R2>(path: &str, part1: F1, part2: F2)
-where
-    F1: Fn(&str) -> R1,
-    F2: Fn(&str) -> R2,
-    R1: Display,
-    R2: Display,
-{
-    let input = std::fs::read_to_string(path).unwrap();
-    println!("Part 1: {}", part1(&input));
-    println!("Part 2: {}", part2(&input));
+pub fn aoc_main<S: Solver>(path: &str) {
+    match read_input(&input::input_path(path)) {
+        Ok(input) => println!("{}", run::<S>(&input, &Part::ALL)),
+        Err(err) => {
+            eprintln!("error: {err}");
+            std::process::exit(1);
+        }
+    }
 }
 
 #[macro_export]
 macro_rules! input {
     () => {
-        concat!(env!("CARGO_MANIFEST_DIR"), "/assets/input.txt")
+        $crate::input!("input")
+    };
+    ($name:literal) => {
+        concat!(env!("CARGO_MANIFEST_DIR"), "/assets/", $name, ".txt")
+    };
+}
+
+#[macro_export]
+macro_rules! day {
+    ($year:expr, $day:expr, $solver:ty) => {
+        $crate::Day {
+            year: $year,
+            day: $day,
+            name: env!("CARGO_PKG_NAME"),
+            assets: concat!(env!("CARGO_MANIFEST_DIR"), "/assets"),
+            run: $crate::run::<$solver>,
+        }
     };
 }
 
-pub mod gcd;
-pub mod lcm;
+pub mod cycle;
+pub mod geom;
+pub mod graph;
+pub mod grid;
+pub mod interval;
+pub mod iter;
+pub mod linalg;
+pub mod math;
+pub mod md5;
+pub mod memo;
+pub mod par;
+pub mod parse;
+pub mod rand;
+pub mod search;
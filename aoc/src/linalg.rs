@@ -0,0 +1,5 @@
+mod matrix;
+mod rational;
+
+pub use matrix::Matrix;
+pub use rational::Rational;
@@ -0,0 +1,300 @@
+use super::Rational;
+use crate::math::Integer;
+use std::ops::{Index, IndexMut, Mul, Neg};
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Matrix<T = i128> {
+    cells: Vec<Rational<T>>,
+    rows: usize,
+    columns: usize,
+}
+
+struct Elimination<T> {
+    pivots: Vec<usize>,
+    determinant: Rational<T>,
+}
+
+impl<T: Integer + Neg<Output = T>> Matrix<T> {
+    pub fn zeros(rows: usize, columns: usize) -> Matrix<T> {
+        Matrix {
+            cells: vec![Rational::ZERO; rows * columns],
+            rows,
+            columns,
+        }
+    }
+
+    pub fn identity(size: usize) -> Matrix<T> {
+        let mut matrix = Matrix::zeros(size, size);
+        for idx in 0..size {
+            matrix[(idx, idx)] = Rational::ONE;
+        }
+        matrix
+    }
+
+    pub fn from_rows<R, V>(rows: impl IntoIterator<Item = R>) -> Matrix<T>
+    where
+        R: IntoIterator<Item = V>,
+        V: Into<Rational<T>>,
+    {
+        let mut cells = Vec::new();
+        let (mut count, mut columns) = (0, None);
+        for row in rows {
+            let len = cells.len();
+            cells.extend(row.into_iter().map(Into::into));
+            let width = cells.len() - len;
+            assert_eq!(
+                *columns.get_or_insert(width),
+                width,
+                "matrix rows must have equal length"
+            );
+            count += 1;
+        }
+        Matrix {
+            cells,
+            rows: count,
+            columns: columns.unwrap_or(0),
+        }
+    }
+
+    pub fn column(values: impl IntoIterator<Item = impl Into<Rational<T>>>) -> Matrix<T> {
+        Matrix::from_rows(values.into_iter().map(|value| [value]))
+    }
+
+    pub fn rows(&self) -> usize {
+        self.rows
+    }
+
+    pub fn columns(&self) -> usize {
+        self.columns
+    }
+
+    pub fn row(&self, row: usize) -> &[Rational<T>] {
+        &self.cells[row * self.columns..(row + 1) * self.columns]
+    }
+
+    pub fn transpose(&self) -> Matrix<T> {
+        let mut transposed = Matrix::zeros(self.columns, self.rows);
+        for row in 0..self.rows {
+            for column in 0..self.columns {
+                transposed[(column, row)] = self[(row, column)];
+            }
+        }
+        transposed
+    }
+
+    pub fn augment(&self, rhs: &Matrix<T>) -> Matrix<T> {
+        assert_eq!(
+            self.rows, rhs.rows,
+            "augmented matrices must have equal rows"
+        );
+        Matrix::from_rows(
+            (0..self.rows).map(|row| self.row(row).iter().chain(rhs.row(row)).copied()),
+        )
+    }
+
+    pub fn reduced(&self) -> Matrix<T> {
+        let mut reduced = self.clone();
+        reduced.eliminate(self.columns);
+        reduced
+    }
+
+    pub fn rank(&self) -> usize {
+        self.clone().eliminate(self.columns).pivots.len()
+    }
+
+    pub fn determinant(&self) -> Option<Rational<T>> {
+        if self.rows != self.columns {
+            return None;
+        }
+        Some(self.clone().eliminate(self.columns).determinant)
+    }
+
+    pub fn inverse(&self) -> Option<Matrix<T>> {
+        if self.rows != self.columns {
+            return None;
+        }
+        let mut augmented = self.augment(&Matrix::identity(self.rows));
+        if augmented.eliminate(self.columns).pivots.len() < self.rows {
+            return None;
+        }
+        Some(Matrix::from_rows(
+            (0..self.rows).map(|row| augmented.row(row)[self.columns..].to_vec()),
+        ))
+    }
+
+    pub fn solve(&self, rhs: &[Rational<T>]) -> Option<Vec<Rational<T>>> {
+        if rhs.len() != self.rows {
+            return None;
+        }
+        let mut augmented = self.augment(&Matrix::column(rhs.iter().copied()));
+        let pivots = augmented.eliminate(self.columns).pivots;
+        if pivots.len() < self.columns {
+            return None;
+        }
+        if (pivots.len()..self.rows).any(|row| !augmented[(row, self.columns)].is_zero()) {
+            return None;
+        }
+        Some(
+            (0..self.columns)
+                .map(|row| augmented[(row, self.columns)])
+                .collect(),
+        )
+    }
+
+    fn eliminate(&mut self, columns: usize) -> Elimination<T> {
+        let mut pivots = Vec::new();
+        let mut determinant = Rational::ONE;
+        for column in 0..columns {
+            let row = pivots.len();
+            let Some(pivot) = (row..self.rows).find(|&idx| !self[(idx, column)].is_zero()) else {
+                determinant = Rational::ZERO;
+                continue;
+            };
+            if pivot != row {
+                self.swap_rows(pivot, row);
+                determinant = -determinant;
+            }
+            let scale = self[(row, column)];
+            determinant *= scale;
+            for idx in column..self.columns {
+                self[(row, idx)] /= scale;
+            }
+            for other in 0..self.rows {
+                let factor = self[(other, column)];
+                if other == row || factor.is_zero() {
+                    continue;
+                }
+                for idx in column..self.columns {
+                    let delta = factor * self[(row, idx)];
+                    self[(other, idx)] -= delta;
+                }
+            }
+            pivots.push(column);
+        }
+        Elimination {
+            pivots,
+            determinant,
+        }
+    }
+
+    fn swap_rows(&mut self, a: usize, b: usize) {
+        for column in 0..self.columns {
+            self.cells
+                .swap(a * self.columns + column, b * self.columns + column);
+        }
+    }
+}
+
+impl<T> Index<(usize, usize)> for Matrix<T> {
+    type Output = Rational<T>;
+
+    fn index(&self, (row, column): (usize, usize)) -> &Self::Output {
+        assert!(row < self.rows && column < self.columns, "out of bounds");
+        &self.cells[row * self.columns + column]
+    }
+}
+
+impl<T> IndexMut<(usize, usize)> for Matrix<T> {
+    fn index_mut(&mut self, (row, column): (usize, usize)) -> &mut Self::Output {
+        assert!(row < self.rows && column < self.columns, "out of bounds");
+        &mut self.cells[row * self.columns + column]
+    }
+}
+
+impl<T: Integer + Neg<Output = T>> Mul for &Matrix<T> {
+    type Output = Matrix<T>;
+
+    fn mul(self, rhs: Self) -> Self::Output {
+        assert_eq!(self.columns, rhs.rows, "incompatible matrix dimensions");
+        let mut product = Matrix::zeros(self.rows, rhs.columns);
+        for row in 0..self.rows {
+            for column in 0..rhs.columns {
+                product[(row, column)] = (0..self.columns)
+                    .map(|idx| self[(row, idx)] * rhs[(idx, column)])
+                    .fold(Rational::ZERO, |sum, value| sum + value);
+            }
+        }
+        product
+    }
+}
+
+impl<T: Integer + Neg<Output = T>> Mul for Matrix<T> {
+    type Output = Matrix<T>;
+
+    fn mul(self, rhs: Self) -> Self::Output {
+        &self * &rhs
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Matrix, Rational};
+
+    fn r(numer: i128, denom: i128) -> Rational {
+        Rational::new(numer, denom)
+    }
+
+    #[test]
+    fn multiply_and_transpose() {
+        let a: Matrix = Matrix::from_rows([[1, 2, 3], [4, 5, 6]]);
+        let b = Matrix::from_rows([[10, 11], [20, 21], [30, 31]]);
+        assert_eq!(&a * &b, Matrix::from_rows([[140, 146], [320, 335]]));
+        assert_eq!(a.transpose(), Matrix::from_rows([[1, 4], [2, 5], [3, 6]]));
+        let column: Matrix = Matrix::column([1, 2, 3]);
+        assert_eq!(column.transpose() * column, Matrix::from_rows([[14]]));
+    }
+
+    #[test]
+    fn determinant() {
+        let matrix: Matrix =
+            Matrix::from_rows([[1, 0, 4, -6], [2, 5, 0, 3], [-1, 2, 3, 5], [2, 1, -2, 3]]);
+        assert_eq!(matrix.determinant(), Some(r(318, 1)));
+        let singular: Matrix = Matrix::from_rows([[1, 2], [2, 4]]);
+        assert_eq!(singular.determinant(), Some(Rational::ZERO));
+        assert_eq!(Matrix::<i128>::zeros(2, 3).determinant(), None);
+    }
+
+    #[test]
+    fn inverse() {
+        let matrix: Matrix = Matrix::from_rows([[1, 5, 2], [0, -1, 2], [0, 0, 1]]);
+        let inverse = matrix.inverse().unwrap();
+        assert_eq!(
+            inverse,
+            Matrix::from_rows([[1, 5, -12], [0, -1, 2], [0, 0, 1]])
+        );
+        assert_eq!(&matrix * &inverse, Matrix::identity(3));
+        let singular: Matrix = Matrix::from_rows([[1, 2], [2, 4]]);
+        assert_eq!(singular.inverse(), None);
+    }
+
+    #[test]
+    fn rank() {
+        let matrix: Matrix = Matrix::from_rows([[1, 2, 3], [2, 4, 6], [1, 0, 1]]);
+        assert_eq!(matrix.rank(), 2);
+        assert_eq!(Matrix::<i128>::identity(4).rank(), 4);
+        assert_eq!(Matrix::<i128>::zeros(3, 2).rank(), 0);
+    }
+
+    #[test]
+    fn solve() {
+        let matrix: Matrix = Matrix::from_rows([[2, 1], [1, 3]]);
+        let solution = matrix.solve(&[r(3, 1), r(5, 1)]).unwrap();
+        assert_eq!(solution, vec![r(4, 5), r(7, 5)]);
+        let overdetermined: Matrix = Matrix::from_rows([[1, 0], [0, 1], [1, 1]]);
+        assert_eq!(
+            overdetermined.solve(&[r(1, 1), r(2, 1), r(3, 1)]),
+            Some(vec![r(1, 1), r(2, 1)])
+        );
+        assert_eq!(overdetermined.solve(&[r(1, 1), r(2, 1), r(4, 1)]), None);
+        let singular: Matrix = Matrix::from_rows([[1, 2], [2, 4]]);
+        assert_eq!(singular.solve(&[r(1, 1), r(2, 1)]), None);
+        assert_eq!(matrix.solve(&[r(3, 1)]), None);
+        assert_eq!(matrix.solve(&[r(3, 1), r(5, 1), r(7, 1)]), None);
+    }
+
+    #[test]
+    fn reduced_row_echelon() {
+        let matrix: Matrix = Matrix::from_rows([[2, 4, 2], [1, 3, 2]]);
+        assert_eq!(matrix.reduced(), Matrix::from_rows([[1, 0, -1], [0, 1, 1]]));
+    }
+}
@@ -0,0 +1,310 @@
+use crate::{math::Integer, ParseError};
+use std::{
+    cmp::Ordering,
+    fmt::{self, Display, Formatter},
+    ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Neg, Sub, SubAssign},
+    str::FromStr,
+};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Rational<T = i128> {
+    numer: T,
+    denom: T,
+}
+
+impl<T: Integer + Neg<Output = T>> Rational<T> {
+    pub const ZERO: Rational<T> = Rational {
+        numer: T::ZERO,
+        denom: T::ONE,
+    };
+    pub const ONE: Rational<T> = Rational {
+        numer: T::ONE,
+        denom: T::ONE,
+    };
+
+    pub fn new(numer: T, denom: T) -> Rational<T> {
+        assert!(denom != T::ZERO, "zero denominator");
+        let gcd = numer.gcd(denom);
+        let (numer, denom) = (numer / gcd, denom / gcd);
+        if denom < T::ZERO {
+            Rational {
+                numer: -numer,
+                denom: -denom,
+            }
+        } else {
+            Rational { numer, denom }
+        }
+    }
+
+    pub fn numer(self) -> T {
+        self.numer
+    }
+
+    pub fn denom(self) -> T {
+        self.denom
+    }
+
+    pub fn is_zero(self) -> bool {
+        self.numer == T::ZERO
+    }
+
+    pub fn is_integer(self) -> bool {
+        self.denom == T::ONE
+    }
+
+    pub fn to_integer(self) -> Option<T> {
+        self.is_integer().then_some(self.numer)
+    }
+
+    pub fn recip(self) -> Option<Rational<T>> {
+        (!self.is_zero()).then(|| Rational::new(self.denom, self.numer))
+    }
+
+    pub fn abs(self) -> Rational<T> {
+        if self.numer < T::ZERO {
+            -self
+        } else {
+            self
+        }
+    }
+
+    pub fn floor(self) -> T {
+        let quotient = self.numer / self.denom;
+        if self.numer % self.denom < T::ZERO {
+            quotient - T::ONE
+        } else {
+            quotient
+        }
+    }
+}
+
+fn checked<T>(value: Option<T>) -> T {
+    value.expect("rational overflow")
+}
+
+impl<T: Integer + Neg<Output = T>> From<T> for Rational<T> {
+    fn from(value: T) -> Self {
+        Rational {
+            numer: value,
+            denom: T::ONE,
+        }
+    }
+}
+
+impl<T: Integer + Neg<Output = T>> Add for Rational<T> {
+    type Output = Rational<T>;
+
+    fn add(self, rhs: Self) -> Self::Output {
+        let gcd = self.denom.gcd(rhs.denom);
+        let (left, right) = (self.denom / gcd, rhs.denom / gcd);
+        let numer = checked(
+            checked(self.numer.checked_mul(right))
+                .checked_add(checked(rhs.numer.checked_mul(left))),
+        );
+        Rational::new(numer, checked(left.checked_mul(rhs.denom)))
+    }
+}
+
+impl<T: Integer + Neg<Output = T>> Sub for Rational<T> {
+    type Output = Rational<T>;
+
+    fn sub(self, rhs: Self) -> Self::Output {
+        self + -rhs
+    }
+}
+
+impl<T: Integer + Neg<Output = T>> Mul for Rational<T> {
+    type Output = Rational<T>;
+
+    fn mul(self, rhs: Self) -> Self::Output {
+        let (a, b) = (self.numer.gcd(rhs.denom), rhs.numer.gcd(self.denom));
+        if a == T::ZERO || b == T::ZERO {
+            return Rational::ZERO;
+        }
+        Rational::new(
+            checked((self.numer / a).checked_mul(rhs.numer / b)),
+            checked((self.denom / b).checked_mul(rhs.denom / a)),
+        )
+    }
+}
+
+impl<T: Integer + Neg<Output = T>> Div for Rational<T> {
+    type Output = Rational<T>;
+
+    fn div(self, rhs: Self) -> Self::Output {
+        assert!(!rhs.is_zero(), "division by zero");
+        let (a, b) = (self.numer.gcd(rhs.numer), self.denom.gcd(rhs.denom));
+        Rational::new(
+            checked((self.numer / a).checked_mul(rhs.denom / b)),
+            checked((self.denom / b).checked_mul(rhs.numer / a)),
+        )
+    }
+}
+
+impl<T: Integer + Neg<Output = T>> Neg for Rational<T> {
+    type Output = Rational<T>;
+
+    fn neg(self) -> Self::Output {
+        Rational {
+            numer: -self.numer,
+            denom: self.denom,
+        }
+    }
+}
+
+macro_rules! impl_assign {
+    ($($trait:ident :: $method:ident => $op:tt),*) => {
+        $(impl<T: Integer + Neg<Output = T>> $trait for Rational<T> {
+            fn $method(&mut self, rhs: Self) {
+                *self = *self $op rhs;
+            }
+        })*
+    };
+}
+
+impl_assign!(
+    AddAssign::add_assign => +,
+    SubAssign::sub_assign => -,
+    MulAssign::mul_assign => *,
+    DivAssign::div_assign => /
+);
+
+impl<T: Integer + Neg<Output = T>> PartialOrd for Rational<T> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<T: Integer + Neg<Output = T>> Ord for Rational<T> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        let sign = self.numer.cmp(&T::ZERO);
+        if sign != other.numer.cmp(&T::ZERO) {
+            return sign.cmp(&other.numer.cmp(&T::ZERO));
+        }
+        match (
+            self.numer.checked_mul(other.denom),
+            other.numer.checked_mul(self.denom),
+        ) {
+            (Some(lhs), Some(rhs)) => lhs.cmp(&rhs),
+            _ => compare_fractions((self.numer, self.denom), (other.numer, other.denom)),
+        }
+    }
+}
+
+fn compare_fractions<T: Integer>(mut lhs: (T, T), mut rhs: (T, T)) -> Ordering {
+    loop {
+        let (lhs_floor, lhs_rem) = floor_div(lhs);
+        let (rhs_floor, rhs_rem) = floor_div(rhs);
+        if lhs_floor != rhs_floor {
+            return lhs_floor.cmp(&rhs_floor);
+        }
+        match (lhs_rem == T::ZERO, rhs_rem == T::ZERO) {
+            (true, true) => return Ordering::Equal,
+            (true, false) => return Ordering::Less,
+            (false, true) => return Ordering::Greater,
+            (false, false) => (lhs, rhs) = ((rhs.1, rhs_rem), (lhs.1, lhs_rem)),
+        }
+    }
+}
+
+fn floor_div<T: Integer>((numer, denom): (T, T)) -> (T, T) {
+    let (quotient, rem) = (numer / denom, numer % denom);
+    if rem < T::ZERO {
+        (quotient - T::ONE, rem + denom)
+    } else {
+        (quotient, rem)
+    }
+}
+
+impl<T: Display + Integer + Neg<Output = T>> Display for Rational<T> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        if self.is_integer() {
+            write!(f, "{}", self.numer)
+        } else {
+            write!(f, "{}/{}", self.numer, self.denom)
+        }
+    }
+}
+
+impl<T: Integer + Neg<Output = T> + FromStr> FromStr for Rational<T> {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (numer, denom) = s.split_once('/').unwrap_or((s, "1"));
+        let parse = |part: &str| {
+            part.trim()
+                .parse::<T>()
+                .map_err(|_| ParseError::invalid("rational", s))
+        };
+        let (numer, denom) = (parse(numer)?, parse(denom)?);
+        if denom == T::ZERO {
+            return Err(ParseError::new("zero denominator").span(s));
+        }
+        Ok(Rational::new(numer, denom))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Rational;
+    use std::cmp::Ordering;
+
+    fn r(numer: i128, denom: i128) -> Rational {
+        Rational::new(numer, denom)
+    }
+
+    #[test]
+    fn normalise() {
+        assert_eq!(r(2, 4), r(1, 2));
+        assert_eq!(r(3, -6), r(-1, 2));
+        assert_eq!(r(0, -5), Rational::ZERO);
+        assert_eq!(r(-1, 2).denom(), 2);
+        assert_eq!(r(6, 3).to_integer(), Some(2));
+        assert_eq!(r(7, 3).to_integer(), None);
+    }
+
+    #[test]
+    fn arithmetic() {
+        assert_eq!(r(1, 2) + r(1, 3), r(5, 6));
+        assert_eq!(r(1, 2) - r(1, 3), r(1, 6));
+        assert_eq!(r(2, 3) * r(9, 4), r(3, 2));
+        assert_eq!(r(2, 3) / r(4, 9), r(3, 2));
+        assert_eq!(-r(1, 2), r(-1, 2));
+        assert_eq!(r(0, 1).recip(), None);
+        let mut x = r(1, 4);
+        x += r(3, 4);
+        x *= r(5, 1);
+        assert_eq!(x, Rational::from(5));
+    }
+
+    #[test]
+    fn ordering() {
+        assert!(r(1, 3) < r(1, 2));
+        assert!(r(-1, 2) < r(-1, 3));
+        assert_eq!(r(-7, 2).floor(), -4);
+        assert_eq!(r(7, 2).floor(), 3);
+        assert_eq!(r(-7, 2).abs(), r(7, 2));
+    }
+
+    #[test]
+    fn ordering_without_overflow() {
+        let max = i64::MAX;
+        let r = Rational::<i64>::new;
+        assert!(r(max, max - 1) < r(max - 1, max - 2));
+        assert!(r(-max, max - 1) > r(-(max - 1), max - 2));
+        assert!(r(max, 2) > r(-max, 3));
+        assert!(r(max - 1, max) < r(1, 1));
+        assert!(r(max, max - 2) > r(max - 1, max - 2));
+        assert_eq!(r(max, max - 1).cmp(&r(max, max - 1)), Ordering::Equal);
+    }
+
+    #[test]
+    fn format_and_parse() {
+        assert_eq!(r(43, 3).to_string(), "43/3");
+        assert_eq!(r(-4, 2).to_string(), "-2");
+        assert_eq!("6/-4".parse::<Rational>().ok(), Some(r(-3, 2)));
+        assert_eq!("12".parse::<Rational>().ok(), Some(r(12, 1)));
+        assert!("1/0".parse::<Rational>().is_err());
+        assert!("a/2".parse::<Rational>().is_err());
+    }
+}
@@ -0,0 +1,279 @@
+use std::ops::{Add, Div, Mul, Rem, Sub};
+
+pub trait Integer:
+    Copy
+    + Ord
+    + Add<Output = Self>
+    + Sub<Output = Self>
+    + Mul<Output = Self>
+    + Div<Output = Self>
+    + Rem<Output = Self>
+{
+    const ZERO: Self;
+    const ONE: Self;
+
+    fn checked_abs(self) -> Option<Self>;
+    fn checked_add(self, rhs: Self) -> Option<Self>;
+    fn checked_sub(self, rhs: Self) -> Option<Self>;
+    fn checked_mul(self, rhs: Self) -> Option<Self>;
+
+    fn checked_gcd(self, rhs: Self) -> Option<Self> {
+        let (mut a, mut b) = (self, rhs);
+        while b != Self::ZERO {
+            (a, b) = (b, a % b);
+        }
+        a.checked_abs()
+    }
+
+    /// Panics if the result does not fit in `Self`, as in `i64::MIN.gcd(0)`.
+    fn gcd(self, rhs: Self) -> Self {
+        self.checked_gcd(rhs).expect("gcd overflows")
+    }
+
+    fn lcm(self, rhs: Self) -> Option<Self> {
+        if self == Self::ZERO || rhs == Self::ZERO {
+            return Some(Self::ZERO);
+        }
+        (self / self.checked_gcd(rhs)?)
+            .checked_mul(rhs)?
+            .checked_abs()
+    }
+}
+
+macro_rules! impl_integer {
+    (signed: $($ty:ty),*) => {
+        $(impl Integer for $ty {
+            const ZERO: Self = 0;
+            const ONE: Self = 1;
+
+            fn checked_abs(self) -> Option<Self> {
+                <$ty>::checked_abs(self)
+            }
+
+            fn checked_add(self, rhs: Self) -> Option<Self> {
+                <$ty>::checked_add(self, rhs)
+            }
+
+            fn checked_sub(self, rhs: Self) -> Option<Self> {
+                <$ty>::checked_sub(self, rhs)
+            }
+
+            fn checked_mul(self, rhs: Self) -> Option<Self> {
+                <$ty>::checked_mul(self, rhs)
+            }
+        })*
+    };
+    (unsigned: $($ty:ty),*) => {
+        $(impl Integer for $ty {
+            const ZERO: Self = 0;
+            const ONE: Self = 1;
+
+            fn checked_abs(self) -> Option<Self> {
+                Some(self)
+            }
+
+            fn checked_add(self, rhs: Self) -> Option<Self> {
+                <$ty>::checked_add(self, rhs)
+            }
+
+            fn checked_sub(self, rhs: Self) -> Option<Self> {
+                <$ty>::checked_sub(self, rhs)
+            }
+
+            fn checked_mul(self, rhs: Self) -> Option<Self> {
+                <$ty>::checked_mul(self, rhs)
+            }
+        })*
+    };
+}
+
+impl_integer!(signed: i8, i16, i32, i64, i128, isize);
+impl_integer!(unsigned: u8, u16, u32, u64, u128, usize);
+
+pub fn gcd_all<T: Integer>(values: impl IntoIterator<Item = T>) -> T {
+    values.into_iter().fold(T::ZERO, T::gcd)
+}
+
+pub fn lcm_all<T: Integer>(values: impl IntoIterator<Item = T>) -> Option<T> {
+    values
+        .into_iter()
+        .try_fold(T::ONE, |lcm, value| lcm.lcm(value))
+}
+
+pub fn extended_gcd(a: i128, b: i128) -> (i128, i128, i128) {
+    let (mut r0, mut r1) = (a, b);
+    let (mut s0, mut s1) = (1, 0);
+    let (mut t0, mut t1) = (0, 1);
+    while r1 != 0 {
+        let q = r0 / r1;
+        (r0, r1) = (r1, r0 - q * r1);
+        (s0, s1) = (s1, s0 - q * s1);
+        (t0, t1) = (t1, t0 - q * t1);
+    }
+    if r0 < 0 {
+        (-r0, -s0, -t0)
+    } else {
+        (r0, s0, t0)
+    }
+}
+
+pub fn mod_inverse(a: i128, modulus: i128) -> Option<i128> {
+    if modulus <= 0 {
+        return None;
+    }
+    match extended_gcd(a.rem_euclid(modulus), modulus) {
+        (1, x, _) => Some(x.rem_euclid(modulus)),
+        _ => None,
+    }
+}
+
+pub fn mul_mod(a: i128, b: i128, modulus: i128) -> Option<i128> {
+    if modulus <= 0 {
+        return None;
+    }
+    Some(mul_rem(a, b, modulus))
+}
+
+fn mul_rem(a: i128, b: i128, modulus: i128) -> i128 {
+    let m = modulus as u128;
+    let (mut a, mut b) = (a.rem_euclid(modulus) as u128, b.rem_euclid(modulus) as u128);
+    if let Some(product) = a.checked_mul(b) {
+        return (product % m) as i128;
+    }
+    let mut result = 0;
+    while b > 0 {
+        if b & 1 == 1 {
+            result = add_mod(result, a, m);
+        }
+        a = add_mod(a, a, m);
+        b >>= 1;
+    }
+    result as i128
+}
+
+fn add_mod(a: u128, b: u128, m: u128) -> u128 {
+    if a >= m - b {
+        a - (m - b)
+    } else {
+        a + b
+    }
+}
+
+pub fn mod_pow(base: i128, mut exp: u128, modulus: i128) -> Option<i128> {
+    if modulus <= 0 {
+        return None;
+    }
+    let mut base = base.rem_euclid(modulus);
+    let mut result = 1 % modulus;
+    while exp > 0 {
+        if exp & 1 == 1 {
+            result = mul_rem(result, base, modulus);
+        }
+        base = mul_rem(base, base, modulus);
+        exp >>= 1;
+    }
+    Some(result)
+}
+
+pub fn crt(congruences: impl IntoIterator<Item = (i128, i128)>) -> Option<(i128, i128)> {
+    congruences
+        .into_iter()
+        .try_fold((0, 1), |(r1, m1), (r2, m2)| {
+            if m2 <= 0 {
+                return None;
+            }
+            let (g, p, _) = extended_gcd(m1, m2);
+            let diff = r2.rem_euclid(m2) - r1;
+            if diff % g != 0 {
+                return None;
+            }
+            let step = m2 / g;
+            let modulus = (m1 / g).checked_mul(m2)?;
+            let k = mul_rem(diff / g, p, step);
+            let r = add_mod(r1 as u128, mul_rem(m1, k, modulus) as u128, modulus as u128);
+            Some((r as i128, modulus))
+        })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{crt, extended_gcd, gcd_all, lcm_all, mod_inverse, mod_pow, mul_mod, Integer};
+
+    #[test]
+    fn gcd_lcm() {
+        assert_eq!(12u64.gcd(18), 6);
+        assert_eq!((-12i32).gcd(18), 6);
+        assert_eq!(0usize.gcd(7), 7);
+        assert_eq!(0i128.gcd(0), 0);
+        assert_eq!(4u8.lcm(6), Some(12));
+        assert_eq!((-4i64).lcm(6), Some(12));
+        assert_eq!(0u32.lcm(5), Some(0));
+        assert_eq!(200u8.lcm(3), None);
+    }
+
+    #[test]
+    fn lcm_overflowing_gcd() {
+        assert_eq!(i64::MIN.checked_gcd(i64::MIN), None);
+        assert_eq!(i64::MIN.lcm(i64::MIN), None);
+        assert_eq!(i64::MIN.lcm(1), None);
+        assert_eq!(i64::MIN.checked_gcd(2), Some(2));
+    }
+
+    #[test]
+    fn over_iterators() {
+        assert_eq!(gcd_all([12, 18, 30]), 6);
+        assert_eq!(lcm_all([2usize, 3, 4, 5]), Some(60));
+        assert_eq!(lcm_all(Vec::<u64>::new()), Some(1));
+        assert_eq!(lcm_all([u64::MAX, 2]), None);
+    }
+
+    #[test]
+    fn extended() {
+        let (g, x, y) = extended_gcd(240, 46);
+        assert_eq!(g, 2);
+        assert_eq!(240 * x + 46 * y, 2);
+        let (g, x, y) = extended_gcd(-15, 10);
+        assert_eq!(g, 5);
+        assert_eq!(-15 * x + 10 * y, 5);
+        assert_eq!(mod_inverse(3, 11), Some(4));
+        assert_eq!(mod_inverse(-3, 11), Some(7));
+        assert_eq!(mod_inverse(6, 9), None);
+    }
+
+    #[test]
+    fn modular_arithmetic() {
+        assert_eq!(mod_pow(2, 10, 1000), Some(24));
+        assert_eq!(mod_pow(-2, 3, 7), Some(6));
+        assert_eq!(mod_pow(5, 0, 1), Some(0));
+        let p = i128::MAX;
+        assert_eq!(mul_mod(p - 1, p - 1, p), Some(1));
+        assert_eq!(mod_pow(3, p as u128 - 1, p), Some(1));
+        assert_eq!(mod_pow(3, (1 << 61) - 2, (1 << 61) - 1), Some(1));
+    }
+
+    #[test]
+    fn invalid_modulus() {
+        assert_eq!(mul_mod(3, 4, 0), None);
+        assert_eq!(mul_mod(3, 4, -5), None);
+        assert_eq!(mod_pow(3, 4, 0), None);
+        assert_eq!(mod_pow(3, 0, -5), None);
+    }
+
+    #[test]
+    #[should_panic(expected = "gcd overflows")]
+    fn gcd_overflow() {
+        i64::MIN.gcd(0);
+    }
+
+    #[test]
+    fn chinese_remainder() {
+        assert_eq!(crt([(2, 3), (3, 5), (2, 7)]), Some((23, 105)));
+        assert_eq!(
+            crt([(0, 7), (12, 13), (55, 59), (25, 31), (12, 19)]),
+            Some((1068781, 3162341))
+        );
+        assert_eq!(crt([(1, 4), (3, 6)]), Some((9, 12)));
+        assert_eq!(crt([(1, 4), (2, 6)]), None);
+        assert_eq!(crt([]), Some((0, 1)));
+    }
+}
@@ -0,0 +1,140 @@
+use std::{
+    borrow::Borrow,
+    collections::HashMap,
+    fmt::{self, Display, Formatter},
+    hash::Hash,
+};
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct Stats {
+    pub hits: usize,
+    pub misses: usize,
+}
+
+impl Stats {
+    pub fn lookups(&self) -> usize {
+        self.hits + self.misses
+    }
+
+    pub fn hit_rate(&self) -> f64 {
+        match self.lookups() {
+            0 => 0.0,
+            lookups => self.hits as f64 / lookups as f64,
+        }
+    }
+}
+
+impl Display for Stats {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{} hits, {} misses ({:.1}%)",
+            self.hits,
+            self.misses,
+            self.hit_rate() * 100.0
+        )
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct Memo<K, V> {
+    table: HashMap<K, V>,
+    stats: Stats,
+}
+
+impl<K, V> Default for Memo<K, V> {
+    fn default() -> Self {
+        Memo {
+            table: HashMap::new(),
+            stats: Stats::default(),
+        }
+    }
+}
+
+impl<K: Hash + Eq, V: Clone> Memo<K, V> {
+    pub fn new() -> Memo<K, V> {
+        Memo::default()
+    }
+
+    pub fn len(&self) -> usize {
+        self.table.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.table.is_empty()
+    }
+
+    pub fn stats(&self) -> Stats {
+        self.stats
+    }
+
+    pub fn get<Q>(&self, key: &Q) -> Option<&V>
+    where
+        K: Borrow<Q>,
+        Q: Hash + Eq + ?Sized,
+    {
+        self.table.get(key)
+    }
+
+    pub fn insert(&mut self, key: K, value: V) -> Option<V> {
+        self.table.insert(key, value)
+    }
+
+    pub fn clear(&mut self) {
+        self.table.clear();
+        self.stats = Stats::default();
+    }
+
+    pub fn get_or_compute(&mut self, key: K, compute: impl FnOnce(&mut Self) -> V) -> V {
+        if let Some(value) = self.table.get(&key) {
+            self.stats.hits += 1;
+            return value.clone();
+        }
+        self.stats.misses += 1;
+        let value = compute(self);
+        self.table.insert(key, value.clone());
+        value
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Memo, Stats};
+
+    fn fibonacci(memo: &mut Memo<u64, u64>, n: u64) -> u64 {
+        if n < 2 {
+            return n;
+        }
+        memo.get_or_compute(n, |memo| fibonacci(memo, n - 1) + fibonacci(memo, n - 2))
+    }
+
+    #[test]
+    fn recursive_compute() {
+        let mut memo = Memo::new();
+        assert_eq!(fibonacci(&mut memo, 90), 2_880_067_194_370_816_120);
+        assert_eq!(memo.len(), 89);
+        assert_eq!(
+            memo.stats(),
+            Stats {
+                hits: 87,
+                misses: 89
+            }
+        );
+        assert_eq!(memo.get(&10), Some(&55));
+        fibonacci(&mut memo, 90);
+        assert_eq!(memo.stats().hits, 88);
+        memo.clear();
+        assert!(memo.is_empty());
+        assert_eq!(memo.stats().lookups(), 0);
+    }
+
+    #[test]
+    fn borrowed_lookup() {
+        let mut memo: Memo<String, usize> = Memo::new();
+        let len = memo.get_or_compute("abc".to_string(), |_| 3);
+        assert_eq!(len, 3);
+        assert_eq!(memo.get("abc"), Some(&3));
+        assert_eq!(memo.get("abd"), None);
+        assert_eq!(memo.stats().to_string(), "0 hits, 1 misses (0.0%)");
+    }
+}
@@ -0,0 +1,138 @@
+use std::{
+    num::NonZeroUsize,
+    ops::Range,
+    sync::atomic::{AtomicUsize, Ordering},
+    thread,
+};
+
+pub fn threads() -> usize {
+    thread::available_parallelism().map_or(1, NonZeroUsize::get)
+}
+
+pub fn find_first(
+    range: Range<usize>,
+    chunk: usize,
+    predicate: impl Fn(usize) -> bool + Sync,
+) -> Option<usize> {
+    find_first_with(threads(), range, chunk, predicate)
+}
+
+pub fn map_reduce<T: Sync, R: Send>(
+    items: &[T],
+    map: impl Fn(&T) -> R + Sync,
+    reduce: impl Fn(R, R) -> R + Sync,
+) -> Option<R> {
+    map_reduce_with(threads(), items, map, reduce)
+}
+
+fn find_first_with(
+    threads: usize,
+    range: Range<usize>,
+    chunk: usize,
+    predicate: impl Fn(usize) -> bool + Sync,
+) -> Option<usize> {
+    assert!(chunk > 0, "empty chunk");
+    let next = AtomicUsize::new(range.start);
+    let found = AtomicUsize::new(0);
+    let worker = || {
+        while let Ok(start) = next.fetch_update(Ordering::Relaxed, Ordering::Relaxed, |start| {
+            (start < range.end).then(|| start.saturating_add(chunk).min(range.end))
+        }) {
+            let best = found.load(Ordering::Relaxed);
+            if best != 0 && start >= best - 1 {
+                break;
+            }
+            let end = start.saturating_add(chunk).min(range.end);
+            if let Some(idx) = (start..end).find(|&idx| predicate(idx)) {
+                let _ = found.fetch_update(Ordering::Relaxed, Ordering::Relaxed, |best| {
+                    (best == 0 || idx < best - 1).then_some(idx + 1)
+                });
+                break;
+            }
+        }
+    };
+    thread::scope(|scope| {
+        for _ in 1..threads {
+            scope.spawn(worker);
+        }
+        worker();
+    });
+    found.into_inner().checked_sub(1)
+}
+
+fn map_reduce_with<T: Sync, R: Send>(
+    threads: usize,
+    items: &[T],
+    map: impl Fn(&T) -> R + Sync,
+    reduce: impl Fn(R, R) -> R + Sync,
+) -> Option<R> {
+    let fold = |chunk: &[T]| chunk.iter().map(&map).reduce(&reduce);
+    if threads <= 1 || items.len() <= 1 {
+        return fold(items);
+    }
+    let chunk = items.len().div_ceil(threads);
+    thread::scope(|scope| {
+        let handles: Vec<_> = items
+            .chunks(chunk)
+            .map(|chunk| scope.spawn(move || fold(chunk)))
+            .collect();
+        handles
+            .into_iter()
+            .filter_map(|handle| handle.join().unwrap())
+            .reduce(&reduce)
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{find_first_with, map_reduce_with};
+
+    #[test]
+    fn first_match_is_smallest() {
+        for threads in [1, 2, 4, 7] {
+            let found = find_first_with(threads, 0..1_000_000, 64, |idx| {
+                idx >= 1_000 && idx % 997 == 0
+            });
+            assert_eq!(found, Some(1_994));
+            assert_eq!(
+                find_first_with(threads, 10..20, 3, |idx| idx > 15),
+                Some(16)
+            );
+            assert_eq!(find_first_with(threads, 0..100, 8, |_| false), None);
+            assert_eq!(find_first_with(threads, 5..5, 8, |_| true), None);
+        }
+    }
+
+    #[test]
+    fn stays_in_range_near_usize_max() {
+        let range = usize::MAX - 10..usize::MAX;
+        for threads in [1, 2, 7] {
+            let in_range = |idx| {
+                assert!(range.contains(&idx));
+                false
+            };
+            assert_eq!(find_first_with(threads, range.clone(), 4, in_range), None);
+            assert_eq!(
+                find_first_with(threads, range.clone(), 4, |idx| idx == usize::MAX - 1),
+                Some(usize::MAX - 1)
+            );
+            assert_eq!(
+                find_first_with(threads, range.clone(), usize::MAX, |_| true),
+                Some(usize::MAX - 10)
+            );
+        }
+    }
+
+    #[test]
+    fn reduce_keeps_order() {
+        let words: Vec<String> = (0..50).map(|idx| idx.to_string()).collect();
+        let expected = words.concat();
+        for threads in [1, 2, 3, 8, 64] {
+            let joined = map_reduce_with(threads, &words, String::clone, |a, b| a + &b);
+            assert_eq!(joined, Some(expected.clone()));
+            let sum = map_reduce_with(threads, &[1u64, 2, 3, 4, 5], |&x| x * x, |a, b| a + b);
+            assert_eq!(sum, Some(55));
+        }
+        assert_eq!(map_reduce_with(4, &[] as &[u8], |&x| x, u8::max), None);
+    }
+}
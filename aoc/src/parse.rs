@@ -0,0 +1,204 @@
+use crate::ParseError;
+use std::{error::Error, str::FromStr};
+
+pub fn complete<'a, T>(
+    s: &'a str,
+    mut parser: impl FnMut(&mut &'a str) -> Result<T, ParseError>,
+) -> Result<T, ParseError> {
+    let mut input = s;
+    let value = parser(&mut input)?;
+    end(&mut input)?;
+    Ok(value)
+}
+
+pub fn lines<'a, T>(
+    s: &'a str,
+    parser: impl FnMut(&'a str) -> Result<T, ParseError>,
+) -> Result<Vec<T>, ParseError> {
+    s.lines().map(parser).collect()
+}
+
+pub fn blocks<'a, T>(
+    s: &'a str,
+    parser: impl FnMut(&'a str) -> Result<T, ParseError>,
+) -> Result<Vec<T>, ParseError> {
+    s.trim_end_matches('\n').split("\n\n").map(parser).collect()
+}
+
+pub fn end(input: &mut &str) -> Result<(), ParseError> {
+    match *input {
+        "" => Ok(()),
+        rest => Err(ParseError::unexpected(rest)),
+    }
+}
+
+pub fn take_while<'a>(input: &mut &'a str, mut predicate: impl FnMut(char) -> bool) -> &'a str {
+    let len = input.find(|c| !predicate(c)).unwrap_or(input.len());
+    let (taken, rest) = input.split_at(len);
+    *input = rest;
+    taken
+}
+
+pub fn spaces(input: &mut &str) {
+    take_while(input, |c| c == ' ');
+}
+
+pub fn literal<'a>(input: &mut &'a str, expected: &str) -> Result<&'a str, ParseError> {
+    match input.strip_prefix(expected) {
+        Some(rest) => {
+            let matched = &input[..expected.len()];
+            *input = rest;
+            Ok(matched)
+        }
+        None => Err(ParseError::new(format!("expected '{expected}'")).span(next_token(input))),
+    }
+}
+
+pub fn identifier<'a>(input: &mut &'a str) -> Result<&'a str, ParseError> {
+    match take_while(input, |c| c.is_ascii_alphanumeric() || c == '_') {
+        "" => Err(ParseError::missing("identifier").span(next_token(input))),
+        identifier => Ok(identifier),
+    }
+}
+
+pub fn unsigned<T>(input: &mut &str) -> Result<T, ParseError>
+where
+    T: FromStr<Err: Error + Send + Sync + 'static>,
+{
+    number(input, 0)
+}
+
+pub fn signed<T>(input: &mut &str) -> Result<T, ParseError>
+where
+    T: FromStr<Err: Error + Send + Sync + 'static>,
+{
+    let sign = usize::from(input.starts_with(['-', '+']));
+    number(input, sign)
+}
+
+fn number<T>(input: &mut &str, sign: usize) -> Result<T, ParseError>
+where
+    T: FromStr<Err: Error + Send + Sync + 'static>,
+{
+    let len = input[sign..]
+        .find(|c: char| !c.is_ascii_digit())
+        .unwrap_or(input.len() - sign);
+    if len == 0 {
+        return Err(ParseError::missing("number").span(next_token(input)));
+    }
+    let (digits, rest) = input.split_at(sign + len);
+    let value = digits
+        .parse()
+        .map_err(|err| ParseError::invalid("number", digits).with_cause(err))?;
+    *input = rest;
+    Ok(value)
+}
+
+pub fn separated<'a, T>(
+    input: &mut &'a str,
+    separator: &str,
+    mut item: impl FnMut(&mut &'a str) -> Result<T, ParseError>,
+) -> Result<Vec<T>, ParseError> {
+    let mut items = vec![item(input)?];
+    while let Some(rest) = input.strip_prefix(separator) {
+        *input = rest;
+        items.push(item(input)?);
+    }
+    Ok(items)
+}
+
+pub fn key_value<'a, T>(
+    input: &mut &'a str,
+    key: &str,
+    value: impl FnOnce(&mut &'a str) -> Result<T, ParseError>,
+) -> Result<T, ParseError> {
+    literal(input, key)?;
+    literal(input, "=")?;
+    value(input)
+}
+
+fn next_token<'a>(input: &&'a str) -> &'a str {
+    let len = input
+        .find(|c: char| c.is_whitespace() || c.is_ascii_punctuation())
+        .unwrap_or(input.len());
+    &input[..len.max(input.chars().next().map_or(0, char::len_utf8))]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{
+        blocks, complete, identifier, key_value, lines, literal, separated, signed, spaces,
+        unsigned,
+    };
+
+    #[test]
+    fn numbers() {
+        let mut input = "42 -7 +3 x";
+        assert_eq!(unsigned::<u32>(&mut input).ok(), Some(42));
+        spaces(&mut input);
+        assert_eq!(signed::<i64>(&mut input).ok(), Some(-7));
+        spaces(&mut input);
+        assert_eq!(signed::<i64>(&mut input).ok(), Some(3));
+        spaces(&mut input);
+        let err = unsigned::<u32>(&mut input).unwrap_err();
+        assert_eq!(err.to_string(), "missing number");
+        assert_eq!(err.snippet(), Some("x"));
+        assert_eq!(input, "x");
+        let err = unsigned::<u8>(&mut "300").unwrap_err();
+        assert_eq!(err.to_string(), "invalid number '300'");
+    }
+
+    #[test]
+    fn literals_and_identifiers() {
+        let mut input = "px{a<2006}";
+        assert_eq!(identifier(&mut input).ok(), Some("px"));
+        assert_eq!(literal(&mut input, "{").ok(), Some("{"));
+        let err = literal(&mut input, "x").unwrap_err();
+        assert_eq!(err.to_string(), "expected 'x'");
+        assert_eq!(input, "a<2006}");
+        assert!(identifier(&mut "<").is_err());
+    }
+
+    #[test]
+    fn lists_and_pairs() {
+        let list = complete("1, 2, 3", |input| separated(input, ", ", unsigned::<u8>));
+        assert_eq!(list.ok(), Some(vec![1, 2, 3]));
+        let pair = complete("x=-4", |input| key_value(input, "x", signed::<i32>));
+        assert_eq!(pair.ok(), Some(-4));
+        let err = complete("1, 2,", |input| separated(input, ", ", unsigned::<u8>)).unwrap_err();
+        assert_eq!(err.to_string(), "unexpected ','");
+    }
+
+    #[test]
+    fn errors_locate_in_source() {
+        let source = "{x=787,m=2655}\n{x=1679,n=44}";
+        let err = lines(source, |line| {
+            complete(line, |input| {
+                literal(input, "{")?;
+                let x = key_value(input, "x", unsigned::<u32>)?;
+                literal(input, ",")?;
+                let m = key_value(input, "m", unsigned::<u32>)?;
+                literal(input, "}")?;
+                Ok((x, m))
+            })
+        })
+        .unwrap_err()
+        .locate(source);
+        assert_eq!(err.to_string(), "line 2, col 9: expected 'm'");
+    }
+
+    #[test]
+    fn blank_line_blocks() {
+        let source = "1000\n2000\n\n4000\n\n5000\n6000\n";
+        let sums = blocks(source, |block| {
+            lines(block, |line| complete(line, unsigned::<u32>))
+                .map(|values| values.iter().sum::<u32>())
+        });
+        assert_eq!(sums.ok(), Some(vec![3000, 4000, 11000]));
+        let source = "1\n\nx";
+        let err = blocks(source, |block| complete(block, unsigned::<u32>))
+            .unwrap_err()
+            .locate(source);
+        assert_eq!(err.to_string(), "line 3, col 1: missing number");
+    }
+}
@@ -0,0 +1,170 @@
+use std::{ops::Range, sync::OnceLock};
+
+pub const SEED_VAR: &str = "AOC_SEED";
+pub const DEFAULT_SEED: u64 = 123456789;
+
+static SEED: OnceLock<u64> = OnceLock::new();
+
+pub fn set_seed(seed: u64) -> bool {
+    SEED.set(seed).is_ok()
+}
+
+pub fn seed() -> u64 {
+    *SEED.get_or_init(|| {
+        std::env::var(SEED_VAR)
+            .ok()
+            .and_then(|seed| seed.parse().ok())
+            .unwrap_or(DEFAULT_SEED)
+    })
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Rng {
+    state: u64,
+}
+
+impl Rng {
+    pub fn new(seed: u64) -> Rng {
+        let mut z = seed.wrapping_add(0x9e37_79b9_7f4a_7c15);
+        z = (z ^ (z >> 30)).wrapping_mul(0xbf58_476d_1ce4_e5b9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94d0_49bb_1331_11eb);
+        Rng {
+            state: (z ^ (z >> 31)).max(1),
+        }
+    }
+
+    pub fn seeded() -> Rng {
+        Rng::new(seed())
+    }
+
+    pub fn next_u64(&mut self) -> u64 {
+        self.state ^= self.state >> 12;
+        self.state ^= self.state << 25;
+        self.state ^= self.state >> 27;
+        self.state.wrapping_mul(0x2545_f491_4f6c_dd1d)
+    }
+
+    pub fn next_u32(&mut self) -> u32 {
+        (self.next_u64() >> 32) as u32
+    }
+
+    pub fn below(&mut self, bound: u64) -> u64 {
+        assert!(bound > 0, "empty range");
+        let threshold = bound.wrapping_neg() % bound;
+        loop {
+            let product = u128::from(self.next_u64()) * u128::from(bound);
+            if product as u64 >= threshold {
+                return (product >> 64) as u64;
+            }
+        }
+    }
+
+    pub fn gen_range<T: Sample>(&mut self, range: Range<T>) -> T {
+        T::sample(self, range)
+    }
+
+    pub fn gen_bool(&mut self, probability: f64) -> bool {
+        ((self.next_u64() >> 11) as f64) < probability * (1u64 << 53) as f64
+    }
+
+    pub fn sample<'a, T>(&mut self, items: &'a [T]) -> Option<&'a T> {
+        if items.is_empty() {
+            None
+        } else {
+            items.get(self.gen_range(0..items.len()))
+        }
+    }
+
+    pub fn shuffle<T>(&mut self, items: &mut [T]) {
+        for idx in (1..items.len()).rev() {
+            items.swap(idx, self.gen_range(0..idx + 1));
+        }
+    }
+}
+
+pub trait Sample: Sized {
+    fn sample(rng: &mut Rng, range: Range<Self>) -> Self;
+}
+
+macro_rules! impl_sample {
+    ($($ty:ty => $unsigned:ty),*) => {
+        $(impl Sample for $ty {
+            fn sample(rng: &mut Rng, range: Range<Self>) -> Self {
+                assert!(range.start < range.end, "empty range");
+                let span = range.end.wrapping_sub(range.start) as $unsigned as u64;
+                range.start.wrapping_add(rng.below(span) as $ty)
+            }
+        })*
+    };
+}
+
+impl_sample!(
+    u8 => u8, u16 => u16, u32 => u32, u64 => u64, usize => usize,
+    i8 => u8, i16 => u16, i32 => u32, i64 => u64, isize => usize
+);
+
+#[cfg(test)]
+mod tests {
+    use super::Rng;
+
+    #[test]
+    fn reproducible() {
+        let (mut a, mut b) = (Rng::new(7), Rng::new(7));
+        let first: Vec<u64> = (0..8).map(|_| a.next_u64()).collect();
+        let second: Vec<u64> = (0..8).map(|_| b.next_u64()).collect();
+        assert_eq!(first, second);
+        assert_ne!(Rng::new(8).next_u64(), first[0]);
+        assert_ne!(Rng::new(0).next_u64(), 0);
+    }
+
+    #[test]
+    fn ranges() {
+        let mut rng = Rng::new(1);
+        let mut seen = [false; 6];
+        for _ in 0..1000 {
+            let value = rng.gen_range(-3i32..3);
+            assert!((-3..3).contains(&value));
+            seen[(value + 3) as usize] = true;
+        }
+        assert!(seen.iter().all(|&seen| seen));
+        assert_eq!(rng.gen_range(5u8..6), 5);
+        assert_eq!(rng.gen_range(i64::MIN..i64::MIN + 1), i64::MIN);
+        let full = rng.gen_range(0..u64::MAX);
+        assert!(full < u64::MAX);
+    }
+
+    #[test]
+    fn low_bits_are_not_periodic() {
+        let mut rng = Rng::new(123456789);
+        let bits: Vec<u64> = (0..64).map(|_| rng.gen_range(0..2)).collect();
+        assert!(bits.windows(2).any(|pair| pair[0] == pair[1]));
+        assert!(bits.contains(&0) && bits.contains(&1));
+    }
+
+    #[test]
+    fn sample_and_shuffle() {
+        let mut rng = Rng::new(42);
+        let items = [1, 2, 3, 4, 5];
+        assert!(items.contains(rng.sample(&items).unwrap()));
+        assert_eq!(rng.sample::<u8>(&[]), None);
+        let mut shuffled = items;
+        rng.shuffle(&mut shuffled);
+        let mut sorted = shuffled;
+        sorted.sort();
+        assert_eq!(sorted, items);
+        let mut orders = std::collections::HashSet::new();
+        for _ in 0..200 {
+            rng.shuffle(&mut shuffled);
+            orders.insert(shuffled);
+        }
+        assert!(orders.len() > 60);
+    }
+
+    #[test]
+    fn probabilities() {
+        let mut rng = Rng::new(3);
+        let hits = (0..10_000).filter(|_| rng.gen_bool(0.25)).count();
+        assert!((2_200..2_800).contains(&hits));
+        assert!(!Rng::new(3).gen_bool(0.0));
+    }
+}
@@ -0,0 +1,305 @@
+use std::{
+    cmp::Reverse,
+    collections::{hash_map::Entry, BinaryHeap, HashMap, VecDeque},
+    hash::Hash,
+    ops::Add,
+};
+
+const NO_PARENT: usize = usize::MAX;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Path<N, C> {
+    pub cost: C,
+    pub nodes: Vec<N>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Search<N, C> {
+    pub path: Option<Path<N, C>>,
+    pub visited: usize,
+}
+
+impl<N, C: Copy> Search<N, C> {
+    pub fn cost(&self) -> Option<C> {
+        self.path.as_ref().map(|path| path.cost)
+    }
+}
+
+struct Visited<N, C> {
+    nodes: Vec<(N, usize, C)>,
+    index: HashMap<N, usize>,
+}
+
+impl<N: Eq + Hash + Clone, C: Copy> Visited<N, C> {
+    fn new() -> Visited<N, C> {
+        Visited {
+            nodes: Vec::new(),
+            index: HashMap::new(),
+        }
+    }
+
+    fn path(&self, mut idx: usize) -> Path<N, C> {
+        let cost = self.nodes[idx].2;
+        let mut nodes = Vec::new();
+        while idx != NO_PARENT {
+            let (node, parent, _) = &self.nodes[idx];
+            nodes.push(node.clone());
+            idx = *parent;
+        }
+        nodes.reverse();
+        Path { cost, nodes }
+    }
+}
+
+pub fn bfs<N, I>(
+    starts: impl IntoIterator<Item = N>,
+    mut neighbours: impl FnMut(&N) -> I,
+    mut is_goal: impl FnMut(&N) -> bool,
+) -> Search<N, usize>
+where
+    N: Eq + Hash + Clone,
+    I: IntoIterator<Item = N>,
+{
+    let mut seen = Visited::new();
+    let mut queue = VecDeque::new();
+    for start in starts {
+        if let Entry::Vacant(entry) = seen.index.entry(start.clone()) {
+            entry.insert(seen.nodes.len());
+            queue.push_back(seen.nodes.len());
+            seen.nodes.push((start, NO_PARENT, 0));
+        }
+    }
+    let mut visited = 0;
+    while let Some(idx) = queue.pop_front() {
+        visited += 1;
+        let (node, _, depth) = &seen.nodes[idx];
+        if is_goal(node) {
+            let path = Some(seen.path(idx));
+            return Search { path, visited };
+        }
+        let depth = *depth + 1;
+        for next in neighbours(node) {
+            if let Entry::Vacant(entry) = seen.index.entry(next.clone()) {
+                entry.insert(seen.nodes.len());
+                queue.push_back(seen.nodes.len());
+                seen.nodes.push((next, idx, depth));
+            }
+        }
+    }
+    Search {
+        path: None,
+        visited,
+    }
+}
+
+pub fn distances<N, I>(
+    starts: impl IntoIterator<Item = N>,
+    mut neighbours: impl FnMut(&N) -> I,
+) -> HashMap<N, usize>
+where
+    N: Eq + Hash + Clone,
+    I: IntoIterator<Item = N>,
+{
+    let mut distances = HashMap::new();
+    let mut queue = VecDeque::new();
+    for start in starts {
+        if let Entry::Vacant(entry) = distances.entry(start.clone()) {
+            entry.insert(0);
+            queue.push_back((start, 0));
+        }
+    }
+    while let Some((node, distance)) = queue.pop_front() {
+        for next in neighbours(&node) {
+            if let Entry::Vacant(entry) = distances.entry(next.clone()) {
+                entry.insert(distance + 1);
+                queue.push_back((next, distance + 1));
+            }
+        }
+    }
+    distances
+}
+
+pub fn dijkstra<N, C, I>(
+    starts: impl IntoIterator<Item = N>,
+    neighbours: impl FnMut(&N) -> I,
+    is_goal: impl FnMut(&N) -> bool,
+) -> Search<N, C>
+where
+    N: Eq + Hash + Clone,
+    C: Copy + Ord + Default + Add<Output = C>,
+    I: IntoIterator<Item = (N, C)>,
+{
+    astar(starts, neighbours, |_| C::default(), is_goal)
+}
+
+pub fn astar<N, C, I>(
+    starts: impl IntoIterator<Item = N>,
+    mut neighbours: impl FnMut(&N) -> I,
+    mut heuristic: impl FnMut(&N) -> C,
+    mut is_goal: impl FnMut(&N) -> bool,
+) -> Search<N, C>
+where
+    N: Eq + Hash + Clone,
+    C: Copy + Ord + Default + Add<Output = C>,
+    I: IntoIterator<Item = (N, C)>,
+{
+    let mut seen = Visited::new();
+    let mut heap = BinaryHeap::new();
+    for start in starts {
+        if let Entry::Vacant(entry) = seen.index.entry(start.clone()) {
+            entry.insert(seen.nodes.len());
+            heap.push(Reverse((heuristic(&start), C::default(), seen.nodes.len())));
+            seen.nodes.push((start, NO_PARENT, C::default()));
+        }
+    }
+    let mut visited = 0;
+    while let Some(Reverse((_, cost, idx))) = heap.pop() {
+        if cost > seen.nodes[idx].2 {
+            continue;
+        }
+        visited += 1;
+        let node = &seen.nodes[idx].0;
+        if is_goal(node) {
+            let path = Some(seen.path(idx));
+            return Search { path, visited };
+        }
+        for (next, edge) in neighbours(node) {
+            let next_cost = cost + edge;
+            let next_idx = match seen.index.entry(next) {
+                Entry::Occupied(entry) => {
+                    let next_idx = *entry.get();
+                    let (_, parent, best) = &mut seen.nodes[next_idx];
+                    if next_cost >= *best {
+                        continue;
+                    }
+                    (*parent, *best) = (idx, next_cost);
+                    next_idx
+                }
+                Entry::Vacant(entry) => {
+                    let next_idx = seen.nodes.len();
+                    seen.nodes.push((entry.key().clone(), idx, next_cost));
+                    entry.insert(next_idx);
+                    next_idx
+                }
+            };
+            let estimate = next_cost + heuristic(&seen.nodes[next_idx].0);
+            heap.push(Reverse((estimate, next_cost, next_idx)));
+        }
+    }
+    Search {
+        path: None,
+        visited,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{astar, bfs, dijkstra, distances};
+    use crate::grid::Grid;
+
+    fn graph() -> Vec<Vec<(usize, u32)>> {
+        vec![
+            vec![(2, 10), (1, 1)],
+            vec![(3, 2)],
+            vec![(1, 1), (3, 3), (4, 1)],
+            vec![(0, 7), (4, 2)],
+            vec![],
+        ]
+    }
+
+    #[test]
+    fn dijkstra_costs() {
+        let graph = graph();
+        let neighbours = |&node: &usize| graph[node].clone();
+        let search = |start, goal| dijkstra([start], neighbours, |&node| node == goal);
+        assert_eq!(search(0, 1).cost(), Some(1));
+        assert_eq!(search(0, 3).cost(), Some(3));
+        assert_eq!(search(3, 0).cost(), Some(7));
+        assert_eq!(search(0, 4).cost(), Some(5));
+        assert_eq!(search(4, 0).cost(), None);
+    }
+
+    #[test]
+    fn dijkstra_path() {
+        let graph = graph();
+        let search = dijkstra([0], |&node| graph[node].clone(), |&node| node == 4);
+        let path = search.path.unwrap();
+        assert_eq!(path.nodes, vec![0, 1, 3, 4]);
+        assert_eq!(path.cost, 5);
+        assert!(search.visited >= 4);
+    }
+
+    #[test]
+    fn multiple_starts() {
+        let graph = graph();
+        let search = dijkstra([0, 2], |&node| graph[node].clone(), |&node| node == 4);
+        assert_eq!(search.path.unwrap().nodes, vec![2, 4]);
+    }
+
+    #[test]
+    fn unreachable_visits_everything() {
+        let graph = graph();
+        let search = dijkstra([0], |&node| graph[node].clone(), |_| false);
+        assert_eq!(search.path, None);
+        assert_eq!(search.visited, 5);
+    }
+
+    const MAZE: &str = "S..#....\n.#.#.##.\n.#...#..\n.####.#.\n......#E";
+
+    #[test]
+    fn bfs_on_grid() {
+        let grid: Grid<char> = MAZE.parse().unwrap();
+        let start = grid.position(|&c| c == 'S').unwrap();
+        let search = bfs(
+            [start],
+            |&position| {
+                grid.neighbours4(position)
+                    .filter(|&next| grid[next] != '#')
+                    .collect::<Vec<_>>()
+            },
+            |&position| grid[position] == 'E',
+        );
+        let path = search.path.unwrap();
+        assert_eq!(path.cost, 15);
+        assert_eq!(path.nodes.len(), 16);
+        assert_eq!(path.nodes.first(), Some(&start));
+        assert_eq!(path.nodes.last(), Some(&(4, 7)));
+    }
+
+    #[test]
+    fn astar_matches_dijkstra() {
+        let grid: Grid<char> = MAZE.parse().unwrap();
+        let start = grid.position(|&c| c == 'S').unwrap();
+        let goal = grid.position(|&c| c == 'E').unwrap();
+        let neighbours = |&position: &(usize, usize)| {
+            grid.neighbours4(position)
+                .filter(|&next| grid[next] != '#')
+                .map(|next| (next, 1))
+                .collect::<Vec<_>>()
+        };
+        let heuristic =
+            |&(row, column): &(usize, usize)| row.abs_diff(goal.0) + column.abs_diff(goal.1);
+        let astar = astar([start], neighbours, heuristic, |&position| position == goal);
+        let dijkstra = dijkstra([start], neighbours, |&position| position == goal);
+        assert_eq!(astar.cost(), Some(15));
+        assert_eq!(astar.cost(), dijkstra.cost());
+        assert!(astar.visited <= dijkstra.visited);
+    }
+
+    #[test]
+    fn distances_on_grid() {
+        let grid: Grid<char> = MAZE.parse().unwrap();
+        let start = grid.position(|&c| c == 'S').unwrap();
+        let distances = distances([start], |&position| {
+            grid.neighbours4(position)
+                .filter(|&next| grid[next] != '#')
+                .collect::<Vec<_>>()
+        });
+        assert_eq!(distances[&start], 0);
+        assert_eq!(distances[&(4, 7)], 15);
+        assert_eq!(
+            distances.len(),
+            grid.iter().filter(|(_, &c)| c != '#').count()
+        );
+    }
+}
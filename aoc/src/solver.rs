@@ -0,0 +1,228 @@
+use crate::ParseError;
+use std::{
+    error::Error,
+    fmt::{Display, Formatter},
+    str::FromStr,
+    time::{Duration, Instant},
+};
+
+pub trait Solver {
+    type Input;
+    type Error: Display;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError>;
+    fn part1(input: &Self::Input) -> Result<impl Display, Self::Error>;
+    fn part2(input: &Self::Input) -> Result<impl Display, Self::Error>;
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Part {
+    One,
+    Two,
+}
+
+impl Part {
+    pub const ALL: [Part; 2] = [Part::One, Part::Two];
+}
+
+impl Display for Part {
+    fn fmt(&self, f: &mut Formatter) -> std::fmt::Result {
+        match self {
+            Part::One => write!(f, "1"),
+            Part::Two => write!(f, "2"),
+        }
+    }
+}
+
+impl FromStr for Part {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "1" => Ok(Part::One),
+            "2" => Ok(Part::Two),
+            _ => Err(format!("invalid part `{s}`")),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Failure {
+    Parse(String),
+    Solve(String),
+}
+
+impl Display for Failure {
+    fn fmt(&self, f: &mut Formatter) -> std::fmt::Result {
+        match self {
+            Failure::Parse(err) => write!(f, "failed to parse input: {err}"),
+            Failure::Solve(err) => write!(f, "failed to solve: {err}"),
+        }
+    }
+}
+
+impl Error for Failure {}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Answer {
+    pub part: Part,
+    pub result: Result<String, Failure>,
+    pub elapsed: Duration,
+}
+
+impl Display for Answer {
+    fn fmt(&self, f: &mut Formatter) -> std::fmt::Result {
+        match &self.result {
+            Ok(answer) => write!(f, "Part {}: {}", self.part, answer),
+            Err(err) => write!(f, "Part {}: {}", self.part, err),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Report {
+    pub parse: Duration,
+    pub answers: Vec<Answer>,
+}
+
+impl Report {
+    pub fn is_ok(&self) -> bool {
+        self.answers.iter().all(|answer| answer.result.is_ok())
+    }
+}
+
+impl Display for Report {
+    fn fmt(&self, f: &mut Formatter) -> std::fmt::Result {
+        write!(f, "Parse: {:?}", self.parse)?;
+        for answer in self.answers.iter() {
+            match answer.result {
+                Ok(_) => write!(f, "\n{} ({:?})", answer, answer.elapsed)?,
+                Err(_) => write!(f, "\n{}", answer)?,
+            }
+        }
+        Ok(())
+    }
+}
+
+pub fn run<S: Solver>(input: &str, parts: &[Part]) -> Report {
+    let start = Instant::now();
+    let parsed = S::parse(input);
+    let parse = start.elapsed();
+    let answers = match parsed {
+        Ok(input) => parts
+            .iter()
+            .map(|&part| {
+                let start = Instant::now();
+                let result = solve_parsed::<S>(&input, part);
+                Answer {
+                    part,
+                    result,
+                    elapsed: start.elapsed(),
+                }
+            })
+            .collect(),
+        Err(err) => {
+            let failure = Failure::Parse(err.locate(input).to_string());
+            parts
+                .iter()
+                .map(|&part| Answer {
+                    part,
+                    result: Err(failure.clone()),
+                    elapsed: Duration::ZERO,
+                })
+                .collect()
+        }
+    };
+    Report { parse, answers }
+}
+
+pub fn solve<S: Solver>(input: &str, part: Part) -> Result<String, Failure> {
+    let input = S::parse(input).map_err(|err| Failure::Parse(err.locate(input).to_string()))?;
+    solve_parsed::<S>(&input, part)
+}
+
+fn solve_parsed<S: Solver>(input: &S::Input, part: Part) -> Result<String, Failure> {
+    let answer = match part {
+        Part::One => S::part1(input).map(|answer| answer.to_string()),
+        Part::Two => S::part2(input).map(|answer| answer.to_string()),
+    };
+    answer.map_err(|err| Failure::Solve(err.to_string()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{run, solve, Failure, Part, Solver};
+    use crate::ParseError;
+    use std::fmt::Display;
+
+    struct Sum;
+
+    impl Solver for Sum {
+        type Input = Vec<u32>;
+        type Error = &'static str;
+
+        fn parse(input: &str) -> Result<Self::Input, ParseError> {
+            input
+                .split(',')
+                .map(|value| {
+                    value
+                        .parse()
+                        .map_err(|_| ParseError::invalid("number", value))
+                })
+                .collect()
+        }
+
+        fn part1(input: &Self::Input) -> Result<impl Display, Self::Error> {
+            Ok(input.iter().sum::<u32>())
+        }
+
+        fn part2(input: &Self::Input) -> Result<impl Display, Self::Error> {
+            input.iter().max().ok_or("empty input")
+        }
+    }
+
+    #[test]
+    fn parse_part() {
+        assert_eq!("1".parse(), Ok(Part::One));
+        assert_eq!("2".parse(), Ok(Part::Two));
+        assert!("3".parse::<Part>().is_err());
+    }
+
+    #[test]
+    fn run_all_parts() {
+        let report = run::<Sum>("1,5,2", &Part::ALL);
+        let answers: Vec<_> = report
+            .answers
+            .iter()
+            .map(|answer| (answer.part, answer.result.clone()))
+            .collect();
+        assert_eq!(
+            answers,
+            vec![
+                (Part::One, Ok("8".to_string())),
+                (Part::Two, Ok("5".to_string()))
+            ]
+        );
+        assert!(report.is_ok());
+    }
+
+    #[test]
+    fn parse_failure() {
+        assert_eq!(
+            solve::<Sum>("1,a", Part::One),
+            Err(Failure::Parse(
+                "line 1, col 3: invalid number 'a'".to_string()
+            ))
+        );
+    }
+
+    #[test]
+    fn display_answer() {
+        let report = run::<Sum>("1,a", &[Part::Two]);
+        assert!(!report.is_ok());
+        assert_eq!(
+            report.answers[0].to_string(),
+            "Part 2: failed to parse input: line 1, col 3: invalid number 'a'"
+        );
+    }
+}